@@ -0,0 +1,4 @@
+pub static ERROR_FORCED_FAILURE: &[u8] = b"Operation failure forced by the owner";
+pub static ERROR_BAD_PAYMENT_AMOUNT: &[u8] = b"Bad payment amount";
+pub static ERROR_INSUFFICIENT_ACTIVE_STAKE: &[u8] = b"Insufficient active stake";
+pub static ERROR_DELEGATION_CAP: &[u8] = b"Total delegation cap reached";
@@ -0,0 +1,299 @@
+#![no_std]
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[rustfmt::skip]
+pub mod delegation_mock_proxy;
+mod errors;
+
+use errors::*;
+
+pub const REWARD_INDEX_PRECISION: u64 = 1_000_000_000_000_000_000;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DelegationOperation {
+    Delegate,
+    Undelegate,
+    Withdraw,
+    ClaimRewards,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct PendingUndelegation<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub unbond_epoch: u64,
+}
+
+// stands in for a system delegation contract in scenario tests,
+// rewards are funded by the owner and any operation can be forced to fail
+#[multiversx_sc::contract]
+pub trait DelegationMock {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[payable("EGLD")]
+    #[endpoint]
+    fn delegate(&self) {
+        self.require_operation_allowed(DelegationOperation::Delegate);
+        let payment = self.call_value().egld().clone_value();
+        require!(payment > 0, ERROR_BAD_PAYMENT_AMOUNT);
+
+        // a zero cap means that the total delegation is not capped
+        let total_delegation_cap = self.total_delegation_cap().get();
+        require!(
+            total_delegation_cap == 0
+                || self.total_active_stake().get() + &payment <= total_delegation_cap,
+            ERROR_DELEGATION_CAP
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.accrue_rewards(&caller);
+        self.active_stake(&caller)
+            .update(|stake| *stake += &payment);
+        self.total_active_stake().update(|stake| *stake += payment);
+    }
+
+    #[endpoint(unDelegate)]
+    fn undelegate(&self, amount: BigUint) {
+        self.require_operation_allowed(DelegationOperation::Undelegate);
+        let caller = self.blockchain().get_caller();
+        let active_stake_mapper = self.active_stake(&caller);
+        require!(amount > 0, ERROR_BAD_PAYMENT_AMOUNT);
+        require!(
+            amount <= active_stake_mapper.get(),
+            ERROR_INSUFFICIENT_ACTIVE_STAKE
+        );
+
+        self.accrue_rewards(&caller);
+        active_stake_mapper.update(|stake| *stake -= &amount);
+        self.total_active_stake().update(|stake| *stake -= &amount);
+        self.unstaked_stake(&caller)
+            .update(|stake| *stake += &amount);
+
+        let unbond_epoch = self.blockchain().get_block_epoch() + self.unbond_period().get();
+        self.pending_undelegations(&caller)
+            .push_back(PendingUndelegation {
+                amount,
+                unbond_epoch,
+            });
+    }
+
+    // only the undelegations that passed the unbond period are released,
+    // so a withdraw can succeed without transferring anything
+    #[endpoint]
+    fn withdraw(&self) {
+        self.require_operation_allowed(DelegationOperation::Withdraw);
+        let caller = self.blockchain().get_caller();
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut pending_undelegations = self.pending_undelegations(&caller);
+
+        let mut amount = BigUint::zero();
+        while let Some(front_node) = pending_undelegations.front() {
+            let undelegation = front_node.get_value_cloned();
+            if undelegation.unbond_epoch > current_epoch {
+                break;
+            }
+
+            amount += undelegation.amount;
+            pending_undelegations.remove_node(&front_node);
+        }
+
+        if amount > 0 {
+            self.unstaked_stake(&caller)
+                .update(|stake| *stake -= &amount);
+            self.tx().to(&caller).egld(&amount).transfer();
+        }
+    }
+
+    #[endpoint(claimRewards)]
+    fn claim_rewards(&self) {
+        self.require_operation_allowed(DelegationOperation::ClaimRewards);
+        let caller = self.blockchain().get_caller();
+        self.accrue_rewards(&caller);
+        let rewards = self.claimable_rewards(&caller).take();
+        if rewards > 0 {
+            self.tx().to(&caller).egld(&rewards).transfer();
+        }
+    }
+
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(addRewards)]
+    fn add_rewards(&self, delegator: ManagedAddress) {
+        let payment = self.call_value().egld().clone_value();
+        require!(payment > 0, ERROR_BAD_PAYMENT_AMOUNT);
+        self.claimable_rewards(&delegator)
+            .update(|rewards| *rewards += payment);
+    }
+
+    // the rewards generated each epoch are split between the delegators by their active stake,
+    // the owner funds them through the payment of this endpoint
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(setRewardsPerEpoch)]
+    fn set_rewards_per_epoch(&self, rewards_per_epoch: BigUint) {
+        self.update_reward_index();
+        self.rewards_per_epoch().set(rewards_per_epoch);
+    }
+
+    #[only_owner]
+    #[endpoint(modifyTotalDelegationCap)]
+    fn modify_total_delegation_cap(&self, new_total_delegation_cap: BigUint) {
+        self.total_delegation_cap().set(new_total_delegation_cap);
+    }
+
+    #[only_owner]
+    #[endpoint(setStakedNodes)]
+    fn set_staked_nodes(&self, bls_keys: MultiValueEncoded<ManagedBuffer>) {
+        let mut staked_nodes = self.staked_nodes();
+        staked_nodes.clear();
+        for bls_key in bls_keys {
+            staked_nodes.push(&bls_key);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(setUnbondPeriod)]
+    fn set_unbond_period(&self, unbond_period: u64) {
+        self.unbond_period().set(unbond_period);
+    }
+
+    #[only_owner]
+    #[endpoint(setOperationFailure)]
+    fn set_operation_failure(&self, operation: DelegationOperation, fail: bool) {
+        self.failing_operation(operation).set(fail);
+    }
+
+    fn require_operation_allowed(&self, operation: DelegationOperation) {
+        require!(
+            !self.failing_operation(operation).get(),
+            ERROR_FORCED_FAILURE
+        );
+    }
+
+    // epochs without any active stake do not generate rewards
+    fn update_reward_index(&self) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let last_reward_epoch = self.last_reward_epoch().replace(current_epoch);
+        let total_active_stake = self.total_active_stake().get();
+        if current_epoch <= last_reward_epoch || total_active_stake == 0 {
+            return;
+        }
+
+        let epoch_rewards = self.rewards_per_epoch().get() * (current_epoch - last_reward_epoch);
+        self.reward_index()
+            .update(|index| *index += epoch_rewards * REWARD_INDEX_PRECISION / total_active_stake);
+    }
+
+    fn accrue_rewards(&self, delegator: &ManagedAddress) {
+        self.update_reward_index();
+        let reward_index = self.reward_index().get();
+        let delegator_index = self
+            .delegator_reward_index(delegator)
+            .replace(reward_index.clone());
+        let rewards = self.active_stake(delegator).get() * (reward_index - delegator_index)
+            / REWARD_INDEX_PRECISION;
+        if rewards > 0 {
+            self.claimable_rewards(delegator)
+                .update(|claimable| *claimable += rewards);
+        }
+    }
+
+    // same layout as the delegation system contract: owner, service fee, delegation cap,
+    // initial owner funds, automatic activation, with delegation cap, changeable service fee,
+    // check cap on redelegate, created nonce and unbond period
+    #[view(getContractConfig)]
+    fn get_contract_config(&self) -> MultiValueEncoded<ManagedBuffer> {
+        let total_delegation_cap = self.total_delegation_cap().get();
+        let with_delegation_cap = if total_delegation_cap > 0 {
+            ManagedBuffer::from(b"true")
+        } else {
+            ManagedBuffer::from(b"false")
+        };
+
+        let mut config = MultiValueEncoded::new();
+        config.push(
+            self.blockchain()
+                .get_owner_address()
+                .as_managed_buffer()
+                .clone(),
+        );
+        config.push(ManagedBuffer::new());
+        config.push(total_delegation_cap.to_bytes_be_buffer());
+        config.push(ManagedBuffer::new());
+        config.push(ManagedBuffer::from(b"true"));
+        config.push(with_delegation_cap);
+        config.push(ManagedBuffer::from(b"false"));
+        config.push(ManagedBuffer::from(b"false"));
+        config.push(ManagedBuffer::new());
+        config.push(BigUint::from(self.unbond_period().get()).to_bytes_be_buffer());
+        config
+    }
+
+    // only the staked status is reported, followed by the BLS keys of the staked nodes
+    #[view(getAllNodeStates)]
+    fn get_all_node_states(&self) -> MultiValueEncoded<ManagedBuffer> {
+        let mut node_states = MultiValueEncoded::new();
+        node_states.push(ManagedBuffer::from(b"staked"));
+        for bls_key in self.staked_nodes().iter() {
+            node_states.push(bls_key);
+        }
+        node_states
+    }
+
+    #[view(getUserActiveStake)]
+    #[storage_mapper("activeStake")]
+    fn active_stake(&self, delegator: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalActiveStake)]
+    #[storage_mapper("totalActiveStake")]
+    fn total_active_stake(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUserUnStakedValue)]
+    #[storage_mapper("unstakedStake")]
+    fn unstaked_stake(&self, delegator: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("pendingUndelegations")]
+    fn pending_undelegations(
+        &self,
+        delegator: &ManagedAddress,
+    ) -> LinkedListMapper<PendingUndelegation<Self::Api>>;
+
+    #[view(getClaimableRewards)]
+    #[storage_mapper("claimableRewards")]
+    fn claimable_rewards(&self, delegator: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getRewardsPerEpoch)]
+    #[storage_mapper("rewardsPerEpoch")]
+    fn rewards_per_epoch(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("lastRewardEpoch")]
+    fn last_reward_epoch(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("rewardIndex")]
+    fn reward_index(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("delegatorRewardIndex")]
+    fn delegator_reward_index(&self, delegator: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalDelegationCap)]
+    #[storage_mapper("totalDelegationCap")]
+    fn total_delegation_cap(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("stakedNodes")]
+    fn staked_nodes(&self) -> VecMapper<ManagedBuffer>;
+
+    #[view(getUnbondPeriod)]
+    #[storage_mapper("unbondPeriod")]
+    fn unbond_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(isOperationFailing)]
+    #[storage_mapper("failingOperation")]
+    fn failing_operation(&self, operation: DelegationOperation) -> SingleValueMapper<bool>;
+}
@@ -0,0 +1,46 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           20
+// Async Callback (empty):               1
+// Total number of exported functions:  23
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    delegation_mock
+    (
+        init => init
+        upgrade => upgrade
+        delegate => delegate
+        unDelegate => undelegate
+        withdraw => withdraw
+        claimRewards => claim_rewards
+        addRewards => add_rewards
+        setRewardsPerEpoch => set_rewards_per_epoch
+        modifyTotalDelegationCap => modify_total_delegation_cap
+        setStakedNodes => set_staked_nodes
+        setUnbondPeriod => set_unbond_period
+        setOperationFailure => set_operation_failure
+        getContractConfig => get_contract_config
+        getAllNodeStates => get_all_node_states
+        getUserActiveStake => active_stake
+        getTotalActiveStake => total_active_stake
+        getUserUnStakedValue => unstaked_stake
+        getClaimableRewards => claimable_rewards
+        getRewardsPerEpoch => rewards_per_epoch
+        getTotalDelegationCap => total_delegation_cap
+        getUnbondPeriod => unbond_period
+        isOperationFailing => failing_operation
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,7 @@
+pub static ERROR_FORCED_FAILURE: &[u8] = b"Operation failure forced by the owner";
+pub static ERROR_INVALID_VOTING_WINDOW: &[u8] = b"Invalid voting window";
+pub static ERROR_PROPOSAL_NOT_FOUND: &[u8] = b"Proposal not found";
+pub static ERROR_VOTING_NOT_OPEN: &[u8] = b"Voting is not open for this proposal";
+pub static ERROR_INVALID_VOTE_TYPE: &[u8] = b"Invalid vote type";
+pub static ERROR_ALREADY_VOTED: &[u8] = b"Already voted for this proposal";
+pub static ERROR_BAD_VOTING_POWER: &[u8] = b"Bad voting power";
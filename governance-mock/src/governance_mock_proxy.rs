@@ -0,0 +1,257 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct GovernanceMockProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for GovernanceMockProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = GovernanceMockProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        GovernanceMockProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct GovernanceMockProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> GovernanceMockProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> GovernanceMockProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> GovernanceMockProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn proposal<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        commit_hash: Arg0,
+        start_vote_epoch: Arg1,
+        end_vote_epoch: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("proposal")
+            .argument(&commit_hash)
+            .argument(&start_vote_epoch)
+            .argument(&end_vote_epoch)
+            .original_result()
+    }
+
+    pub fn vote<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        vote: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("vote")
+            .argument(&proposal)
+            .argument(&vote)
+            .original_result()
+    }
+
+    pub fn delegate_vote<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        vote: Arg1,
+        delegate_to: Arg2,
+        voting_power: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("delegateVote")
+            .argument(&proposal)
+            .argument(&vote)
+            .argument(&delegate_to)
+            .argument(&voting_power)
+            .original_result()
+    }
+
+    pub fn set_operation_failure<
+        Arg0: ProxyArg<GovernanceOperation>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        operation: Arg0,
+        fail: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOperationFailure")
+            .argument(&operation)
+            .argument(&fail)
+            .original_result()
+    }
+
+    pub fn proposals<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Proposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("viewProposal")
+            .argument(&proposal)
+            .original_result()
+    }
+
+    pub fn votes<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RecordedVote<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVote")
+            .argument(&proposal)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn proposal_tally<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        vote_type: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalTally")
+            .argument(&proposal)
+            .argument(&vote_type)
+            .original_result()
+    }
+
+    pub fn delegated_voting_power<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        delegator: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegatedVotingPower")
+            .argument(&proposal)
+            .argument(&delegator)
+            .original_result()
+    }
+
+    pub fn failing_operation<
+        Arg0: ProxyArg<GovernanceOperation>,
+    >(
+        self,
+        operation: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isOperationFailing")
+            .argument(&operation)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GovernanceOperation {
+    Vote,
+    DelegateVote,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Proposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub commit_hash: ManagedBuffer<Api>,
+    pub start_vote_epoch: u64,
+    pub end_vote_epoch: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct RecordedVote<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub vote_type: ManagedBuffer<Api>,
+    pub voting_power: BigUint<Api>,
+}
@@ -0,0 +1,189 @@
+#![no_std]
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[rustfmt::skip]
+pub mod governance_mock_proxy;
+mod errors;
+
+use errors::*;
+
+pub const VOTE_TYPES: [&[u8]; 4] = [b"yes", b"no", b"abstain", b"veto"];
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GovernanceOperation {
+    Vote,
+    DelegateVote,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Proposal<M: ManagedTypeApi> {
+    pub commit_hash: ManagedBuffer<M>,
+    pub start_vote_epoch: u64,
+    pub end_vote_epoch: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct RecordedVote<M: ManagedTypeApi> {
+    pub vote_type: ManagedBuffer<M>,
+    pub voting_power: BigUint<M>,
+}
+
+// stands in for the governance system contract in scenario tests, it is placed
+// at the governance system address and any vote can be forced to fail by the owner
+#[multiversx_sc::contract]
+pub trait GovernanceMock {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    // the proposal fee is accepted but not checked
+    #[payable("EGLD")]
+    #[endpoint]
+    fn proposal(
+        &self,
+        commit_hash: ManagedBuffer,
+        start_vote_epoch: u64,
+        end_vote_epoch: u64,
+    ) -> BigUint {
+        require!(
+            start_vote_epoch <= end_vote_epoch,
+            ERROR_INVALID_VOTING_WINDOW
+        );
+
+        let nonce = self.last_proposal_nonce().update(|nonce| {
+            *nonce += 1;
+            *nonce
+        });
+        let nonce = BigUint::from(nonce);
+        self.proposals(&nonce).set(Proposal {
+            commit_hash,
+            start_vote_epoch,
+            end_vote_epoch,
+        });
+
+        nonce
+    }
+
+    // direct votes are recorded without voting power, as the mock has no staking data
+    #[endpoint]
+    fn vote(&self, proposal: BigUint, vote: ManagedBuffer) {
+        self.require_operation_allowed(GovernanceOperation::Vote);
+        let caller = self.blockchain().get_caller();
+        self.record_vote(&proposal, &caller, vote, BigUint::zero());
+    }
+
+    #[endpoint(delegateVote)]
+    fn delegate_vote(
+        &self,
+        proposal: BigUint,
+        vote: ManagedBuffer,
+        delegate_to: ManagedAddress,
+        voting_power: BigUint,
+    ) {
+        self.require_operation_allowed(GovernanceOperation::DelegateVote);
+        require!(voting_power > 0, ERROR_BAD_VOTING_POWER);
+
+        let caller = self.blockchain().get_caller();
+        self.delegated_voting_power(&proposal, &caller)
+            .update(|power| *power += &voting_power);
+        self.record_vote(&proposal, &delegate_to, vote, voting_power);
+    }
+
+    fn record_vote(
+        &self,
+        proposal: &BigUint,
+        voter: &ManagedAddress,
+        vote_type: ManagedBuffer,
+        voting_power: BigUint,
+    ) {
+        let proposal_mapper = self.proposals(proposal);
+        require!(!proposal_mapper.is_empty(), ERROR_PROPOSAL_NOT_FOUND);
+        let proposal_data = proposal_mapper.get();
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            current_epoch >= proposal_data.start_vote_epoch
+                && current_epoch <= proposal_data.end_vote_epoch,
+            ERROR_VOTING_NOT_OPEN
+        );
+        require!(
+            VOTE_TYPES
+                .iter()
+                .any(|valid_type| vote_type == **valid_type),
+            ERROR_INVALID_VOTE_TYPE
+        );
+
+        // a delegated vote can be topped up with more power, but never change its vote type
+        let vote_mapper = self.votes(proposal, voter);
+        let mut recorded_vote = RecordedVote {
+            vote_type: vote_type.clone(),
+            voting_power: BigUint::zero(),
+        };
+        if !vote_mapper.is_empty() {
+            recorded_vote = vote_mapper.get();
+            require!(
+                voting_power > 0 && recorded_vote.vote_type == vote_type,
+                ERROR_ALREADY_VOTED
+            );
+        }
+
+        self.proposal_tally(proposal, &vote_type)
+            .update(|tally| *tally += &voting_power);
+        recorded_vote.voting_power += voting_power;
+        vote_mapper.set(recorded_vote);
+    }
+
+    #[only_owner]
+    #[endpoint(setOperationFailure)]
+    fn set_operation_failure(&self, operation: GovernanceOperation, fail: bool) {
+        self.failing_operation(operation).set(fail);
+    }
+
+    fn require_operation_allowed(&self, operation: GovernanceOperation) {
+        require!(
+            !self.failing_operation(operation).get(),
+            ERROR_FORCED_FAILURE
+        );
+    }
+
+    #[storage_mapper("lastProposalNonce")]
+    fn last_proposal_nonce(&self) -> SingleValueMapper<u64>;
+
+    #[view(viewProposal)]
+    #[storage_mapper("proposals")]
+    fn proposals(&self, proposal: &BigUint) -> SingleValueMapper<Proposal<Self::Api>>;
+
+    #[view(getVote)]
+    #[storage_mapper("votes")]
+    fn votes(
+        &self,
+        proposal: &BigUint,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<RecordedVote<Self::Api>>;
+
+    #[view(getProposalTally)]
+    #[storage_mapper("proposalTally")]
+    fn proposal_tally(
+        &self,
+        proposal: &BigUint,
+        vote_type: &ManagedBuffer,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getDelegatedVotingPower)]
+    #[storage_mapper("delegatedVotingPower")]
+    fn delegated_voting_power(
+        &self,
+        proposal: &BigUint,
+        delegator: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(isOperationFailing)]
+    #[storage_mapper("failingOperation")]
+    fn failing_operation(&self, operation: GovernanceOperation) -> SingleValueMapper<bool>;
+}
@@ -0,0 +1,35 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            9
+// Async Callback (empty):               1
+// Total number of exported functions:  12
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    governance_mock
+    (
+        init => init
+        upgrade => upgrade
+        proposal => proposal
+        vote => vote
+        delegateVote => delegate_vote
+        setOperationFailure => set_operation_failure
+        viewProposal => proposals
+        getVote => votes
+        getProposalTally => proposal_tally
+        getDelegatedVotingPower => delegated_voting_power
+        isOperationFailing => failing_operation
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
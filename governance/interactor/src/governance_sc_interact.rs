@@ -240,8 +240,8 @@ impl GovernanceCallsInteract {
         match response {
             Ok(_) => println!("Delegate vote successfully done!"),
             Err(err) => {
-                if err_message.is_some() {
-                    assert_eq!(err_message.unwrap(), err.message.to_string());
+                if let Some(expected_message) = err_message {
+                    assert_eq!(expected_message, err.message.to_string());
                 } else {
                     panic!("Unexpected error: {err}");
                 }
@@ -48,7 +48,7 @@ impl Interact {
             .to(self.state.liquid_staking_address())
             .gas(50_000_000u64)
             .typed(liquid_staking_proxy::LiquidStakingProxy)
-            .add_liquidity()
+            .add_liquidity(OptionalValue::<ManagedAddress<StaticApi>>::None)
             .egld(liquidity)
             .returns(ReturnsResultUnmanaged)
             .run()
@@ -316,7 +316,7 @@ impl Interact {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn clear_ongoing_whitelist_op(&mut self) {
+    pub async fn clear_pending_whitelist(&mut self, contract_address: Bech32Address) {
         let response = self
             .interactor
             .tx()
@@ -324,7 +324,7 @@ impl Interact {
             .to(self.state.liquid_staking_address())
             .gas(30_000_000u64)
             .typed(liquid_staking_proxy::LiquidStakingProxy)
-            .clear_ongoing_whitelist_op()
+            .clear_pending_whitelist(contract_address)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -525,13 +525,13 @@ impl Interact {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn addresses_to_claim(&mut self) {
+    pub async fn remaining_claim_count(&mut self) {
         let result_value = self
             .interactor
             .query()
             .to(self.state.liquid_staking_address())
             .typed(liquid_staking_proxy::LiquidStakingProxy)
-            .addresses_to_claim()
+            .remaining_claim_count()
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -560,4 +560,57 @@ impl Interact {
             .run()
             .await;
     }
+
+    pub async fn register_protocol_proposal(
+        &mut self,
+        proposal: u32,
+        start_epoch: u64,
+        end_epoch: u64,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.liquid_staking_address())
+            .gas(30_000_000u64)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .register_protocol_proposal(proposal, start_epoch, end_epoch)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn decide_protocol_vote(&mut self, proposal: u32, vote_type: &str) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.liquid_staking_address())
+            .gas(30_000_000u64)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .decide_protocol_vote(proposal, vote_type)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn cast_protocol_vote(&mut self, caller: Bech32Address, proposal: u32) {
+        let response = self
+            .interactor
+            .tx()
+            .from(caller)
+            .to(self.state.liquid_staking_address())
+            .gas(100_000_000u64)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .cast_protocol_vote(proposal)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
 }
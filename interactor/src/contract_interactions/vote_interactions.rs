@@ -47,6 +47,112 @@ impl Interact {
         println!("new address: {new_address_string}");
     }
 
+    pub async fn register_proposal(
+        &mut self,
+        proposal_id: u32,
+        start_epoch: u64,
+        end_epoch: u64,
+        snapshot_voting: bool,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.vote_address())
+            .gas(30_000_000u64)
+            .typed(vote_proxy::VoteSCProxy)
+            .register_proposal(proposal_id, start_epoch, end_epoch, snapshot_voting)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_proposal_aggregation(&mut self, proposal_id: u32) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.vote_address())
+            .gas(30_000_000u64)
+            .typed(vote_proxy::VoteSCProxy)
+            .set_proposal_aggregation(proposal_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_proposal_hash_function(
+        &mut self,
+        proposal_id: u32,
+        hash_function: vote_proxy::HashFunction,
+    ) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.vote_address())
+            .gas(30_000_000u64)
+            .typed(vote_proxy::VoteSCProxy)
+            .set_proposal_hash_function(proposal_id, hash_function)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn prove_voting_powers(
+        &mut self,
+        proposal_id: u32,
+        leaf_count: u32,
+        proof: Vec<ManagedByteArray<StaticApi, { HASH_LENGTH }>>,
+        voters: Vec<(Bech32Address, u128, u32)>,
+    ) {
+        let voters = voters
+            .into_iter()
+            .map(|(voter, voting_power, index)| {
+                MultiValue3::from((
+                    ManagedAddress::from(voter.to_address()),
+                    BigUint::<StaticApi>::from(voting_power),
+                    index,
+                ))
+            })
+            .collect::<MultiValueVec<_>>();
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.vote_address())
+            .gas(200_000_000u64)
+            .typed(vote_proxy::VoteSCProxy)
+            .prove_voting_powers(proposal_id, leaf_count, proof, voters)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn cast_aggregated_vote(&mut self, proposal_id: u32) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.vote_address())
+            .gas(100_000_000u64)
+            .typed(vote_proxy::VoteSCProxy)
+            .cast_aggregated_vote(proposal_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
     pub async fn set_root_hash(
         &mut self,
         hash: ManagedByteArray<StaticApi, HASH_LENGTH>,
@@ -94,7 +200,53 @@ impl Interact {
             .to(self.state.vote_address())
             .gas(200_000_000u64)
             .typed(vote_proxy::VoteSCProxy)
-            .delegate_vote(proposal_id, vote, voting_power, proof);
+            .delegate_vote(
+                proposal_id,
+                vote,
+                voting_power,
+                proof,
+                OptionalValue::<BigUint<StaticApi>>::None,
+            );
+
+        match error {
+            None => {
+                tx.returns(ReturnsResultUnmanaged).run().await;
+            }
+            Some(expect_error) => {
+                tx.returns(expect_error).run().await;
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn delegate_signed_vote(
+        &mut self,
+        relayer: Bech32Address,
+        voter: Bech32Address,
+        proposal_id: u32,
+        vote: &str,
+        voting_power: u128,
+        nonce: u64,
+        proof: Vec<ManagedByteArray<StaticApi, { HASH_LENGTH }>>,
+        signature: Vec<u8>,
+        error: Option<ExpectError<'_>>,
+    ) {
+        let tx = self
+            .interactor
+            .tx()
+            .from(relayer)
+            .to(self.state.vote_address())
+            .gas(200_000_000u64)
+            .typed(vote_proxy::VoteSCProxy)
+            .delegate_signed_vote(
+                voter,
+                proposal_id,
+                vote,
+                voting_power,
+                nonce,
+                proof,
+                ManagedBuffer::<StaticApi>::from(signature),
+            );
 
         match error {
             None => {
@@ -1,2 +1,3 @@
-pub mod liquid_staking_proxy;
 pub mod vote_proxy;
+
+pub use liquid_staking::liquid_staking_proxy;
@@ -79,10 +79,10 @@ pub enum InteractCliCommand {
     GetUnstakeTokenId,
 
     #[command(
-        name = "clear-ongoing-whitelist-operation",
-        about = "Clear ongoing whitelist operation"
+        name = "clear-pending-whitelist",
+        about = "Clear an expired pending whitelisting of a delegation contract"
     )]
-    ClearOngoingWhitelistOp,
+    ClearPendingWhitelist(ClearPendingWhitelistArgs),
 
     #[command(
         name = "whitelist-delegation-contract",
@@ -141,8 +141,11 @@ pub enum InteractCliCommand {
     )]
     GetDelegationAddressesList,
 
-    #[command(name = "get-addresses-to-claim", about = "Get the addresses to claim")]
-    GetAddressesToClaim,
+    #[command(
+        name = "get-remaining-claim-count",
+        about = "Get the number of delegation contracts left to claim"
+    )]
+    GetRemainingClaimCount,
 
     #[command(
         name = "get-delgation-claim-status",
@@ -205,6 +208,12 @@ pub struct RegisterTokenArgs {
     pub amount: u128,
 }
 
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ClearPendingWhitelistArgs {
+    #[arg(short = 'n', long = "contract")]
+    pub contract_address: String,
+}
+
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
 pub struct WhitelistDelegationContractArgs {
     #[arg(short = 'n', long = "amount")]
@@ -100,8 +100,10 @@ pub async fn cli() {
             interact.rewards_reserve().await
         }
         Some(interact_cli::InteractCliCommand::GetUnstakeTokenId) => interact.unstake_token().await,
-        Some(interact_cli::InteractCliCommand::ClearOngoingWhitelistOp) => {
-            interact.clear_ongoing_whitelist_op().await
+        Some(interact_cli::InteractCliCommand::ClearPendingWhitelist(args)) => {
+            interact
+                .clear_pending_whitelist(Bech32Address::from_bech32_string(args.contract_address))
+                .await
         }
         Some(interact_cli::InteractCliCommand::WhitelistDelegationContract(args)) => {
             interact
@@ -166,8 +168,8 @@ pub async fn cli() {
         Some(interact_cli::InteractCliCommand::GetDelegationAddressesList) => {
             interact.delegation_addresses_list().await
         }
-        Some(interact_cli::InteractCliCommand::GetAddressesToClaim) => {
-            interact.addresses_to_claim().await
+        Some(interact_cli::InteractCliCommand::GetRemainingClaimCount) => {
+            interact.remaining_claim_count().await
         }
         Some(interact_cli::InteractCliCommand::GetDelegationClaimStatus) => {
             interact.delegation_claim_status().await
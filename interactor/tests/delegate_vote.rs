@@ -48,6 +48,9 @@ async fn test_delegate_vote() {
 
     let root_hash = hex::decode(ROOT_HASH).unwrap();
     let proposal_id = 1u32;
+    interact
+        .register_proposal(proposal_id, 0u64, 1_000_000u64, false)
+        .await;
     interact
         .set_root_hash(
             ManagedByteArray::new_from_bytes(root_hash.as_slice().try_into().unwrap()),
@@ -80,7 +83,7 @@ async fn test_delegate_vote() {
             "yes",
             1_000_000_000_000_000_000u128,
             proof.clone(),
-            Some(ExpectError(4, "Invalid root hash provided")),
+            Some(ExpectError(4, "Proposal does not exist")),
         )
         .await;
 
@@ -99,6 +102,19 @@ async fn test_delegate_vote() {
 
     // delegate_vote that should pass
 
+    interact
+        .delegate_vote(
+            owner_address.clone(),
+            proposal_id,
+            "yes",
+            1_000_000_000_000_000_000u128,
+            proof.clone(),
+            None,
+        )
+        .await;
+
+    // delegate_vote attempt with the already used voting power
+
     interact
         .delegate_vote(
             owner_address,
@@ -106,7 +122,7 @@ async fn test_delegate_vote() {
             "yes",
             1_000_000_000_000_000_000u128,
             proof,
-            None,
+            Some(ExpectError(4, "Insufficient remaining voting power")),
         )
         .await;
 }
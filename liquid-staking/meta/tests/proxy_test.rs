@@ -0,0 +1,10 @@
+use multiversx_sc::contract_base::ContractAbiProvider;
+use multiversx_sc_meta_lib::contract::meta_config::MetaConfig;
+
+// fails when the endpoints changed without `cargo run proxy` being run in this folder
+#[test]
+fn liquid_staking_proxy_up_to_date_test() {
+    let abi = <liquid_staking::AbiProvider as ContractAbiProvider>::abi();
+    let mut meta_config = MetaConfig::create(abi, false);
+    meta_config.compare_proxy();
+}
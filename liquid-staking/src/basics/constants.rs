@@ -14,7 +14,40 @@ pub const RECOMPUTE_BLOCK_OFFSET: Blocks = 10;
 
 pub const MIN_EGLD_TO_DELEGATE: u64 = 1_000_000_000_000_000_000; // 1 EGLD
 pub const EGLD_TO_WHITELIST: u64 = 1_000_000_000_000_000_000; // 1 EGLD
+pub const WHITELIST_BOND: u64 = 5_000_000_000_000_000_000; // 5 EGLD
+
+pub const MAX_CLAIM_RETRIES: u32 = 3;
 
 pub const MINIMUM_LIQUIDITY: u64 = 1_000;
 
+pub const ONE_EGLD: u64 = 1_000_000_000_000_000_000;
+pub const UNSTAKE_TOKEN_NAME_DECIMALS: u32 = 4;
+pub const UNSTAKE_TOKEN_URI_NONCE_PLACEHOLDER: &[u8] = b"{nonce}";
+pub const MAX_ROYALTIES: u64 = 10_000;
+
+pub const MAX_MARKET_FEE_PERCENTAGE: u64 = 10_000; // 10%
+
+pub const MAX_PARTNERS: usize = 50;
+
+pub const VOTE_TYPES: [&[u8]; 4] = [b"yes", b"no", b"abstain", b"veto"];
+
+pub const BLS_KEY_LENGTH: usize = 96;
+pub const DELEGATION_CONFIG_OWNER_INDEX: usize = 0;
+pub const DELEGATION_CONFIG_CAP_INDEX: usize = 2;
+pub const DELEGATION_CONFIG_WITH_CAP_INDEX: usize = 5;
+pub const DELEGATION_NODE_STATUS_STAKED: &[u8] = b"staked";
+pub const DELEGATION_CONFIG_TRUE: &[u8] = b"true";
+
 pub const MAX_DELEGATION_ADDRESSES: usize = 20;
+pub const UNBOND_PERIOD: Epoch = 10;
+pub const WHITELIST_APPLICATION_EXPIRY: Epoch = 10;
+
+// bounds for the owner configurable parameters
+pub const MIN_DELEGATION_SC_AMOUNT: u64 = 1_000_000_000_000_000_000; // 1 EGLD
+pub const MAX_MIN_EGLD_TO_DELEGATE_EGLD: u64 = 100; // denominated in EGLD, as it exceeds the i64 range
+pub const MIN_WHITELIST_BOND: u64 = 1_000_000_000_000_000_000; // 1 EGLD
+pub const MAX_WHITELIST_BOND_EGLD: u64 = 100; // denominated in EGLD, as it exceeds the i64 range
+pub const MAX_UNBOND_PERIOD: Epoch = 30;
+pub const MAX_RECOMPUTE_BLOCK_OFFSET: Blocks = 14_400; // ~1 day
+pub const MAX_DELEGATION_ADDRESSES_LIMIT: usize = 1_000;
+pub const MAX_GAS_PER_TX: GasLimit = 600_000_000;
@@ -18,14 +18,15 @@ pub static ERROR_NOTHING_TO_UNBOUND: &[u8] = b"Nothing to unbond";
 
 pub static ERROR_BAD_PAYMENT_TOKEN: &[u8] = b"Bad payment token";
 pub static ERROR_BAD_PAYMENT_AMOUNT: &[u8] = b"Insufficient delegated amount";
-pub static ERROR_BAD_WHITELIST_FEE: &[u8] = b"Whitelisting contracts costs 1 EGLD";
+pub static ERROR_BAD_WHITELIST_FEE: &[u8] = b"Invalid whitelisting fee";
 pub static ERROR_INSUFFICIENT_UNSTAKE_AMOUNT: &[u8] = b"Insufficient unstake amount";
 pub static ERROR_INSUFFICIENT_LIQUIDITY: &[u8] = b"Insufficient liquidity minted";
 pub static ERROR_INSUFFICIENT_LIQ_BURNED: &[u8] = b"Insufficient liquidity burned";
 pub static ERROR_INVALID_SC_ADDRESS: &[u8] = b"Invalid SC address";
+pub static ERROR_INVALID_ROYALTIES: &[u8] = b"Invalid royalties value";
 
 pub static ERROR_BAD_DELEGATION_ADDRESS: &[u8] = b"No delegation contract available";
-pub static ERROR_BAD_DELEGATION_AMOUNT: &[u8] = b"Delegation amount must be at least 1 EGLD";
+pub static ERROR_BAD_DELEGATION_AMOUNT: &[u8] = b"Delegation amount is below the minimum";
 pub static ERROR_NO_DELEGATION_CONTRACTS: &[u8] = b"There are no delegation contracts whitelisted";
 pub static ERROR_FIRST_DELEGATION_NODE: &[u8] = b"The first delegation node is incorrect";
 pub static ERROR_ALREADY_WHITELISTED: &[u8] = b"Delegation contract already whitelisted";
@@ -41,9 +42,51 @@ pub static ERROR_VOTE_SC_NOT_SET: &[u8] = b"Vote contract is not set";
 pub static ERROR_MISSING_VOTING_POWER: &[u8] = b"invalid payment or missing voting power";
 
 pub static ERROR_CLEAR_WHITELIST: &[u8] = b"Whitelist operation cannot be cleared now";
-pub static ERROR_ANOTHER_WHITELIST_ONGOING: &[u8] = b"Another whitelisting is currently ongoing";
+pub static ERROR_ANOTHER_WHITELIST_ONGOING: &[u8] =
+    b"Whitelisting is already ongoing for this delegation contract";
 pub static ERROR_MAX_DELEGATION_ADDRESSES: &[u8] =
     b"Maximum number of delegation addresses reached";
 
 pub static ERROR_NOTHING_TO_CLAIM: &[u8] = b"Nothing to claim";
+pub static ERROR_LISTING_NOT_FOUND: &[u8] = b"Listing does not exist";
+pub static ERROR_ONLY_SELLER: &[u8] = b"Only the seller can cancel the listing";
+pub static ERROR_BAD_LISTING_PRICE: &[u8] = b"Listing price must be below the unstake amount";
+pub static ERROR_POSITION_ALREADY_MATURE: &[u8] =
+    b"Unstake position is mature and can be unbonded directly";
+pub static ERROR_BAD_MARKET_FEE: &[u8] = b"Market fee is too high";
+pub static ERROR_INSUFFICIENT_WRAPPED_BALANCE: &[u8] = b"Insufficient wrapped balance";
+pub static ERROR_PARTNER_ALREADY_REGISTERED: &[u8] = b"Partner is already registered";
+pub static ERROR_PARTNER_NOT_REGISTERED: &[u8] = b"Partner is not registered";
+pub static ERROR_MAX_PARTNERS: &[u8] = b"Maximum number of partners reached";
+pub static ERROR_BAD_PARTNER_SHARE: &[u8] = b"Partner share is too high";
+pub static ERROR_NO_PARTNER_TVL: &[u8] = b"There is no partner TVL to distribute to";
+pub static ERROR_CONTRACT_NOT_WHITELISTED: &[u8] = b"Caller contract is not whitelisted";
+pub static ERROR_WEGLD_SWAP_NOT_SET: &[u8] = b"WEGLD swap contract is not set";
+pub static ERROR_APPLICATION_EXISTS: &[u8] = b"Whitelist application already exists";
+pub static ERROR_APPLICATION_NOT_FOUND: &[u8] = b"Whitelist application does not exist";
+pub static ERROR_NO_PROVIDER_BOND: &[u8] = b"Delegation contract has no bond";
+pub static ERROR_APPLICATION_NOT_VERIFIED: &[u8] = b"Whitelist application is not verified yet";
+pub static ERROR_APPLICATION_NOT_EXPIRED: &[u8] = b"Whitelist application has not expired yet";
+pub static ERROR_ALREADY_VERIFIED_THIS_EPOCH: &[u8] =
+    b"Delegation contract was already verified this epoch";
+pub static ERROR_INSUFFICIENT_LOCKED_BALANCE: &[u8] = b"Insufficient locked balance";
+pub static ERROR_NO_VOTES_TO_CAST: &[u8] = b"There are no votes to cast";
+pub static ERROR_INVALID_VOTE_TYPE: &[u8] = b"Invalid vote type";
+pub static ERROR_INVALID_VOTING_WINDOW: &[u8] = b"Invalid voting window";
+pub static ERROR_PROTOCOL_PROPOSAL_EXISTS: &[u8] = b"Protocol proposal is already registered";
+pub static ERROR_PROTOCOL_PROPOSAL_NOT_FOUND: &[u8] = b"Protocol proposal does not exist";
+pub static ERROR_WRONG_PROTOCOL_VOTE_MODE: &[u8] = b"Not allowed in the current protocol vote mode";
+pub static ERROR_PROTOCOL_VOTING_NOT_OPEN: &[u8] = b"Protocol voting is not open";
+pub static ERROR_PROTOCOL_VOTING_NOT_ENDED: &[u8] = b"Protocol voting has not ended yet";
+pub static ERROR_ALREADY_VOTED: &[u8] = b"Already voted on this proposal";
+pub static ERROR_NO_VOTING_POWER: &[u8] = b"No voting power";
+pub static ERROR_NO_PROTOCOL_DECISION: &[u8] = b"Protocol vote is not decided";
+pub static ERROR_PROTOCOL_VOTE_ALREADY_CAST: &[u8] = b"Protocol vote was already cast";
+pub static ERROR_PROTOCOL_VOTED_PROPOSAL: &[u8] = b"Proposal is voted directly by the protocol";
+pub static ERROR_VOTE_CONTRACT_VOTED_PROPOSAL: &[u8] =
+    b"Proposal is already voted through the vote contract";
+pub static ERROR_AGGREGATED_VOTE_CAST: &[u8] = b"Aggregated vote was already cast";
+pub static ERROR_VOTING_POWER_EXCEEDED: &[u8] = b"Voting power exceeds the protocol stake";
 pub static ERROR_CANNOT_CLAIM_YET: &[u8] = b"Cannot claim yet";
+
+pub static ERROR_PARAMETER_OUT_OF_BOUNDS: &[u8] = b"Parameter value is out of bounds";
@@ -1,7 +1,11 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::contexts::base::StorageCache;
+use crate::{
+    contexts::base::StorageCache,
+    market::unstake_market::UnstakeListing,
+    setup::{onboarding::WhitelistApplication, protocol_vote::ProtocolVote},
+};
 
 #[type_abi]
 #[derive(TopEncode)]
@@ -36,6 +40,7 @@ pub struct RemoveLiquidityEvent<M: ManagedTypeApi> {
 #[multiversx_sc::module]
 pub trait EventsModule:
     crate::setup::config::ConfigModule
+    + crate::setup::parameters::ParametersModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     fn emit_add_liquidity_event(
@@ -109,6 +114,30 @@ pub trait EventsModule:
         remove_liquidity_event: &RemoveLiquidityEvent<Self::Api>,
     );
 
+    #[event("wrap_ls_token")]
+    fn wrap_ls_token_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] ls_token_amount: &BigUint,
+        rebasing_amount: &BigUint,
+    );
+
+    #[event("unwrap_ls_token")]
+    fn unwrap_ls_token_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] ls_token_amount: &BigUint,
+        rebasing_amount: &BigUint,
+    );
+
+    #[event("partner_share_distributed")]
+    fn partner_share_distributed_event(
+        &self,
+        #[indexed] epoch: u64,
+        #[indexed] total_partner_tvl: &BigUint,
+        distributed_amount: &BigUint,
+    );
+
     #[event("successful_claim")]
     fn successful_claim_event(
         &self,
@@ -116,6 +145,121 @@ pub trait EventsModule:
         #[indexed] caller: &ManagedAddress,
     );
 
+    #[event("whitelist_application")]
+    fn whitelist_application_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        application: &WhitelistApplication<Self::Api>,
+    );
+
+    #[event("provider_bond_slashed")]
+    fn provider_bond_slashed_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        bond: &BigUint,
+    );
+
+    #[event("provider_bond_released")]
+    fn provider_bond_released_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] admin_address: &ManagedAddress,
+        bond: &BigUint,
+    );
+
+    #[event("whitelist_application_rejected")]
+    fn whitelist_application_rejected_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] applicant: &ManagedAddress,
+    );
+
+    #[event("whitelist_application_withdrawn")]
+    fn whitelist_application_withdrawn_event(
+        &self,
+        #[indexed] contract_address: &ManagedAddress,
+        #[indexed] applicant: &ManagedAddress,
+    );
+
     #[event("failed_claim")]
     fn failed_claim_event(&self, #[indexed] caller: &ManagedAddress);
+
+    #[event("claim_rewards_progress")]
+    fn claim_rewards_progress_event(
+        &self,
+        #[indexed] epoch: u64,
+        #[indexed] processed_count: usize,
+        remaining_count: usize,
+    );
+
+    #[event("market_fee_changed")]
+    fn market_fee_changed_event(&self, #[indexed] fee_percentage: u64);
+
+    #[event("unstake_token_listed")]
+    fn unstake_token_listed_event(
+        &self,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] token_nonce: u64,
+        listing: &UnstakeListing<Self::Api>,
+    );
+
+    #[event("unstake_listing_cancelled")]
+    fn unstake_listing_cancelled_event(
+        &self,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] token_nonce: u64,
+    );
+
+    #[event("unstake_token_sold")]
+    fn unstake_token_sold_event(
+        &self,
+        #[indexed] buyer: &ManagedAddress,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] token_nonce: u64,
+        market_fee: &BigUint,
+    );
+
+    #[event("vote_lock")]
+    fn vote_lock_event(&self, #[indexed] user: &ManagedAddress, amount: &BigUint);
+
+    #[event("vote_unlock")]
+    fn vote_unlock_event(&self, #[indexed] user: &ManagedAddress, amount: &BigUint);
+
+    #[event("protocol_proposal_vote")]
+    fn protocol_proposal_vote_event(
+        &self,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] proposal: u32,
+        #[indexed] vote_type: &ManagedBuffer,
+        voting_power: &BigUint,
+    );
+
+    #[event("protocol_vote_cast")]
+    fn protocol_vote_cast_event(
+        &self,
+        #[indexed] proposal: u32,
+        protocol_vote: &ProtocolVote<Self::Api>,
+    );
+
+    #[event("protocol_vote_failed")]
+    fn protocol_vote_failed_event(&self, #[indexed] proposal: u32);
+
+    #[event("aggregated_vote_cast")]
+    fn aggregated_vote_cast_event(
+        &self,
+        #[indexed] proposal: u32,
+        #[indexed] vote_type: &ManagedBuffer,
+        voting_power: &BigUint,
+    );
+
+    #[event("aggregated_vote_failed")]
+    fn aggregated_vote_failed_event(&self, #[indexed] proposal: u32);
+
+    #[event("delegate_vote_failed")]
+    fn delegate_vote_failed_event(
+        &self,
+        #[indexed] proposal: u32,
+        #[indexed] delegate_to: &ManagedAddress,
+        voting_power: &BigUint,
+    );
 }
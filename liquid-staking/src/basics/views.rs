@@ -5,6 +5,7 @@ use crate::{basics::errors::ERROR_INSUFFICIENT_LIQ_BURNED, liquidity_pool, setup
 #[multiversx_sc::module]
 pub trait ViewsModule:
     setup::config::ConfigModule
+    + setup::parameters::ParametersModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + liquidity_pool::LiquidityPoolModule
 {
@@ -2,8 +2,8 @@ multiversx_sc::imports!();
 
 use crate::{
     basics::{
-        constants::{DEFAULT_GAS_TO_CLAIM_REWARDS, DEFAULT_MIN_GAS_TO_SAVE_PROGRESS},
-        errors::{ERROR_NOT_ACTIVE, ERROR_NO_DELEGATION_CONTRACTS},
+        constants::{GasLimit, MAX_CLAIM_RETRIES, MAX_GAS_PER_TX},
+        errors::{ERROR_NOT_ACTIVE, ERROR_NO_DELEGATION_CONTRACTS, ERROR_PARAMETER_OUT_OF_BOUNDS},
         events,
     },
     setup::{self, delegation::ClaimStatusType},
@@ -13,12 +13,21 @@ use crate::{
 #[multiversx_sc::module]
 pub trait ClaimModule:
     setup::config::ConfigModule
+    + setup::parameters::ParametersModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + setup::delegation::DelegationModule
     + events::EventsModule
 {
+    // a zero gas limit removes the provider specific setting
+    #[only_owner]
+    #[endpoint(setProviderClaimGas)]
+    fn set_provider_claim_gas(&self, delegation_address: ManagedAddress, gas_limit: GasLimit) {
+        require!(gas_limit <= MAX_GAS_PER_TX, ERROR_PARAMETER_OUT_OF_BOUNDS);
+        self.provider_claim_gas(&delegation_address).set(gas_limit);
+    }
+
     #[endpoint(claimRewards)]
-    fn claim_rewards(&self) {
+    fn claim_rewards(&self) -> MultiValue2<usize, usize> {
         let storage_cache = StorageCache::new(self);
 
         require!(
@@ -31,43 +40,118 @@ pub trait ClaimModule:
             !delegation_addresses_mapper.is_empty(),
             ERROR_NO_DELEGATION_CONTRACTS
         );
+
+        // the failed claims of a finished claim operation are retried in the same epoch,
+        // the next claim operation walks all the delegation contracts again
+        let claim_cursor_mapper = self.claim_cursor();
         let claim_status_mapper = self.delegation_claim_status();
         let old_claim_status = claim_status_mapper.get();
         let current_epoch = self.blockchain().get_block_epoch();
+        if claim_cursor_mapper.get() == 0
+            && !self.failed_claims().is_empty()
+            && old_claim_status.last_claim_epoch == current_epoch
+        {
+            return self.retry_failed_claims();
+        }
 
         self.check_claim_operation(old_claim_status, current_epoch);
-        let mut delegation_addresses = self.addresses_to_claim();
-        if delegation_addresses.is_empty() {
+        if claim_cursor_mapper.get() == 0 {
+            self.clear_failed_claims();
             self.prepare_claim_operation();
         }
 
-        while !delegation_addresses.is_empty() {
-            let gas_left = self.blockchain().get_gas_left();
-            if gas_left < DEFAULT_MIN_GAS_TO_SAVE_PROGRESS {
+        let mut claim_cursor = claim_cursor_mapper.get();
+        let mut processed_count = 0;
+        while claim_cursor != 0 {
+            let current_node = delegation_addresses_mapper
+                .get_node_by_id(claim_cursor)
+                .unwrap();
+            let address = current_node.get_value_cloned();
+            if !self.has_gas_to_claim(&address) {
                 break;
             }
 
-            let current_node = delegation_addresses.pop_back().unwrap();
-            let address = current_node.clone().into_value();
-
-            self.tx()
-                .to(address.clone())
-                .typed(DelegationSCProxy)
-                .claim_rewards()
-                .gas(DEFAULT_GAS_TO_CLAIM_REWARDS)
-                .callback(ClaimModule::callbacks(self).claim_rewards_callback(address))
-                .register_promise();
-
-            delegation_addresses.remove_node(&current_node);
+            self.call_claim_rewards(address);
+            claim_cursor = current_node.get_next_node_id();
+            processed_count += 1;
         }
+        claim_cursor_mapper.set(claim_cursor);
 
-        if delegation_addresses.is_empty() {
+        let remaining_count = self.remaining_claim_count().update(|count| {
+            *count = count.saturating_sub(processed_count);
+            *count
+        });
+        if claim_cursor == 0 {
             claim_status_mapper.update(|claim_status| {
                 claim_status.status = ClaimStatusType::Finished;
                 claim_status.last_claim_block = self.blockchain().get_block_nonce();
                 claim_status.last_claim_epoch = self.blockchain().get_block_epoch();
             });
         }
+
+        self.claim_rewards_progress_event(current_epoch, processed_count, remaining_count);
+        (processed_count, remaining_count).into()
+    }
+
+    fn retry_failed_claims(&self) -> MultiValue2<usize, usize> {
+        let mut failed_claims = self.failed_claims();
+        let mut processed_count = 0;
+        while !failed_claims.is_empty() {
+            let address = failed_claims.get_by_index(1);
+            if !self.has_gas_to_claim(&address) {
+                break;
+            }
+
+            failed_claims.swap_remove(&address);
+            self.failed_claim_retries(&address)
+                .update(|retries| *retries += 1);
+            self.call_claim_rewards(address);
+            processed_count += 1;
+        }
+
+        let remaining_count = failed_claims.len();
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.claim_rewards_progress_event(current_epoch, processed_count, remaining_count);
+        (processed_count, remaining_count).into()
+    }
+
+    fn clear_failed_claims(&self) {
+        let mut failed_claims = self.failed_claims();
+        for address in failed_claims.iter() {
+            self.failed_claim_retries(&address).clear();
+        }
+        failed_claims.clear();
+    }
+
+    // the gas kept to save progress on top of the default claim gas
+    // is kept on top of the provider specific claim gas as well
+    fn has_gas_to_claim(&self, delegation_address: &ManagedAddress) -> bool {
+        let gas_to_save_progress =
+            self.min_gas_to_save_progress().get() - self.gas_to_claim_rewards().get();
+        let gas_left = self.blockchain().get_gas_left();
+
+        gas_left >= self.get_provider_claim_gas(delegation_address.clone()) + gas_to_save_progress
+    }
+
+    fn call_claim_rewards(&self, delegation_address: ManagedAddress) {
+        let gas_to_claim_rewards = self.get_provider_claim_gas(delegation_address.clone());
+        self.tx()
+            .to(delegation_address.clone())
+            .typed(DelegationSCProxy)
+            .claim_rewards()
+            .gas(gas_to_claim_rewards)
+            .callback(ClaimModule::callbacks(self).claim_rewards_callback(delegation_address))
+            .register_promise();
+    }
+
+    #[view(getProviderClaimGas)]
+    fn get_provider_claim_gas(&self, delegation_address: ManagedAddress) -> GasLimit {
+        let provider_claim_gas = self.provider_claim_gas(&delegation_address).get();
+        if provider_claim_gas > 0 {
+            return provider_claim_gas;
+        }
+
+        self.gas_to_claim_rewards().get()
     }
 
     #[promises_callback]
@@ -78,13 +162,35 @@ pub trait ClaimModule:
     ) {
         match result {
             ManagedAsyncCallResult::Ok(_) => {
+                self.failed_claim_retries(&delegation_address).clear();
                 let payment = self.call_value().egld().clone_value();
                 self.rewards_reserve().update(|value| *value += &payment);
                 self.successful_claim_event(payment, &delegation_address);
             }
             ManagedAsyncCallResult::Err(_) => {
+                // a delegation contract that keeps failing is dropped until the next claim operation
+                let retries_mapper = self.failed_claim_retries(&delegation_address);
+                if retries_mapper.get() < MAX_CLAIM_RETRIES {
+                    self.failed_claims().insert(delegation_address.clone());
+                } else {
+                    retries_mapper.clear();
+                }
                 self.failed_claim_event(&delegation_address);
             }
         }
     }
+
+    #[storage_mapper("providerClaimGas")]
+    fn provider_claim_gas(
+        &self,
+        delegation_address: &ManagedAddress,
+    ) -> SingleValueMapper<GasLimit>;
+
+    #[view(getFailedClaimRetries)]
+    #[storage_mapper("failedClaimRetries")]
+    fn failed_claim_retries(&self, delegation_address: &ManagedAddress) -> SingleValueMapper<u32>;
+
+    #[view(getFailedClaims)]
+    #[storage_mapper("failedClaims")]
+    fn failed_claims(&self) -> UnorderedSetMapper<ManagedAddress>;
 }
@@ -2,7 +2,6 @@ multiversx_sc::imports!();
 
 use crate::{
     basics,
-    basics::constants::{MIN_EGLD_TO_DELEGATE, MIN_GAS_FOR_CALLBACK},
     basics::errors::{ERROR_BAD_DELEGATION_AMOUNT, ERROR_CLAIM_REDELEGATE, ERROR_NOT_ACTIVE},
     setup::{self, delegation::ClaimStatusType},
     StorageCache,
@@ -11,6 +10,7 @@ use crate::{
 #[multiversx_sc::module]
 pub trait DelegateRewardsModule:
     setup::config::ConfigModule
+    + setup::parameters::ParametersModule
     + setup::delegation::DelegationModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + basics::events::EventsModule
@@ -31,7 +31,7 @@ pub trait DelegateRewardsModule:
         let rewards_reserve = self.rewards_reserve().take();
 
         require!(
-            rewards_reserve >= MIN_EGLD_TO_DELEGATE,
+            rewards_reserve >= self.min_egld_to_delegate().get(),
             ERROR_BAD_DELEGATION_AMOUNT
         );
         self.call_delegate(rewards_reserve);
@@ -50,6 +50,7 @@ pub trait DelegateRewardsModule:
                     .update(|contract_data| {
                         contract_data.total_staked_from_ls_contract += &staked_tokens;
                     });
+                self.restore_delegation_priority(&delegation_contract);
 
                 self.delegation_claim_status()
                     .update(|claim_status| claim_status.status = ClaimStatusType::Redelegated);
@@ -81,7 +82,7 @@ pub trait DelegateRewardsModule:
                 DelegateRewardsModule::callbacks(self)
                     .delegate_rewards_callback(delegation_contract, rewards_reserve),
             )
-            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+            .gas_for_callback(self.min_gas_for_callback().get())
             .register_promise();
     }
 }
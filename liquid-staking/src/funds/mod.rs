@@ -2,4 +2,5 @@ pub mod claim;
 pub mod delegate_rewards;
 pub mod recompute_token_reserve;
 pub mod unbond;
+pub mod undelegation;
 pub mod withdraw;
@@ -1,7 +1,6 @@
 multiversx_sc::imports!();
 
 use crate::{
-    basics::constants::{MIN_EGLD_TO_DELEGATE, RECOMPUTE_BLOCK_OFFSET},
     basics::errors::{ERROR_NOT_ACTIVE, ERROR_RECOMPUTE_RESERVES, ERROR_RECOMPUTE_TOO_SOON},
     setup::{self, delegation::ClaimStatusType},
     StorageCache,
@@ -10,6 +9,7 @@ use crate::{
 #[multiversx_sc::module]
 pub trait RecomputeTokenReserveModule:
     setup::config::ConfigModule
+    + setup::parameters::ParametersModule
     + setup::delegation::DelegationModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
@@ -30,11 +30,11 @@ pub trait RecomputeTokenReserveModule:
 
         let current_block = self.blockchain().get_block_nonce();
         require!(
-            current_block >= claim_status.last_claim_block + RECOMPUTE_BLOCK_OFFSET,
+            current_block >= claim_status.last_claim_block + self.recompute_block_offset().get(),
             ERROR_RECOMPUTE_TOO_SOON
         );
 
-        if self.rewards_reserve().get() >= MIN_EGLD_TO_DELEGATE {
+        if self.rewards_reserve().get() >= self.min_egld_to_delegate().get() {
             claim_status.status = ClaimStatusType::Delegable;
         } else {
             claim_status.status = ClaimStatusType::Insufficient;
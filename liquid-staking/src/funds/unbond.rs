@@ -5,7 +5,7 @@ use crate::{
         ERROR_BAD_PAYMENT_AMOUNT, ERROR_BAD_PAYMENT_TOKEN, ERROR_NOTHING_TO_UNBOUND,
         ERROR_NOT_ACTIVE, ERROR_UNSTAKE_PERIOD_NOT_PASSED,
     },
-    liquidity_pool,
+    funds, liquidity_pool,
     setup::{self, config::UnstakeTokenAttributes},
     StorageCache,
 };
@@ -13,17 +13,35 @@ use crate::{
 #[multiversx_sc::module]
 pub trait UnbondModule:
     setup::config::ConfigModule
+    + setup::parameters::ParametersModule
     + setup::delegation::DelegationModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + liquidity_pool::LiquidityPoolModule
+    + funds::undelegation::UndelegationModule
+    + setup::integrators::IntegratorsModule
+    + setup::wegld::WegldModule
 {
     #[payable("*")]
     #[endpoint(unbondTokens)]
     fn unbond_tokens(&self) {
         self.blockchain().check_caller_is_user_account();
+        let caller = self.blockchain().get_caller();
+        self.process_unbond_tokens(&caller);
+    }
+
+    // contract friendly variant, the unbonded EGLD is sent to the receiver
+    // and returned as the endpoint result
+    #[payable("*")]
+    #[endpoint(unbondTokensFor)]
+    fn unbond_tokens_for(&self, receiver: ManagedAddress) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        self.require_caller_allowed(&caller);
+        self.process_unbond_tokens(&receiver)
+    }
+
+    fn process_unbond_tokens(&self, receiver: &ManagedAddress) -> BigUint {
         let storage_cache = StorageCache::new(self);
         let payment = self.call_value().single_esdt();
-        let caller = self.blockchain().get_caller();
 
         require!(
             self.is_state_active(storage_cache.contract_state),
@@ -36,20 +54,30 @@ pub trait UnbondModule:
         );
         require!(payment.amount > 0, ERROR_BAD_PAYMENT_AMOUNT);
 
-        let unstake_token_attributes: UnstakeTokenAttributes<Self::Api> = self
-            .unstake_token()
-            .get_token_attributes(payment.token_nonce);
+        let unstake_token_attributes = self.get_unstake_token_attributes(payment.token_nonce);
 
-        let current_epoch = self.blockchain().get_block_epoch();
         require!(
-            current_epoch >= unstake_token_attributes.unbond_epoch,
+            self.is_unstake_position_mature(&unstake_token_attributes),
             ERROR_UNSTAKE_PERIOD_NOT_PASSED
         );
 
+        let wegld_payout = self.wegld_payout(payment.token_nonce).take();
         let total_unstake_amount =
             self.handle_unstake_amount(unstake_token_attributes, payment.token_nonce);
 
-        self.send().direct_egld(&caller, &total_unstake_amount);
+        if wegld_payout {
+            let wegld_payment = self.wrap_egld(&total_unstake_amount);
+            self.send().direct_esdt(
+                receiver,
+                &wegld_payment.token_identifier,
+                wegld_payment.token_nonce,
+                &wegld_payment.amount,
+            );
+        } else {
+            self.send().direct_egld(receiver, &total_unstake_amount);
+        }
+
+        total_unstake_amount
     }
 
     fn handle_unstake_amount(
@@ -0,0 +1,181 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    basics::constants::Epoch,
+    setup::{self, config::UnstakeTokenAttributes},
+};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct UndelegationBatch<M: ManagedTypeApi> {
+    pub unstake_epoch: Epoch,
+    pub amount: BigUint<M>,
+    pub withdrawn_amount: BigUint<M>,
+    pub withdraw_epoch: Option<Epoch>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct UnstakeTokenMaturity {
+    pub expected_unbond_epoch: Epoch,
+    pub actual_unbond_epoch: Option<Epoch>,
+}
+
+#[multiversx_sc::module]
+pub trait UndelegationModule:
+    setup::config::ConfigModule
+    + setup::parameters::ParametersModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + setup::delegation::DelegationModule
+{
+    fn record_undelegation(&self, provider: &ManagedAddress, amount: &BigUint) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let batch_mapper = self.undelegation_batch(provider, current_epoch);
+        if batch_mapper.is_empty() {
+            batch_mapper.set(UndelegationBatch {
+                unstake_epoch: current_epoch,
+                amount: amount.clone(),
+                withdrawn_amount: BigUint::zero(),
+                withdraw_epoch: None,
+            });
+            self.pending_undelegation_epochs(provider)
+                .push_back(current_epoch);
+        } else {
+            batch_mapper.update(|batch| batch.amount += amount);
+        }
+    }
+
+    // the delegation contract releases undelegated funds in the order they were undelegated,
+    // so the withdrawn amount goes to the untracked undelegations first, as they are older
+    // than any batch, then to the oldest pending batches
+    fn record_withdrawal(&self, provider: &ManagedAddress, withdraw_amount: &BigUint) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut pending_epochs = self.pending_undelegation_epochs(provider);
+        let mut amount_left = withdraw_amount.clone();
+
+        let untracked_mapper = self.untracked_undelegation(provider);
+        let untracked_amount = untracked_mapper.get();
+        if untracked_amount > 0 {
+            let untracked_withdrawn = core::cmp::min(untracked_amount.clone(), amount_left.clone());
+            untracked_mapper.set(&untracked_amount - &untracked_withdrawn);
+            amount_left -= untracked_withdrawn;
+        }
+
+        while amount_left > 0 {
+            let Some(front_node) = pending_epochs.front() else {
+                break;
+            };
+            let unstake_epoch = front_node.into_value();
+            let batch_mapper = self.undelegation_batch(provider, unstake_epoch);
+            let mut batch = batch_mapper.get();
+
+            let amount_to_complete = &batch.amount - &batch.withdrawn_amount;
+            if amount_left < amount_to_complete {
+                batch.withdrawn_amount += &amount_left;
+                batch_mapper.set(batch);
+                break;
+            }
+
+            amount_left -= &amount_to_complete;
+            batch.withdrawn_amount = batch.amount.clone();
+            batch.withdraw_epoch = Some(current_epoch);
+            batch_mapper.set(batch);
+            pending_epochs.remove_node(&front_node);
+        }
+    }
+
+    // undelegations made before the batches were tracked are still pending in the delegation
+    // contracts without a batch; their amount is the unstaked amount neither withdrawn
+    // nor covered by the pending batches
+    fn seed_untracked_undelegations(&self) {
+        for delegation_address_node in self.delegation_addresses_list().iter() {
+            let provider = delegation_address_node.into_value();
+            let contract_data = self.delegation_contract_data(&provider).get();
+            let mut accounted_amount = contract_data.total_unbonded_from_ls_contract;
+            for unstake_epoch in self.pending_undelegation_epochs(&provider).iter() {
+                let batch = self
+                    .undelegation_batch(&provider, unstake_epoch.into_value())
+                    .get();
+                accounted_amount += batch.amount - batch.withdrawn_amount;
+            }
+
+            let untracked_mapper = self.untracked_undelegation(&provider);
+            let unstaked_amount = contract_data.total_unstaked_from_ls_contract;
+            if unstaked_amount > accounted_amount {
+                untracked_mapper.set(unstaked_amount - accounted_amount);
+            } else {
+                untracked_mapper.clear();
+            }
+        }
+    }
+
+    fn is_unstake_position_mature(
+        &self,
+        unstake_token_attributes: &UnstakeTokenAttributes<Self::Api>,
+    ) -> bool {
+        let batch_mapper = self.undelegation_batch(
+            &unstake_token_attributes.delegation_contract,
+            unstake_token_attributes.unstake_epoch,
+        );
+
+        // positions created before batches were tracked rely on the fixed unbond period
+        if batch_mapper.is_empty() {
+            let current_epoch = self.blockchain().get_block_epoch();
+            return current_epoch >= unstake_token_attributes.unbond_epoch;
+        }
+
+        batch_mapper.get().withdraw_epoch.is_some()
+    }
+
+    #[view(getUnstakeTokenMaturity)]
+    fn get_unstake_token_maturity(&self, token_nonce: u64) -> UnstakeTokenMaturity {
+        let unstake_token_attributes = self.get_unstake_token_attributes(token_nonce);
+        let batch_mapper = self.undelegation_batch(
+            &unstake_token_attributes.delegation_contract,
+            unstake_token_attributes.unstake_epoch,
+        );
+
+        let actual_unbond_epoch = if batch_mapper.is_empty() {
+            None
+        } else {
+            batch_mapper.get().withdraw_epoch
+        };
+
+        UnstakeTokenMaturity {
+            expected_unbond_epoch: unstake_token_attributes.unbond_epoch,
+            actual_unbond_epoch,
+        }
+    }
+
+    #[view(getPendingUndelegations)]
+    fn get_pending_undelegations(
+        &self,
+        provider: ManagedAddress,
+    ) -> MultiValueEncoded<UndelegationBatch<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for unstake_epoch in self.pending_undelegation_epochs(&provider).iter() {
+            result.push(
+                self.undelegation_batch(&provider, unstake_epoch.into_value())
+                    .get(),
+            );
+        }
+
+        result
+    }
+
+    #[view(getUndelegationBatch)]
+    #[storage_mapper("undelegationBatch")]
+    fn undelegation_batch(
+        &self,
+        provider: &ManagedAddress,
+        unstake_epoch: Epoch,
+    ) -> SingleValueMapper<UndelegationBatch<Self::Api>>;
+
+    #[view(getUntrackedUndelegation)]
+    #[storage_mapper("untrackedUndelegation")]
+    fn untracked_undelegation(&self, provider: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("pendingUndelegationEpochs")]
+    fn pending_undelegation_epochs(&self, provider: &ManagedAddress) -> LinkedListMapper<Epoch>;
+}
@@ -1,18 +1,20 @@
 multiversx_sc::imports!();
 
-use crate::{
-    basics::constants::MIN_GAS_FOR_CALLBACK, basics::errors::ERROR_NOT_ACTIVE, setup, StorageCache,
-};
+use crate::{basics::errors::ERROR_NOT_ACTIVE, funds, setup, StorageCache};
 
 #[multiversx_sc::module]
 pub trait WithdrawModule:
     setup::config::ConfigModule
+    + setup::parameters::ParametersModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + setup::delegation::DelegationModule
+    + funds::undelegation::UndelegationModule
+    + setup::integrators::IntegratorsModule
 {
     #[endpoint(withdrawAll)]
     fn withdraw_all(&self, delegation_contract: ManagedAddress) {
-        self.blockchain().check_caller_is_user_account();
+        let caller = self.blockchain().get_caller();
+        self.require_caller_allowed(&caller);
         let storage_cache = StorageCache::new(self);
 
         require!(
@@ -39,6 +41,7 @@ pub trait WithdrawModule:
                     .update(|contract_data| {
                         contract_data.total_unbonded_from_ls_contract += &withdraw_amount
                     });
+                self.record_withdrawal(&provider, &withdraw_amount);
             }
             ManagedAsyncCallResult::Err(_) => {}
         }
@@ -52,7 +55,7 @@ pub trait WithdrawModule:
             .withdraw()
             .gas(gas_for_async_call)
             .callback(WithdrawModule::callbacks(self).withdraw_tokens_callback(delegation_contract))
-            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+            .gas_for_callback(self.min_gas_for_callback().get())
             .register_promise();
     }
 }
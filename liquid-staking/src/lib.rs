@@ -6,8 +6,13 @@ multiversx_sc::derive_imports!();
 pub mod basics;
 pub mod contexts;
 pub mod funds;
+#[rustfmt::skip]
+pub mod liquid_staking_proxy;
 pub mod liquidity;
 pub mod liquidity_pool;
+pub mod market;
+pub mod partners;
+pub mod proxies;
 pub mod setup;
 
 use setup::delegation::{ClaimStatus, ClaimStatusType};
@@ -21,15 +26,27 @@ pub trait LiquidStaking:
     + basics::views::ViewsModule
     + setup::config::ConfigModule
     + setup::delegation::DelegationModule
+    + setup::integrators::IntegratorsModule
+    + setup::onboarding::OnboardingModule
+    + setup::parameters::ParametersModule
+    + setup::protocol_vote::ProtocolVoteModule
+    + setup::verification::VerificationModule
     + setup::vote::VoteModule
+    + setup::vote_lock::VoteLockModule
+    + setup::wegld::WegldModule
     + funds::claim::ClaimModule
     + funds::delegate_rewards::DelegateRewardsModule
     + funds::recompute_token_reserve::RecomputeTokenReserveModule
     + funds::unbond::UnbondModule
+    + funds::undelegation::UndelegationModule
     + funds::withdraw::WithdrawModule
     + liquidity::add_liquidity::AddLiquidityModule
+    + liquidity::rebasing_wrapper::RebasingWrapperModule
     + liquidity::remove_liquidity::RemoveLiquidityModule
     + liquidity_pool::LiquidityPoolModule
+    + market::unstake_market::UnstakeMarketModule
+    + partners::partner_share::PartnerShareModule
+    + partners::referral::ReferralModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     #[init]
@@ -43,8 +60,13 @@ pub trait LiquidStaking:
             last_claim_block: current_round,
         };
         self.delegation_claim_status().set_if_empty(claim_status);
+        self.init_parameters();
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.init_parameters();
+        self.index_delegation_addresses();
+        self.seed_untracked_undelegations();
+    }
 }
@@ -0,0 +1,1989 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct LiquidStakingProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for LiquidStakingProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = LiquidStakingProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        LiquidStakingProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct LiquidStakingProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> LiquidStakingProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> LiquidStakingProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> LiquidStakingProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn get_ls_value_for_position<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        ls_token_amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLsValueForPosition")
+            .argument(&ls_token_amount)
+            .original_result()
+    }
+
+    pub fn register_ls_token<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        token_display_name: Arg0,
+        token_ticker: Arg1,
+        num_decimals: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("registerLsToken")
+            .argument(&token_display_name)
+            .argument(&token_ticker)
+            .argument(&num_decimals)
+            .original_result()
+    }
+
+    pub fn register_unstake_token<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        token_display_name: Arg0,
+        token_ticker: Arg1,
+        num_decimals: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("registerUnstakeToken")
+            .argument(&token_display_name)
+            .argument(&token_ticker)
+            .argument(&num_decimals)
+            .original_result()
+    }
+
+    pub fn set_unstake_token_metadata<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        uri_template: Arg0,
+        royalties: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setUnstakeTokenMetadata")
+            .argument(&uri_template)
+            .argument(&royalties)
+            .original_result()
+    }
+
+    pub fn state(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, State> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getState")
+            .original_result()
+    }
+
+    pub fn ls_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLsTokenId")
+            .original_result()
+    }
+
+    pub fn ls_token_supply(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLsSupply")
+            .original_result()
+    }
+
+    pub fn virtual_egld_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVirtualEgldReserve")
+            .original_result()
+    }
+
+    pub fn rewards_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsReserve")
+            .original_result()
+    }
+
+    pub fn unstake_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnstakeTokenId")
+            .original_result()
+    }
+
+    pub fn unstake_token_uri_template(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnstakeTokenUriTemplate")
+            .original_result()
+    }
+
+    pub fn unstake_token_royalties(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnstakeTokenRoyalties")
+            .original_result()
+    }
+
+    pub fn delegated_voting_power<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegatedVotingPower")
+            .argument(&proposal)
+            .original_result()
+    }
+
+    pub fn aggregated_vote_cast<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAggregatedVoteCast")
+            .argument(&proposal)
+            .original_result()
+    }
+
+    pub fn clear_pending_whitelist<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearPendingWhitelist")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn whitelist_delegation_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+    >(
+        self,
+        contract_address: Arg0,
+        admin_address: Arg1,
+        total_staked: Arg2,
+        delegation_contract_cap: Arg3,
+        nr_nodes: Arg4,
+        apy: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("whitelistDelegationContract")
+            .argument(&contract_address)
+            .argument(&admin_address)
+            .argument(&total_staked)
+            .argument(&delegation_contract_cap)
+            .argument(&nr_nodes)
+            .argument(&apy)
+            .original_result()
+    }
+
+    pub fn change_delegation_contract_admin<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+        admin_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("changeDelegationContractAdmin")
+            .argument(&contract_address)
+            .argument(&admin_address)
+            .original_result()
+    }
+
+    pub fn change_delegation_contract_params<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        contract_address: Arg0,
+        total_staked: Arg1,
+        delegation_contract_cap: Arg2,
+        nr_nodes: Arg3,
+        apy: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("changeDelegationContractParams")
+            .argument(&contract_address)
+            .argument(&total_staked)
+            .argument(&delegation_contract_cap)
+            .argument(&nr_nodes)
+            .argument(&apy)
+            .original_result()
+    }
+
+    pub fn get_delegation_addresses_page<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from_node_id: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u32, ManagedVec<Env::Api, ManagedAddress<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegationAddressesPage")
+            .argument(&from_node_id)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_delegation_status(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ClaimStatusType> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegationStatus")
+            .original_result()
+    }
+
+    pub fn get_delegation_contract_staked_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegation_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegationContractStakedAmount")
+            .argument(&delegation_address)
+            .original_result()
+    }
+
+    pub fn get_delegation_contract_unstaked_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegation_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegationContractUnstakedAmount")
+            .argument(&delegation_address)
+            .original_result()
+    }
+
+    pub fn get_delegation_contract_unbonded_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegation_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegationContractUnbondedAmount")
+            .argument(&delegation_address)
+            .original_result()
+    }
+
+    pub fn set_state_active(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStateActive")
+            .original_result()
+    }
+
+    pub fn set_state_inactive(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStateInactive")
+            .original_result()
+    }
+
+    pub fn delegation_addresses_list(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegationAddressesList")
+            .original_result()
+    }
+
+    pub fn claim_cursor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimCursor")
+            .original_result()
+    }
+
+    pub fn remaining_claim_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRemainingClaimCount")
+            .original_result()
+    }
+
+    pub fn delegation_apys(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegationApys")
+            .original_result()
+    }
+
+    pub fn apy_bucket<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        apy: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getApyBucket")
+            .argument(&apy)
+            .original_result()
+    }
+
+    pub fn low_priority_delegation_addresses(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLowPriorityDelegationAddresses")
+            .original_result()
+    }
+
+    pub fn delegation_claim_status(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ClaimStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegationClaimStatus")
+            .original_result()
+    }
+
+    pub fn delegation_contract_data<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DelegationContractData<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegationContractData")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn provider_bond<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProviderBond")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn pending_whitelist<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PendingWhitelist<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingWhitelist")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn pending_whitelist_addresses(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingWhitelistAddresses")
+            .original_result()
+    }
+
+    pub fn set_integrator_whitelist_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setIntegratorWhitelistEnabled")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    pub fn add_integrators<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        integrators: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addIntegrators")
+            .argument(&integrators)
+            .original_result()
+    }
+
+    pub fn remove_integrators<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        integrators: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeIntegrators")
+            .argument(&integrators)
+            .original_result()
+    }
+
+    pub fn is_integrator_whitelist_enabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isIntegratorWhitelistEnabled")
+            .original_result()
+    }
+
+    pub fn integrator_whitelist(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIntegratorWhitelist")
+            .original_result()
+    }
+
+    pub fn apply_for_whitelist<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        contract_address: Arg0,
+        total_staked: Arg1,
+        delegation_contract_cap: Arg2,
+        nr_nodes: Arg3,
+        apy: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("applyForWhitelist")
+            .argument(&contract_address)
+            .argument(&total_staked)
+            .argument(&delegation_contract_cap)
+            .argument(&nr_nodes)
+            .argument(&apy)
+            .original_result()
+    }
+
+    pub fn approve_whitelist_application<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveWhitelistApplication")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn reject_whitelist_application<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rejectWhitelistApplication")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn withdraw_whitelist_application<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawWhitelistApplication")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn slash_provider_bond<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("slashProviderBond")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn release_provider_bond<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseProviderBond")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn whitelist_application<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, WhitelistApplication<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWhitelistApplication")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn pending_applications(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingApplications")
+            .original_result()
+    }
+
+    pub fn set_min_egld_to_delegate<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinEgldToDelegate")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_egld_to_whitelist<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEgldToWhitelist")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_whitelist_bond<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setWhitelistBond")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_unbond_period<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        unbond_period: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setUnbondPeriod")
+            .argument(&unbond_period)
+            .original_result()
+    }
+
+    pub fn set_max_delegation_addresses<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        max_delegation_addresses: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxDelegationAddresses")
+            .argument(&max_delegation_addresses)
+            .original_result()
+    }
+
+    pub fn set_recompute_block_offset<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        recompute_block_offset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRecomputeBlockOffset")
+            .argument(&recompute_block_offset)
+            .original_result()
+    }
+
+    pub fn set_claim_rewards_gas<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        gas_to_claim_rewards: Arg0,
+        min_gas_to_save_progress: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setClaimRewardsGas")
+            .argument(&gas_to_claim_rewards)
+            .argument(&min_gas_to_save_progress)
+            .original_result()
+    }
+
+    pub fn set_async_call_gas<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        min_gas_for_async_call: Arg0,
+        min_gas_for_callback: Arg1,
+        min_gas_finish_exec: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAsyncCallGas")
+            .argument(&min_gas_for_async_call)
+            .argument(&min_gas_for_callback)
+            .argument(&min_gas_finish_exec)
+            .original_result()
+    }
+
+    pub fn get_parameters(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Parameters<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getParameters")
+            .original_result()
+    }
+
+    pub fn set_protocol_vote_mode<
+        Arg0: ProxyArg<ProtocolVoteMode>,
+    >(
+        self,
+        mode: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProtocolVoteMode")
+            .argument(&mode)
+            .original_result()
+    }
+
+    pub fn register_protocol_proposal<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        proposal: Arg0,
+        start_epoch: Arg1,
+        end_epoch: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerProtocolProposal")
+            .argument(&proposal)
+            .argument(&start_epoch)
+            .argument(&end_epoch)
+            .original_result()
+    }
+
+    pub fn decide_protocol_vote<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        vote_type: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("decideProtocolVote")
+            .argument(&proposal)
+            .argument(&vote_type)
+            .original_result()
+    }
+
+    pub fn vote_protocol_proposal<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        vote_type: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("voteProtocolProposal")
+            .argument(&proposal)
+            .argument(&vote_type)
+            .original_result()
+    }
+
+    pub fn cast_protocol_vote<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("castProtocolVote")
+            .argument(&proposal)
+            .original_result()
+    }
+
+    pub fn protocol_vote_mode(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProtocolVoteMode> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolVoteMode")
+            .original_result()
+    }
+
+    pub fn protocol_proposal<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProtocolProposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolProposal")
+            .argument(&proposal)
+            .original_result()
+    }
+
+    pub fn protocol_proposal_tally<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        vote_type: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolProposalTally")
+            .argument(&proposal)
+            .argument(&vote_type)
+            .original_result()
+    }
+
+    pub fn protocol_proposal_voted<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasVotedProtocolProposal")
+            .argument(&proposal)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn protocol_vote<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProtocolVote<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolVote")
+            .argument(&proposal)
+            .original_result()
+    }
+
+    pub fn protocol_voted_proposals(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u32>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolVotedProposals")
+            .original_result()
+    }
+
+    pub fn verify_delegation_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("verifyDelegationContract")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn get_delegation_contract_verification<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DelegationContractVerification<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegationContractVerification")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    pub fn get_mismatched_delegation_contracts(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMismatchedDelegationContracts")
+            .original_result()
+    }
+
+    pub fn set_vote_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_vote_contract")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    pub fn delegate_vote<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        vote_type: Arg1,
+        delegate_to: Arg2,
+        voting_power: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("delegateVote")
+            .argument(&proposal)
+            .argument(&vote_type)
+            .argument(&delegate_to)
+            .argument(&voting_power)
+            .original_result()
+    }
+
+    pub fn cast_aggregated_vote<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposal: Arg0,
+        vote_type: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("castAggregatedVote")
+            .argument(&proposal)
+            .argument(&vote_type)
+            .original_result()
+    }
+
+    pub fn vote_contract(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoteContract")
+            .original_result()
+    }
+
+    pub fn lock_for_vote(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("lockForVote")
+            .original_result()
+    }
+
+    pub fn unlock_from_vote<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unlockFromVote")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn get_vote_power_at<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        start_epoch: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVotePowerAt")
+            .argument(&user)
+            .argument(&start_epoch)
+            .original_result()
+    }
+
+    pub fn get_total_vote_power_at<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        start_epoch: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalVotePowerAt")
+            .argument(&start_epoch)
+            .original_result()
+    }
+
+    pub fn vote_locked_balance<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoteLockedBalance")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn total_vote_locked(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalVoteLocked")
+            .original_result()
+    }
+
+    pub fn set_wegld_swap<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        wegld_swap_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setWegldSwap")
+            .argument(&wegld_swap_address)
+            .original_result()
+    }
+
+    pub fn claim_wegld_refund<
+        Arg0: ProxyArg<OptionalValue<bool>>,
+    >(
+        self,
+        opt_as_egld: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimWegldRefund")
+            .argument(&opt_as_egld)
+            .original_result()
+    }
+
+    pub fn wegld_swap_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWegldSwapAddress")
+            .original_result()
+    }
+
+    pub fn wegld_token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWegldTokenId")
+            .original_result()
+    }
+
+    pub fn wegld_refund<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWegldRefund")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn wegld_payout<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        token_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isWegldPayout")
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn set_provider_claim_gas<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        delegation_address: Arg0,
+        gas_limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProviderClaimGas")
+            .argument(&delegation_address)
+            .argument(&gas_limit)
+            .original_result()
+    }
+
+    pub fn claim_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<usize, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRewards")
+            .original_result()
+    }
+
+    pub fn get_provider_claim_gas<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegation_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProviderClaimGas")
+            .argument(&delegation_address)
+            .original_result()
+    }
+
+    pub fn failed_claim_retries<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegation_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFailedClaimRetries")
+            .argument(&delegation_address)
+            .original_result()
+    }
+
+    pub fn failed_claims(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFailedClaims")
+            .original_result()
+    }
+
+    pub fn delegate_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("delegateRewards")
+            .original_result()
+    }
+
+    pub fn recompute_token_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recomputeTokenReserve")
+            .original_result()
+    }
+
+    pub fn unbond_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("unbondTokens")
+            .original_result()
+    }
+
+    pub fn unbond_tokens_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        receiver: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("unbondTokensFor")
+            .argument(&receiver)
+            .original_result()
+    }
+
+    pub fn get_unstake_token_maturity<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        token_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, UnstakeTokenMaturity> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnstakeTokenMaturity")
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn get_pending_undelegations<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        provider: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, UndelegationBatch<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingUndelegations")
+            .argument(&provider)
+            .original_result()
+    }
+
+    pub fn undelegation_batch<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        provider: Arg0,
+        unstake_epoch: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, UndelegationBatch<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUndelegationBatch")
+            .argument(&provider)
+            .argument(&unstake_epoch)
+            .original_result()
+    }
+
+    pub fn untracked_undelegation<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        provider: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUntrackedUndelegation")
+            .argument(&provider)
+            .original_result()
+    }
+
+    pub fn withdraw_all<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegation_contract: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawAll")
+            .argument(&delegation_contract)
+            .original_result()
+    }
+
+    pub fn add_liquidity<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        opt_referrer: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("addLiquidity")
+            .argument(&opt_referrer)
+            .original_result()
+    }
+
+    pub fn add_liquidity_wegld<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        opt_referrer: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("addLiquidityWegld")
+            .argument(&opt_referrer)
+            .original_result()
+    }
+
+    pub fn add_liquidity_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        receiver: Arg0,
+        opt_referrer: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("addLiquidityFor")
+            .argument(&receiver)
+            .argument(&opt_referrer)
+            .original_result()
+    }
+
+    pub fn wrap_ls_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("wrapLsToken")
+            .original_result()
+    }
+
+    pub fn unwrap_ls_token<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        opt_rebasing_amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unwrapLsToken")
+            .argument(&opt_rebasing_amount)
+            .original_result()
+    }
+
+    pub fn get_rebasing_balance<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRebasingBalance")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_rebasing_total_supply(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRebasingTotalSupply")
+            .original_result()
+    }
+
+    pub fn wrapped_shares<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWrappedShares")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn total_wrapped_shares(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalWrappedShares")
+            .original_result()
+    }
+
+    pub fn remove_liquidity(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("removeLiquidity")
+            .original_result()
+    }
+
+    pub fn remove_liquidity_wegld(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("removeLiquidityWegld")
+            .original_result()
+    }
+
+    pub fn remove_liquidity_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        receiver: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("removeLiquidityFor")
+            .argument(&receiver)
+            .original_result()
+    }
+
+    pub fn set_market_fee<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        fee_percentage: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketFee")
+            .argument(&fee_percentage)
+            .original_result()
+    }
+
+    pub fn list_unstake_token<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        price: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("listUnstakeToken")
+            .argument(&price)
+            .original_result()
+    }
+
+    pub fn cancel_unstake_listing<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        token_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelUnstakeListing")
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn buy_unstake_token<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        token_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("buyUnstakeToken")
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn get_unstake_listings(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, UnstakeListing<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnstakeListings")
+            .original_result()
+    }
+
+    pub fn unstake_listing<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        token_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, UnstakeListing<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnstakeListing")
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn market_fee_percentage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketFeePercentage")
+            .original_result()
+    }
+
+    pub fn market_fees_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketFeesReserve")
+            .original_result()
+    }
+
+    pub fn total_market_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalMarketFees")
+            .original_result()
+    }
+
+    pub fn set_partner_share_percentage<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        share_percentage: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPartnerSharePercentage")
+            .argument(&share_percentage)
+            .original_result()
+    }
+
+    pub fn claim_market_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimMarketFees")
+            .original_result()
+    }
+
+    pub fn distribute_partner_share(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("distributePartnerShare")
+            .original_result()
+    }
+
+    pub fn get_pending_partner_share(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingPartnerShare")
+            .original_result()
+    }
+
+    pub fn claim_partner_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimPartnerRewards")
+            .original_result()
+    }
+
+    pub fn partner_share_percentage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartnerSharePercentage")
+            .original_result()
+    }
+
+    pub fn partner_share_watermark(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartnerShareWatermark")
+            .original_result()
+    }
+
+    pub fn partner_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        partner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartnerRewards")
+            .argument(&partner)
+            .original_result()
+    }
+
+    pub fn register_partner<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        partner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerPartner")
+            .argument(&partner)
+            .original_result()
+    }
+
+    pub fn unregister_partner<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        partner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unregisterPartner")
+            .argument(&partner)
+            .original_result()
+    }
+
+    pub fn get_partner_tvl<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        partner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartnerTvl")
+            .argument(&partner)
+            .original_result()
+    }
+
+    pub fn get_partner_tvl_history<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        partner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PartnerTvlEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartnerTvlHistory")
+            .argument(&partner)
+            .original_result()
+    }
+
+    pub fn partners(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartners")
+            .original_result()
+    }
+
+    pub fn user_referral<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Referral<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserReferral")
+            .argument(&user)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode)]
+pub struct AddLiquidityEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub caller: ManagedAddress<Api>,
+    pub ls_token_id: TokenIdentifier<Api>,
+    pub ls_token_amount: BigUint<Api>,
+    pub ls_token_supply: BigUint<Api>,
+    pub virtual_egld_reserve: BigUint<Api>,
+    pub rewards_reserve: BigUint<Api>,
+    pub block: u64,
+    pub epoch: u64,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode)]
+pub struct RemoveLiquidityEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub caller: ManagedAddress<Api>,
+    pub ls_token_id: TokenIdentifier<Api>,
+    pub ls_token_amount: BigUint<Api>,
+    pub unstake_token_id: TokenIdentifier<Api>,
+    pub unstake_token_amount: BigUint<Api>,
+    pub ls_token_supply: BigUint<Api>,
+    pub virtual_egld_reserve: BigUint<Api>,
+    pub rewards_reserve: BigUint<Api>,
+    pub block: u64,
+    pub epoch: u64,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct WhitelistApplication<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub applicant: ManagedAddress<Api>,
+    pub total_staked: BigUint<Api>,
+    pub delegation_contract_cap: BigUint<Api>,
+    pub nr_nodes: u64,
+    pub apy: u64,
+    pub whitelist_fee: BigUint<Api>,
+    pub bond: BigUint<Api>,
+    pub application_epoch: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct UnstakeListing<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub seller: ManagedAddress<Api>,
+    pub token_nonce: u64,
+    pub price: BigUint<Api>,
+    pub unstake_amount: BigUint<Api>,
+    pub unbond_epoch: u64,
+    pub listing_epoch: u64,
+    pub wegld_payout: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProtocolVote<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub vote_type: ManagedBuffer<Api>,
+    pub voting_power: BigUint<Api>,
+    pub epoch: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Eq, Copy, Clone, Debug)]
+pub enum State {
+    Inactive,
+    Active,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone)]
+pub enum ClaimStatusType {
+    Finished,
+    Delegable,
+    Insufficient,
+    Redelegated,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone)]
+pub struct ClaimStatus {
+    pub status: ClaimStatusType,
+    pub last_claim_epoch: u64,
+    pub last_claim_block: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct DelegationContractData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub admin_address: ManagedAddress<Api>,
+    pub total_staked: BigUint<Api>,
+    pub delegation_contract_cap: BigUint<Api>,
+    pub nr_nodes: u64,
+    pub apy: u64,
+    pub total_staked_from_ls_contract: BigUint<Api>,
+    pub total_unstaked_from_ls_contract: BigUint<Api>,
+    pub total_unbonded_from_ls_contract: BigUint<Api>,
+    pub egld_in_ongoing_undelegation: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct PendingWhitelist<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub caller: ManagedAddress<Api>,
+    pub whitelist_fee: BigUint<Api>,
+    pub bond: BigUint<Api>,
+    pub start_block: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Parameters<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub min_egld_to_delegate: BigUint<Api>,
+    pub egld_to_whitelist: BigUint<Api>,
+    pub whitelist_bond: BigUint<Api>,
+    pub unbond_period: u64,
+    pub max_delegation_addresses: usize,
+    pub recompute_block_offset: u64,
+    pub gas_to_claim_rewards: u64,
+    pub min_gas_to_save_progress: u64,
+    pub min_gas_for_async_call: u64,
+    pub min_gas_for_callback: u64,
+    pub min_gas_finish_exec: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProtocolVoteMode {
+    Owner,
+    Dao,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProtocolProposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+    pub decision: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct DelegationContractVerification<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub reported_total_staked: BigUint<Api>,
+    pub reported_delegation_contract_cap: BigUint<Api>,
+    pub reported_nr_nodes: u64,
+    pub verified: VerifiedContractData<Api>,
+    pub total_staked_mismatch: bool,
+    pub delegation_contract_cap_mismatch: bool,
+    pub nr_nodes_mismatch: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug, Default)]
+pub struct VerifiedContractData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub total_staked: Option<BigUint<Api>>,
+    pub delegation_contract_cap: Option<BigUint<Api>>,
+    pub nr_nodes: Option<u64>,
+    pub last_verification_epoch: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct UnstakeTokenMaturity {
+    pub expected_unbond_epoch: u64,
+    pub actual_unbond_epoch: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct UndelegationBatch<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub unstake_epoch: u64,
+    pub amount: BigUint<Api>,
+    pub withdrawn_amount: BigUint<Api>,
+    pub withdraw_epoch: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct PartnerTvlEntry<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub epoch: u64,
+    pub tvl: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Referral<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub partner: ManagedAddress<Api>,
+    pub ls_token_amount: BigUint<Api>,
+}
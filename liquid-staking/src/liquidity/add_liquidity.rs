@@ -2,29 +2,81 @@ multiversx_sc::imports!();
 
 use crate::{
     basics,
-    basics::constants::{MINIMUM_LIQUIDITY, MIN_EGLD_TO_DELEGATE, MIN_GAS_FOR_CALLBACK},
+    basics::constants::MINIMUM_LIQUIDITY,
     basics::errors::{
         ERROR_BAD_PAYMENT_AMOUNT, ERROR_DELEGATION_CONTRACT_NOT_INITIALIZED, ERROR_NOT_ACTIVE,
     },
-    liquidity_pool, setup, StorageCache,
+    liquidity_pool, partners, setup, StorageCache,
 };
 
 #[multiversx_sc::module]
 pub trait AddLiquidityModule:
     setup::config::ConfigModule
+    + setup::parameters::ParametersModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + setup::delegation::DelegationModule
     + liquidity_pool::LiquidityPoolModule
     + basics::events::EventsModule
+    + partners::referral::ReferralModule
+    + setup::integrators::IntegratorsModule
+    + setup::wegld::WegldModule
 {
     #[payable("EGLD")]
     #[endpoint(addLiquidity)]
-    fn add_liquidity(&self) {
+    fn add_liquidity(&self, opt_referrer: OptionalValue<ManagedAddress>) {
         self.blockchain().check_caller_is_user_account();
-        let storage_cache = StorageCache::new(self);
         let caller = self.blockchain().get_caller();
+        let payment = self.call_value().egld().clone_value();
+        self.process_add_liquidity(
+            caller.clone(),
+            caller,
+            payment,
+            opt_referrer.into_option(),
+            false,
+        );
+    }
 
+    // the WEGLD is unwrapped through the swap contract, a failed delegation is refunded in WEGLD
+    #[payable("*")]
+    #[endpoint(addLiquidityWegld)]
+    fn add_liquidity_wegld(&self, opt_referrer: OptionalValue<ManagedAddress>) {
+        self.blockchain().check_caller_is_user_account();
+        let caller = self.blockchain().get_caller();
+        let wegld_payment = self.call_value().single_esdt();
+        let payment = self.unwrap_wegld(&wegld_payment);
+        self.process_add_liquidity(
+            caller.clone(),
+            caller,
+            payment,
+            opt_referrer.into_option(),
+            true,
+        );
+    }
+
+    // contract friendly variant, the lsEGLD is sent to the receiver once the delegation
+    // succeeds, while a failed delegation is refunded to the caller; nothing is returned
+    #[payable("EGLD")]
+    #[endpoint(addLiquidityFor)]
+    fn add_liquidity_for(
+        &self,
+        receiver: ManagedAddress,
+        opt_referrer: OptionalValue<ManagedAddress>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_caller_allowed(&caller);
         let payment = self.call_value().egld().clone_value();
+        self.process_add_liquidity(caller, receiver, payment, opt_referrer.into_option(), false);
+    }
+
+    fn process_add_liquidity(
+        &self,
+        caller: ManagedAddress,
+        receiver: ManagedAddress,
+        payment: BigUint,
+        opt_referrer: Option<ManagedAddress>,
+        refund_in_wegld: bool,
+    ) {
+        let storage_cache = StorageCache::new(self);
         require!(
             self.is_state_active(storage_cache.contract_state),
             ERROR_NOT_ACTIVE
@@ -35,17 +87,23 @@ pub trait AddLiquidityModule:
                 ERROR_DELEGATION_CONTRACT_NOT_INITIALIZED
             );
         }
-        require!(payment > MIN_EGLD_TO_DELEGATE, ERROR_BAD_PAYMENT_AMOUNT);
+        require!(
+            payment > self.min_egld_to_delegate().get(),
+            ERROR_BAD_PAYMENT_AMOUNT
+        );
 
-        self.call_delegate(caller, payment);
+        self.call_delegate(caller, receiver, payment, opt_referrer, refund_in_wegld);
     }
 
     #[promises_callback]
     fn add_liquidity_callback(
         &self,
         caller: ManagedAddress,
+        receiver: ManagedAddress,
         delegation_contract: ManagedAddress,
         staked_tokens: BigUint,
+        opt_referrer: Option<ManagedAddress>,
+        refund_in_wegld: bool,
         #[call_result] result: ManagedAsyncCallResult<()>,
     ) {
         match result {
@@ -54,6 +112,7 @@ pub trait AddLiquidityModule:
                     .update(|contract_data| {
                         contract_data.total_staked_from_ls_contract += &staked_tokens;
                     });
+                self.restore_delegation_priority(&delegation_contract);
 
                 let mut storage_cache = StorageCache::new(self);
                 let mut ls_token_amount_before_add = BigUint::zero();
@@ -65,22 +124,36 @@ pub trait AddLiquidityModule:
                     - ls_token_amount_before_add;
                 let user_payment = self.mint_ls_token(ls_token_amount);
                 self.send().direct_esdt(
-                    &caller,
+                    &receiver,
                     &user_payment.token_identifier,
                     user_payment.token_nonce,
                     &user_payment.amount,
                 );
+                self.record_referral(&receiver, opt_referrer, &user_payment.amount);
 
-                self.emit_add_liquidity_event(&storage_cache, &caller, user_payment.amount);
+                self.emit_add_liquidity_event(&storage_cache, &receiver, user_payment.amount);
             }
             ManagedAsyncCallResult::Err(_) => {
-                self.send().direct_egld(&caller, &staked_tokens);
+                if refund_in_wegld {
+                    // wrapped when claimed, so a failing swap contract cannot revert the callback
+                    self.wegld_refund(&caller)
+                        .update(|amount| *amount += &staked_tokens);
+                } else {
+                    self.send().direct_egld(&caller, &staked_tokens);
+                }
                 self.move_delegation_contract_to_back(delegation_contract);
             }
         }
     }
 
-    fn call_delegate(&self, caller: ManagedAddress, payment: BigUint) {
+    fn call_delegate(
+        &self,
+        caller: ManagedAddress,
+        receiver: ManagedAddress,
+        payment: BigUint,
+        opt_referrer: Option<ManagedAddress>,
+        refund_in_wegld: bool,
+    ) {
         let delegation_contract = self.get_delegation_contract_for_delegate(&payment);
 
         let gas_for_async_call = self.get_gas_for_async_call_with_callback();
@@ -91,10 +164,13 @@ pub trait AddLiquidityModule:
             .gas(gas_for_async_call)
             .callback(AddLiquidityModule::callbacks(self).add_liquidity_callback(
                 caller,
+                receiver,
                 delegation_contract,
                 payment,
+                opt_referrer,
+                refund_in_wegld,
             ))
-            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+            .gas_for_callback(self.min_gas_for_callback().get())
             .register_promise();
     }
 }
@@ -1,2 +1,3 @@
 pub mod add_liquidity;
+pub mod rebasing_wrapper;
 pub mod remove_liquidity;
@@ -0,0 +1,123 @@
+multiversx_sc::imports!();
+
+use crate::{
+    basics,
+    basics::errors::{
+        ERROR_BAD_PAYMENT_AMOUNT, ERROR_BAD_PAYMENT_TOKEN, ERROR_INSUFFICIENT_WRAPPED_BALANCE,
+        ERROR_LS_TOKEN_NOT_ISSUED, ERROR_NOT_ACTIVE,
+    },
+    liquidity_pool, setup, StorageCache,
+};
+
+#[multiversx_sc::module]
+pub trait RebasingWrapperModule:
+    setup::config::ConfigModule
+    + setup::parameters::ParametersModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + setup::delegation::DelegationModule
+    + liquidity_pool::LiquidityPoolModule
+    + basics::events::EventsModule
+{
+    #[payable("*")]
+    #[endpoint(wrapLsToken)]
+    fn wrap_ls_token(&self) -> BigUint {
+        let storage_cache = StorageCache::new(self);
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().single_esdt();
+
+        require!(
+            self.is_state_active(storage_cache.contract_state),
+            ERROR_NOT_ACTIVE
+        );
+        require!(
+            storage_cache.ls_token_id.is_valid_esdt_identifier(),
+            ERROR_LS_TOKEN_NOT_ISSUED
+        );
+        require!(
+            payment.token_identifier == storage_cache.ls_token_id,
+            ERROR_BAD_PAYMENT_TOKEN
+        );
+        require!(payment.amount > 0, ERROR_BAD_PAYMENT_AMOUNT);
+
+        // the wrapped lsEGLD amount acts as the share of the rebasing balance
+        self.wrapped_shares(&caller)
+            .update(|shares| *shares += &payment.amount);
+        self.total_wrapped_shares()
+            .update(|shares| *shares += &payment.amount);
+
+        let rebasing_amount = self.get_egld_amount(
+            &payment.amount,
+            &storage_cache.ls_token_supply,
+            &storage_cache.virtual_egld_reserve,
+        );
+        self.wrap_ls_token_event(&caller, &payment.amount, &rebasing_amount);
+
+        rebasing_amount
+    }
+
+    #[endpoint(unwrapLsToken)]
+    fn unwrap_ls_token(&self, opt_rebasing_amount: OptionalValue<BigUint>) -> EsdtTokenPayment {
+        let storage_cache = StorageCache::new(self);
+        let caller = self.blockchain().get_caller();
+        let shares_mapper = self.wrapped_shares(&caller);
+        let user_shares = shares_mapper.get();
+
+        let shares_to_unwrap = match opt_rebasing_amount {
+            OptionalValue::Some(rebasing_amount) => {
+                require!(
+                    storage_cache.virtual_egld_reserve > 0,
+                    ERROR_INSUFFICIENT_WRAPPED_BALANCE
+                );
+                rebasing_amount * &storage_cache.ls_token_supply
+                    / &storage_cache.virtual_egld_reserve
+            }
+            OptionalValue::None => user_shares.clone(),
+        };
+        require!(
+            shares_to_unwrap > 0 && shares_to_unwrap <= user_shares,
+            ERROR_INSUFFICIENT_WRAPPED_BALANCE
+        );
+
+        shares_mapper.set(&user_shares - &shares_to_unwrap);
+        self.total_wrapped_shares()
+            .update(|shares| *shares -= &shares_to_unwrap);
+
+        let rebasing_amount = self.get_egld_amount(
+            &shares_to_unwrap,
+            &storage_cache.ls_token_supply,
+            &storage_cache.virtual_egld_reserve,
+        );
+        self.send()
+            .direct_esdt(&caller, &storage_cache.ls_token_id, 0, &shares_to_unwrap);
+        self.unwrap_ls_token_event(&caller, &shares_to_unwrap, &rebasing_amount);
+
+        EsdtTokenPayment::new(storage_cache.ls_token_id.clone(), 0, shares_to_unwrap)
+    }
+
+    #[view(getRebasingBalance)]
+    fn get_rebasing_balance(&self, address: ManagedAddress) -> BigUint {
+        self.shares_to_rebasing_amount(&self.wrapped_shares(&address).get())
+    }
+
+    #[view(getRebasingTotalSupply)]
+    fn get_rebasing_total_supply(&self) -> BigUint {
+        self.shares_to_rebasing_amount(&self.total_wrapped_shares().get())
+    }
+
+    fn shares_to_rebasing_amount(&self, shares: &BigUint) -> BigUint {
+        let ls_token_supply = self.ls_token_supply().get();
+        if ls_token_supply == 0 {
+            return BigUint::zero();
+        }
+
+        self.get_egld_amount(shares, &ls_token_supply, &self.virtual_egld_reserve().get())
+    }
+
+    #[view(getWrappedShares)]
+    #[storage_mapper("wrappedShares")]
+    fn wrapped_shares(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalWrappedShares)]
+    #[storage_mapper("totalWrappedShares")]
+    fn total_wrapped_shares(&self) -> SingleValueMapper<BigUint>;
+}
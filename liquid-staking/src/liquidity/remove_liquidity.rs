@@ -2,33 +2,66 @@ multiversx_sc::imports!();
 
 use crate::{
     basics,
-    basics::constants::{MIN_EGLD_TO_DELEGATE, MIN_GAS_FOR_CALLBACK},
     basics::errors::{
         ERROR_BAD_PAYMENT_AMOUNT, ERROR_BAD_PAYMENT_TOKEN, ERROR_INSUFFICIENT_UNSTAKE_AMOUNT,
-        ERROR_LS_TOKEN_NOT_ISSUED, ERROR_NOT_ACTIVE,
-    },
-    liquidity_pool,
-    setup::{
-        self,
-        config::{UnstakeTokenAttributes, UNBOND_PERIOD},
+        ERROR_LS_TOKEN_NOT_ISSUED, ERROR_NOT_ACTIVE, ERROR_WEGLD_SWAP_NOT_SET,
     },
+    funds, liquidity_pool, partners,
+    setup::{self, config::UnstakeTokenAttributes},
     StorageCache,
 };
 
 #[multiversx_sc::module]
 pub trait RemoveLiquidityModule:
     setup::config::ConfigModule
+    + setup::parameters::ParametersModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + setup::delegation::DelegationModule
     + liquidity_pool::LiquidityPoolModule
     + basics::events::EventsModule
+    + funds::undelegation::UndelegationModule
+    + partners::referral::ReferralModule
+    + setup::integrators::IntegratorsModule
+    + setup::wegld::WegldModule
 {
     #[payable("*")]
     #[endpoint(removeLiquidity)]
     fn remove_liquidity(&self) {
         self.blockchain().check_caller_is_user_account();
-        let mut storage_cache = StorageCache::new(self);
         let caller = self.blockchain().get_caller();
+        self.process_remove_liquidity(caller.clone(), caller, false);
+    }
+
+    // the unstake NFT is marked so that unbondTokens pays it out in WEGLD
+    #[payable("*")]
+    #[endpoint(removeLiquidityWegld)]
+    fn remove_liquidity_wegld(&self) {
+        self.blockchain().check_caller_is_user_account();
+        require!(
+            !self.wegld_swap_address().is_empty(),
+            ERROR_WEGLD_SWAP_NOT_SET
+        );
+        let caller = self.blockchain().get_caller();
+        self.process_remove_liquidity(caller.clone(), caller, true);
+    }
+
+    // contract friendly variant, the unstake NFT is sent to the receiver once the undelegation
+    // succeeds, while a failed undelegation is refunded to the caller; nothing is returned
+    #[payable("*")]
+    #[endpoint(removeLiquidityFor)]
+    fn remove_liquidity_for(&self, receiver: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_caller_allowed(&caller);
+        self.process_remove_liquidity(caller, receiver, false);
+    }
+
+    fn process_remove_liquidity(
+        &self,
+        caller: ManagedAddress,
+        receiver: ManagedAddress,
+        wegld_payout: bool,
+    ) {
+        let mut storage_cache = StorageCache::new(self);
         let payment = self.call_value().single_esdt();
 
         require!(
@@ -47,21 +80,29 @@ pub trait RemoveLiquidityModule:
 
         let egld_to_unstake = self.pool_remove_liquidity(&payment.amount, &mut storage_cache);
         require!(
-            egld_to_unstake >= MIN_EGLD_TO_DELEGATE,
+            egld_to_unstake >= self.min_egld_to_delegate().get(),
             ERROR_INSUFFICIENT_UNSTAKE_AMOUNT
         );
         self.burn_ls_token(&payment.amount);
 
-        self.call_undelegate(egld_to_unstake, caller, payment.amount.clone());
+        self.call_undelegate(
+            egld_to_unstake,
+            caller,
+            receiver,
+            payment.amount.clone(),
+            wegld_payout,
+        );
     }
 
     #[promises_callback]
     fn remove_liquidity_callback(
         &self,
         caller: ManagedAddress,
+        receiver: ManagedAddress,
         delegation_contract: ManagedAddress,
         egld_to_unstake: BigUint,
         ls_tokens_to_be_burned: BigUint,
+        wegld_payout: bool,
         #[call_result] result: ManagedAsyncCallResult<()>,
     ) {
         let mut storage_cache = StorageCache::new(self);
@@ -70,13 +111,17 @@ pub trait RemoveLiquidityModule:
         match result {
             ManagedAsyncCallResult::Ok(()) => {
                 let current_epoch = self.blockchain().get_block_epoch();
-                let unbond_epoch = current_epoch + UNBOND_PERIOD;
+                let unbond_epoch = current_epoch + self.unbond_period().get();
 
                 delegation_contract_mapper.update(|contract_data| {
                     contract_data.total_staked_from_ls_contract -= &egld_to_unstake;
                     contract_data.total_unstaked_from_ls_contract += &egld_to_unstake;
                     contract_data.egld_in_ongoing_undelegation -= &egld_to_unstake;
                 });
+                self.record_undelegation(&delegation_contract, &egld_to_unstake);
+                self.restore_delegation_priority(&delegation_contract);
+                // the burned lsEGLD belonged to the caller, so its referral is reduced
+                self.remove_referral_amount(&caller, &ls_tokens_to_be_burned);
 
                 let virtual_position = UnstakeTokenAttributes {
                     delegation_contract,
@@ -86,8 +131,11 @@ pub trait RemoveLiquidityModule:
                 };
 
                 let user_payment = self.mint_unstake_tokens(&virtual_position);
+                if wegld_payout {
+                    self.wegld_payout(user_payment.token_nonce).set(true);
+                }
                 self.send().direct_esdt(
-                    &caller,
+                    &receiver,
                     &user_payment.token_identifier,
                     user_payment.token_nonce,
                     &user_payment.amount,
@@ -121,7 +169,9 @@ pub trait RemoveLiquidityModule:
         &self,
         egld_to_unstake: BigUint,
         caller: ManagedAddress,
+        receiver: ManagedAddress,
         ls_tokens_to_be_burned: BigUint,
+        wegld_payout: bool,
     ) {
         let delegation_contract = self.get_delegation_contract_for_undelegate(&egld_to_unstake);
 
@@ -138,12 +188,14 @@ pub trait RemoveLiquidityModule:
             .callback(
                 RemoveLiquidityModule::callbacks(self).remove_liquidity_callback(
                     caller,
+                    receiver,
                     delegation_contract,
                     egld_to_unstake,
                     ls_tokens_to_be_burned,
+                    wegld_payout,
                 ),
             )
-            .gas_for_callback(MIN_GAS_FOR_CALLBACK)
+            .gas_for_callback(self.min_gas_for_callback().get())
             .register_promise();
     }
 }
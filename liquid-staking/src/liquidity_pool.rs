@@ -1,10 +1,11 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::basics::constants::{ONE_EGLD, UNSTAKE_TOKEN_NAME_DECIMALS};
 use crate::basics::errors::{ERROR_INSUFFICIENT_LIQUIDITY, ERROR_INSUFFICIENT_LIQ_BURNED};
 use crate::contexts::base::StorageCache;
 
-use crate::setup::config;
+use crate::setup::{config, config::UnstakeTokenAttributes, parameters};
 
 #[type_abi]
 #[derive(TopEncode, TopDecode, PartialEq, Eq, Copy, Clone, Debug)]
@@ -15,7 +16,9 @@ pub enum State {
 
 #[multiversx_sc::module]
 pub trait LiquidityPoolModule:
-    config::ConfigModule + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    config::ConfigModule
+    + parameters::ParametersModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     fn pool_add_liquidity(
         &self,
@@ -72,13 +75,76 @@ pub trait LiquidityPoolModule:
         self.ls_token().burn(amount);
     }
 
-    fn mint_unstake_tokens<T: TopEncode>(&self, attributes: &T) -> EsdtTokenPayment<Self::Api> {
-        self.unstake_token()
-            .nft_create(BigUint::from(1u64), attributes)
+    fn mint_unstake_tokens(
+        &self,
+        attributes: &UnstakeTokenAttributes<Self::Api>,
+    ) -> EsdtTokenPayment<Self::Api> {
+        let token_id = self.unstake_token().get_token_id();
+        let sc_address = self.blockchain().get_sc_address();
+        let next_nonce = self
+            .blockchain()
+            .get_current_esdt_nft_nonce(&sc_address, &token_id)
+            + 1;
+
+        let name = sc_format!(
+            "Unstake {} EGLD - unlocks epoch {}",
+            self.format_egld_amount(&attributes.unstake_amount),
+            attributes.unbond_epoch
+        );
+
+        let mut uris = ManagedVec::new();
+        let uri_parts_mapper = self.unstake_token_uri_parts();
+        if !uri_parts_mapper.is_empty() {
+            let (uri_prefix, uri_suffix) = uri_parts_mapper.get();
+            uris.push(sc_format!("{}{}{}", uri_prefix, next_nonce, uri_suffix));
+        }
+
+        let amount = BigUint::from(1u64);
+        let token_nonce = self.send().esdt_nft_create(
+            &token_id,
+            &amount,
+            &name,
+            &self.unstake_token_royalties().get(),
+            &ManagedBuffer::new(),
+            attributes,
+            &uris,
+        );
+
+        EsdtTokenPayment::new(token_id, token_nonce, amount)
     }
 
     fn burn_unstake_tokens(&self, token_nonce: u64) {
         self.unstake_token()
             .nft_burn(token_nonce, &BigUint::from(1u64));
     }
+
+    // displays the amount in EGLD, truncated to a fixed number of decimals
+    fn format_egld_amount(&self, amount: &BigUint) -> ManagedBuffer {
+        let one_egld = BigUint::from(ONE_EGLD);
+        let decimals_divisor = ONE_EGLD / 10u64.pow(UNSTAKE_TOKEN_NAME_DECIMALS);
+        let integer_part = amount / &one_egld;
+        let fractional_part = ((amount % &one_egld) / decimals_divisor)
+            .to_u64()
+            .unwrap_or_default();
+
+        let mut result = sc_format!("{}", integer_part);
+        if fractional_part == 0 {
+            return result;
+        }
+
+        let mut digits = [b'0'; UNSTAKE_TOKEN_NAME_DECIMALS as usize];
+        let mut remaining = fractional_part;
+        for digit in digits.iter_mut().rev() {
+            *digit += (remaining % 10) as u8;
+            remaining /= 10;
+        }
+        let mut digits_len = digits.len();
+        while digits[digits_len - 1] == b'0' {
+            digits_len -= 1;
+        }
+
+        result.append_bytes(b".");
+        result.append_bytes(&digits[..digits_len]);
+        result
+    }
 }
@@ -0,0 +1,182 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    basics::{
+        self,
+        constants::{Epoch, MAX_MARKET_FEE_PERCENTAGE},
+        errors::{
+            ERROR_BAD_LISTING_PRICE, ERROR_BAD_MARKET_FEE, ERROR_BAD_PAYMENT_AMOUNT,
+            ERROR_BAD_PAYMENT_TOKEN, ERROR_LISTING_NOT_FOUND, ERROR_NOT_ACTIVE, ERROR_ONLY_SELLER,
+            ERROR_POSITION_ALREADY_MATURE,
+        },
+    },
+    funds,
+    setup::{self, config::MAX_PERCENTAGE},
+    StorageCache,
+};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct UnstakeListing<M: ManagedTypeApi> {
+    pub seller: ManagedAddress<M>,
+    pub token_nonce: u64,
+    pub price: BigUint<M>,
+    pub unstake_amount: BigUint<M>,
+    pub unbond_epoch: Epoch,
+    pub listing_epoch: Epoch,
+    pub wegld_payout: bool,
+}
+
+#[multiversx_sc::module]
+pub trait UnstakeMarketModule:
+    setup::config::ConfigModule
+    + setup::parameters::ParametersModule
+    + setup::delegation::DelegationModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + funds::undelegation::UndelegationModule
+    + setup::wegld::WegldModule
+    + basics::events::EventsModule
+{
+    #[only_owner]
+    #[endpoint(setMarketFee)]
+    fn set_market_fee(&self, fee_percentage: u64) {
+        require!(
+            fee_percentage <= MAX_MARKET_FEE_PERCENTAGE,
+            ERROR_BAD_MARKET_FEE
+        );
+        self.market_fee_percentage().set(fee_percentage);
+        self.market_fee_changed_event(fee_percentage);
+    }
+
+    #[payable("*")]
+    #[endpoint(listUnstakeToken)]
+    fn list_unstake_token(&self, price: BigUint) {
+        let storage_cache = StorageCache::new(self);
+        let payment = self.call_value().single_esdt();
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            self.is_state_active(storage_cache.contract_state),
+            ERROR_NOT_ACTIVE
+        );
+        require!(
+            payment.token_identifier == self.unstake_token().get_token_id(),
+            ERROR_BAD_PAYMENT_TOKEN
+        );
+        require!(payment.amount == 1u64, ERROR_BAD_PAYMENT_AMOUNT);
+
+        let unstake_token_attributes = self.get_unstake_token_attributes(payment.token_nonce);
+        require!(
+            !self.is_unstake_position_mature(&unstake_token_attributes),
+            ERROR_POSITION_ALREADY_MATURE
+        );
+        require!(
+            price > 0 && price < unstake_token_attributes.unstake_amount,
+            ERROR_BAD_LISTING_PRICE
+        );
+
+        let listing = UnstakeListing {
+            seller: caller,
+            token_nonce: payment.token_nonce,
+            price,
+            unstake_amount: unstake_token_attributes.unstake_amount,
+            unbond_epoch: unstake_token_attributes.unbond_epoch,
+            listing_epoch: self.blockchain().get_block_epoch(),
+            wegld_payout: self.wegld_payout(payment.token_nonce).get(),
+        };
+        self.unstake_listing(payment.token_nonce).set(&listing);
+        self.listed_unstake_tokens().insert(payment.token_nonce);
+
+        self.unstake_token_listed_event(&listing.seller, payment.token_nonce, &listing);
+    }
+
+    #[endpoint(cancelUnstakeListing)]
+    fn cancel_unstake_listing(&self, token_nonce: u64) {
+        let caller = self.blockchain().get_caller();
+        let listing_mapper = self.unstake_listing(token_nonce);
+        require!(!listing_mapper.is_empty(), ERROR_LISTING_NOT_FOUND);
+
+        let listing = listing_mapper.take();
+        require!(listing.seller == caller, ERROR_ONLY_SELLER);
+        self.listed_unstake_tokens().swap_remove(&token_nonce);
+
+        self.send().direct_esdt(
+            &caller,
+            &self.unstake_token().get_token_id(),
+            token_nonce,
+            &BigUint::from(1u64),
+        );
+
+        self.unstake_listing_cancelled_event(&caller, token_nonce);
+    }
+
+    #[payable("EGLD")]
+    #[endpoint(buyUnstakeToken)]
+    fn buy_unstake_token(&self, token_nonce: u64) {
+        let storage_cache = StorageCache::new(self);
+        let payment = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            self.is_state_active(storage_cache.contract_state),
+            ERROR_NOT_ACTIVE
+        );
+
+        let listing_mapper = self.unstake_listing(token_nonce);
+        require!(!listing_mapper.is_empty(), ERROR_LISTING_NOT_FOUND);
+
+        let listing = listing_mapper.take();
+        require!(payment == listing.price, ERROR_BAD_PAYMENT_AMOUNT);
+        self.listed_unstake_tokens().swap_remove(&token_nonce);
+
+        let market_fee = &listing.price * self.market_fee_percentage().get() / MAX_PERCENTAGE;
+        let seller_amount = &listing.price - &market_fee;
+        if market_fee > 0 {
+            self.market_fees_reserve()
+                .update(|value| *value += &market_fee);
+            self.total_market_fees()
+                .update(|value| *value += &market_fee);
+        }
+
+        self.send().direct_egld(&listing.seller, &seller_amount);
+        self.send().direct_esdt(
+            &caller,
+            &self.unstake_token().get_token_id(),
+            token_nonce,
+            &BigUint::from(1u64),
+        );
+
+        self.unstake_token_sold_event(&caller, &listing.seller, token_nonce, &market_fee);
+    }
+
+    #[view(getUnstakeListings)]
+    fn get_unstake_listings(&self) -> MultiValueEncoded<UnstakeListing<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for token_nonce in self.listed_unstake_tokens().iter() {
+            result.push(self.unstake_listing(token_nonce).get());
+        }
+
+        result
+    }
+
+    #[view(getUnstakeListing)]
+    #[storage_mapper("unstakeListing")]
+    fn unstake_listing(&self, token_nonce: u64) -> SingleValueMapper<UnstakeListing<Self::Api>>;
+
+    #[storage_mapper("listedUnstakeTokens")]
+    fn listed_unstake_tokens(&self) -> UnorderedSetMapper<u64>;
+
+    #[view(getMarketFeePercentage)]
+    #[storage_mapper("marketFeePercentage")]
+    fn market_fee_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMarketFeesReserve)]
+    #[storage_mapper("marketFeesReserve")]
+    fn market_fees_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    // all the market fees collected so far, never decreased by claims
+    #[view(getTotalMarketFees)]
+    #[storage_mapper("totalMarketFees")]
+    fn total_market_fees(&self) -> SingleValueMapper<BigUint>;
+}
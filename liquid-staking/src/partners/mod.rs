@@ -0,0 +1,2 @@
+pub mod partner_share;
+pub mod referral;
@@ -0,0 +1,122 @@
+multiversx_sc::imports!();
+
+use crate::{
+    basics::{
+        self,
+        errors::{ERROR_BAD_PARTNER_SHARE, ERROR_NOTHING_TO_CLAIM, ERROR_NO_PARTNER_TVL},
+    },
+    funds, market,
+    partners::referral,
+    setup::{self, config::MAX_PERCENTAGE},
+};
+
+#[multiversx_sc::module]
+pub trait PartnerShareModule:
+    referral::ReferralModule
+    + market::unstake_market::UnstakeMarketModule
+    + setup::config::ConfigModule
+    + setup::parameters::ParametersModule
+    + setup::delegation::DelegationModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + funds::undelegation::UndelegationModule
+    + setup::wegld::WegldModule
+    + basics::events::EventsModule
+{
+    #[only_owner]
+    #[endpoint(setPartnerSharePercentage)]
+    fn set_partner_share_percentage(&self, share_percentage: u64) {
+        require!(share_percentage <= MAX_PERCENTAGE, ERROR_BAD_PARTNER_SHARE);
+        self.partner_share_percentage().set(share_percentage);
+    }
+
+    // the partner share of the market fees not yet distributed is kept out of the owner claim
+    #[only_owner]
+    #[endpoint(claimMarketFees)]
+    fn claim_market_fees(&self) {
+        let pending_partner_share = self.get_pending_partner_share();
+        let market_fees_reserve = self.market_fees_reserve().get();
+        require!(
+            market_fees_reserve > pending_partner_share,
+            ERROR_NOTHING_TO_CLAIM
+        );
+
+        let market_fees = market_fees_reserve - &pending_partner_share;
+        self.market_fees_reserve().set(pending_partner_share);
+
+        let caller = self.blockchain().get_caller();
+        self.send().direct_egld(&caller, &market_fees);
+    }
+
+    // a share of the market fees collected since the last distribution is split between
+    // the registered partners, proportionally to the lsEGLD TVL each of them referred;
+    // the watermark marks the collected fees whose share was already distributed
+    #[only_owner]
+    #[endpoint(distributePartnerShare)]
+    fn distribute_partner_share(&self) {
+        let mut total_partner_tvl = BigUint::zero();
+        for partner in self.partners().iter() {
+            total_partner_tvl += self.current_partner_tvl(&partner);
+        }
+        require!(total_partner_tvl > 0, ERROR_NO_PARTNER_TVL);
+
+        let share_amount = core::cmp::min(
+            self.get_pending_partner_share(),
+            self.market_fees_reserve().get(),
+        );
+        require!(share_amount > 0, ERROR_NOTHING_TO_CLAIM);
+
+        let mut distributed_amount = BigUint::zero();
+        for partner in self.partners().iter() {
+            let partner_amount =
+                &share_amount * &self.current_partner_tvl(&partner) / &total_partner_tvl;
+            if partner_amount == 0 {
+                continue;
+            }
+
+            distributed_amount += &partner_amount;
+            self.partner_rewards(&partner)
+                .update(|rewards| *rewards += partner_amount);
+        }
+
+        self.partner_share_watermark()
+            .set(self.total_market_fees().get());
+        self.market_fees_reserve()
+            .update(|value| *value -= &distributed_amount);
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.partner_share_distributed_event(
+            current_epoch,
+            &total_partner_tvl,
+            &distributed_amount,
+        );
+    }
+
+    #[view(getPendingPartnerShare)]
+    fn get_pending_partner_share(&self) -> BigUint {
+        let period_market_fees =
+            self.total_market_fees().get() - self.partner_share_watermark().get();
+
+        period_market_fees * self.partner_share_percentage().get() / MAX_PERCENTAGE
+    }
+
+    #[endpoint(claimPartnerRewards)]
+    fn claim_partner_rewards(&self) {
+        let caller = self.blockchain().get_caller();
+        let rewards = self.partner_rewards(&caller).take();
+        require!(rewards > 0, ERROR_NOTHING_TO_CLAIM);
+
+        self.send().direct_egld(&caller, &rewards);
+    }
+
+    #[view(getPartnerSharePercentage)]
+    #[storage_mapper("partnerSharePercentage")]
+    fn partner_share_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getPartnerShareWatermark)]
+    #[storage_mapper("partnerShareWatermark")]
+    fn partner_share_watermark(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getPartnerRewards)]
+    #[storage_mapper("partnerRewards")]
+    fn partner_rewards(&self, partner: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
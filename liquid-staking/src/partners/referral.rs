@@ -0,0 +1,155 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::basics::{
+    constants::{Epoch, MAX_PARTNERS},
+    errors::{ERROR_MAX_PARTNERS, ERROR_PARTNER_ALREADY_REGISTERED, ERROR_PARTNER_NOT_REGISTERED},
+};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Referral<M: ManagedTypeApi> {
+    pub partner: ManagedAddress<M>,
+    pub ls_token_amount: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct PartnerTvlEntry<M: ManagedTypeApi> {
+    pub epoch: Epoch,
+    pub tvl: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait ReferralModule {
+    #[only_owner]
+    #[endpoint(registerPartner)]
+    fn register_partner(&self, partner: ManagedAddress) {
+        require!(self.partners().len() < MAX_PARTNERS, ERROR_MAX_PARTNERS);
+        require!(
+            self.partners().insert(partner),
+            ERROR_PARTNER_ALREADY_REGISTERED
+        );
+    }
+
+    #[only_owner]
+    #[endpoint(unregisterPartner)]
+    fn unregister_partner(&self, partner: ManagedAddress) {
+        require!(
+            self.partners().swap_remove(&partner),
+            ERROR_PARTNER_NOT_REGISTERED
+        );
+    }
+
+    // only referrals coming from registered partners are attributed,
+    // a user stays attributed to the first partner that referred them
+    fn record_referral(
+        &self,
+        user: &ManagedAddress,
+        opt_referrer: Option<ManagedAddress>,
+        ls_token_amount: &BigUint,
+    ) {
+        let referral_mapper = self.user_referral(user);
+        let partner = if !referral_mapper.is_empty() {
+            referral_mapper.get().partner
+        } else {
+            match opt_referrer {
+                Some(referrer) if self.partners().contains(&referrer) => referrer,
+                _ => return,
+            }
+        };
+
+        if referral_mapper.is_empty() {
+            referral_mapper.set(Referral {
+                partner: partner.clone(),
+                ls_token_amount: ls_token_amount.clone(),
+            });
+        } else {
+            referral_mapper.update(|referral| referral.ls_token_amount += ls_token_amount);
+        }
+
+        let new_tvl = self.current_partner_tvl(&partner) + ls_token_amount;
+        self.update_partner_tvl(&partner, new_tvl);
+    }
+
+    fn remove_referral_amount(&self, user: &ManagedAddress, ls_token_amount: &BigUint) {
+        let referral_mapper = self.user_referral(user);
+        if referral_mapper.is_empty() {
+            return;
+        }
+
+        let mut referral = referral_mapper.get();
+        let removed_amount = if ls_token_amount < &referral.ls_token_amount {
+            ls_token_amount.clone()
+        } else {
+            referral.ls_token_amount.clone()
+        };
+        referral.ls_token_amount -= &removed_amount;
+
+        let partner_tvl = self.current_partner_tvl(&referral.partner);
+        let new_tvl = if removed_amount < partner_tvl {
+            partner_tvl - removed_amount
+        } else {
+            BigUint::zero()
+        };
+        self.update_partner_tvl(&referral.partner, new_tvl);
+
+        if referral.ls_token_amount == 0 {
+            referral_mapper.clear();
+        } else {
+            referral_mapper.set(referral);
+        }
+    }
+
+    fn update_partner_tvl(&self, partner: &ManagedAddress, tvl: BigUint) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut history_mapper = self.partner_tvl_history(partner);
+        let history_len = history_mapper.len();
+        let new_entry = PartnerTvlEntry {
+            epoch: current_epoch,
+            tvl,
+        };
+
+        if history_len > 0 && history_mapper.get(history_len).epoch == current_epoch {
+            history_mapper.set(history_len, &new_entry);
+        } else {
+            history_mapper.push(&new_entry);
+        }
+    }
+
+    #[view(getPartnerTvl)]
+    fn get_partner_tvl(&self, partner: ManagedAddress) -> BigUint {
+        self.current_partner_tvl(&partner)
+    }
+
+    fn current_partner_tvl(&self, partner: &ManagedAddress) -> BigUint {
+        let history_mapper = self.partner_tvl_history(partner);
+        if history_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        history_mapper.get(history_mapper.len()).tvl
+    }
+
+    #[view(getPartnerTvlHistory)]
+    fn get_partner_tvl_history(
+        &self,
+        partner: ManagedAddress,
+    ) -> MultiValueEncoded<PartnerTvlEntry<Self::Api>> {
+        self.partner_tvl_history(&partner).iter().collect()
+    }
+
+    #[view(getPartners)]
+    #[storage_mapper("partners")]
+    fn partners(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getUserReferral)]
+    #[storage_mapper("userReferral")]
+    fn user_referral(&self, user: &ManagedAddress) -> SingleValueMapper<Referral<Self::Api>>;
+
+    #[storage_mapper("partnerTvlHistory")]
+    fn partner_tvl_history(
+        &self,
+        partner: &ManagedAddress,
+    ) -> VecMapper<PartnerTvlEntry<Self::Api>>;
+}
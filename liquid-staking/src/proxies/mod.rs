@@ -0,0 +1,2 @@
+pub mod delegation_views_proxy;
+pub mod wegld_swap_proxy;
@@ -3,14 +3,13 @@ multiversx_sc::derive_imports!();
 
 use crate::{
     basics::{
-        constants::{GasLimit, MIN_GAS_FINISH_EXEC, MIN_GAS_FOR_ASYNC_CALL, MIN_GAS_FOR_CALLBACK},
-        errors::ERROR_INSUFFICIENT_GAS_FOR_CALLBACK,
+        constants::{GasLimit, MAX_ROYALTIES, UNSTAKE_TOKEN_URI_NONCE_PLACEHOLDER},
+        errors::{ERROR_INSUFFICIENT_GAS_FOR_CALLBACK, ERROR_INVALID_ROYALTIES},
     },
     liquidity_pool::State,
 };
 
 pub const MAX_PERCENTAGE: u64 = 100_000;
-pub const UNBOND_PERIOD: u64 = 10;
 
 #[type_abi]
 #[derive(
@@ -25,7 +24,8 @@ pub struct UnstakeTokenAttributes<M: ManagedTypeApi> {
 
 #[multiversx_sc::module]
 pub trait ConfigModule:
-    multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    crate::setup::parameters::ParametersModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     #[only_owner]
     #[payable("EGLD")]
@@ -66,13 +66,50 @@ pub trait ConfigModule:
         );
     }
 
+    #[only_owner]
+    #[endpoint(setUnstakeTokenMetadata)]
+    fn set_unstake_token_metadata(&self, uri_template: ManagedBuffer, royalties: BigUint) {
+        require!(royalties <= MAX_ROYALTIES, ERROR_INVALID_ROYALTIES);
+
+        let placeholder = ManagedBuffer::new_from_bytes(UNSTAKE_TOKEN_URI_NONCE_PLACEHOLDER);
+        let template_len = uri_template.len();
+        let placeholder_len = placeholder.len();
+        let mut uri_prefix = uri_template.clone();
+        let mut uri_suffix = ManagedBuffer::new();
+        if template_len >= placeholder_len {
+            for start_index in 0..=template_len - placeholder_len {
+                if uri_template.copy_slice(start_index, placeholder_len)
+                    == Some(placeholder.clone())
+                {
+                    let suffix_index = start_index + placeholder_len;
+                    uri_prefix = uri_template.copy_slice(0, start_index).unwrap_or_default();
+                    uri_suffix = uri_template
+                        .copy_slice(suffix_index, template_len - suffix_index)
+                        .unwrap_or_default();
+                    break;
+                }
+            }
+        }
+
+        self.unstake_token_uri_template().set(uri_template);
+        self.unstake_token_uri_parts().set((uri_prefix, uri_suffix));
+        self.unstake_token_royalties().set(royalties);
+    }
+
+    fn get_unstake_token_attributes(&self, token_nonce: u64) -> UnstakeTokenAttributes<Self::Api> {
+        self.unstake_token().get_token_attributes(token_nonce)
+    }
+
     fn get_gas_for_async_call_with_callback(&self) -> GasLimit {
         let gas_left = self.blockchain().get_gas_left();
+        let min_gas_for_callback = self.min_gas_for_callback().get();
+        let min_gas_finish_exec = self.min_gas_finish_exec().get();
         require!(
-            gas_left > MIN_GAS_FOR_ASYNC_CALL + MIN_GAS_FOR_CALLBACK + MIN_GAS_FINISH_EXEC,
+            gas_left
+                > self.min_gas_for_async_call().get() + min_gas_for_callback + min_gas_finish_exec,
             ERROR_INSUFFICIENT_GAS_FOR_CALLBACK
         );
-        gas_left - MIN_GAS_FOR_CALLBACK - MIN_GAS_FINISH_EXEC
+        gas_left - min_gas_for_callback - min_gas_finish_exec
     }
 
     #[view(getState)]
@@ -98,4 +135,23 @@ pub trait ConfigModule:
     #[view(getUnstakeTokenId)]
     #[storage_mapper("unstakeTokenId")]
     fn unstake_token(&self) -> NonFungibleTokenMapper<Self::Api>;
+
+    #[view(getUnstakeTokenUriTemplate)]
+    #[storage_mapper("unstakeTokenUriTemplate")]
+    fn unstake_token_uri_template(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("unstakeTokenUriParts")]
+    fn unstake_token_uri_parts(&self) -> SingleValueMapper<(ManagedBuffer, ManagedBuffer)>;
+
+    #[view(getUnstakeTokenRoyalties)]
+    #[storage_mapper("unstakeTokenRoyalties")]
+    fn unstake_token_royalties(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getDelegatedVotingPower)]
+    #[storage_mapper("delegatedVotingPower")]
+    fn delegated_voting_power(&self, proposal: u32) -> SingleValueMapper<BigUint>;
+
+    #[view(isAggregatedVoteCast)]
+    #[storage_mapper("aggregatedVoteCast")]
+    fn aggregated_vote_cast(&self, proposal: u32) -> SingleValueMapper<bool>;
 }
@@ -1,9 +1,7 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::basics::constants::{
-    EGLD_TO_WHITELIST, MAX_DELEGATION_ADDRESSES, MIN_BLOCKS_BEFORE_CLEAR_ONGOING_OP,
-};
+use crate::basics::constants::{Blocks, MIN_BLOCKS_BEFORE_CLEAR_ONGOING_OP};
 use crate::basics::errors::{
     ERROR_ANOTHER_WHITELIST_ONGOING, ERROR_BAD_WHITELIST_FEE, ERROR_CLEAR_WHITELIST,
     ERROR_MAX_DELEGATION_ADDRESSES,
@@ -59,25 +57,51 @@ pub struct DelegationContractData<M: ManagedTypeApi> {
     pub egld_in_ongoing_undelegation: BigUint<M>,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct PendingWhitelist<M: ManagedTypeApi> {
+    pub caller: ManagedAddress<M>,
+    pub whitelist_fee: BigUint<M>,
+    pub bond: BigUint<M>,
+    pub start_block: Blocks,
+}
+
+// positions of a delegation contract in the delegation list and in the priority index
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct DelegationAddressNodes {
+    pub list_node_id: u32,
+    pub priority_node_id: u32,
+    pub apy: u64,
+    pub low_priority: bool,
+}
+
 #[multiversx_sc::module]
 pub trait DelegationModule:
     crate::setup::config::ConfigModule
+    + crate::setup::parameters::ParametersModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
-    #[only_owner]
-    #[endpoint(clearOngoingWhitelistOp)]
-    fn clear_ongoing_whitelist_op(&self) {
-        let current_nonce = self.blockchain().get_block_nonce();
-
+    // a whitelisting whose callback never arrived can be cleared by anyone after the expiry,
+    // only the bond is refunded, as the outcome of the fee delegation is unknown
+    #[endpoint(clearPendingWhitelist)]
+    fn clear_pending_whitelist(&self, contract_address: ManagedAddress) {
+        let pending_whitelist_mapper = self.pending_whitelist(&contract_address);
+        let current_block = self.blockchain().get_block_nonce();
         require!(
-            !self.last_whitelisting_delegation_nonce().is_empty()
-                && self.last_whitelisting_delegation_nonce().get()
-                    + MIN_BLOCKS_BEFORE_CLEAR_ONGOING_OP
-                    < current_nonce,
+            !pending_whitelist_mapper.is_empty()
+                && pending_whitelist_mapper.get().start_block + MIN_BLOCKS_BEFORE_CLEAR_ONGOING_OP
+                    < current_block,
             ERROR_CLEAR_WHITELIST
         );
 
-        self.last_whitelisting_delegation_nonce().clear();
+        let pending_whitelist = pending_whitelist_mapper.take();
+        self.pending_whitelist_addresses()
+            .swap_remove(&contract_address);
+        if pending_whitelist.bond > 0 {
+            self.send()
+                .direct_egld(&pending_whitelist.caller, &pending_whitelist.bond);
+        }
     }
 
     #[only_owner]
@@ -95,27 +119,16 @@ pub trait DelegationModule:
         let caller = self.blockchain().get_caller();
 
         let payment = self.call_value().egld().clone_value();
-        require!(payment == EGLD_TO_WHITELIST, ERROR_BAD_WHITELIST_FEE);
         require!(
-            self.delegation_contract_data(&contract_address).is_empty(),
-            ERROR_ALREADY_WHITELISTED
+            payment == self.egld_to_whitelist().get(),
+            ERROR_BAD_WHITELIST_FEE
         );
-
-        require!(
-            self.last_whitelisting_delegation_nonce().is_empty(),
-            ERROR_ANOTHER_WHITELIST_ONGOING
-        );
-
+        self.require_whitelisting_allowed(&contract_address);
         require!(
             delegation_contract_cap >= total_staked,
             ERROR_DELEGATION_CAP
         );
 
-        require!(
-            self.delegation_addresses_list().len() <= MAX_DELEGATION_ADDRESSES,
-            ERROR_MAX_DELEGATION_ADDRESSES
-        );
-
         let contract_data = DelegationContractData {
             admin_address,
             total_staked,
@@ -128,19 +141,62 @@ pub trait DelegationModule:
             egld_in_ongoing_undelegation: BigUint::zero(),
         };
 
-        self.last_whitelisting_delegation_nonce()
-            .set(self.blockchain().get_block_epoch());
+        self.call_whitelist_delegation_contract(
+            caller,
+            contract_address,
+            contract_data,
+            payment,
+            BigUint::zero(),
+        );
+    }
+
+    fn require_whitelisting_allowed(&self, contract_address: &ManagedAddress) {
+        require!(
+            self.delegation_contract_data(contract_address).is_empty(),
+            ERROR_ALREADY_WHITELISTED
+        );
+        require!(
+            self.pending_whitelist(contract_address).is_empty(),
+            ERROR_ANOTHER_WHITELIST_ONGOING
+        );
+        require!(
+            self.delegation_addresses_list().len() + self.pending_whitelist_addresses().len()
+                < self.max_delegation_addresses().get(),
+            ERROR_MAX_DELEGATION_ADDRESSES
+        );
+    }
+
+    fn call_whitelist_delegation_contract(
+        &self,
+        caller: ManagedAddress,
+        contract_address: ManagedAddress,
+        contract_data: DelegationContractData<Self::Api>,
+        whitelist_fee: BigUint,
+        bond: BigUint,
+    ) {
+        let start_block = self.blockchain().get_block_nonce();
+        self.pending_whitelist(&contract_address)
+            .set(PendingWhitelist {
+                caller: caller.clone(),
+                whitelist_fee: whitelist_fee.clone(),
+                bond: bond.clone(),
+                start_block,
+            });
+        self.pending_whitelist_addresses()
+            .insert(contract_address.clone());
 
         self.tx()
             .to(contract_address.clone())
             .typed(DelegationSCProxy)
-            .delegate(payment)
+            .delegate(whitelist_fee.clone())
             .callback(
                 DelegationModule::callbacks(self).whitelist_contract_callback(
                     caller,
                     contract_address,
                     contract_data,
-                    apy,
+                    whitelist_fee,
+                    bond,
+                    start_block,
                 ),
             )
             .async_call_and_exit();
@@ -152,22 +208,46 @@ pub trait DelegationModule:
         caller: ManagedAddress,
         contract_address: ManagedAddress,
         contract_data: DelegationContractData<Self::Api>,
-        apy: u64,
+        whitelist_fee: BigUint,
+        bond: BigUint,
+        start_block: Blocks,
         #[call_result] result: ManagedAsyncCallResult<()>,
     ) {
+        // the pending entry is only settled by its own callback, the bond of a cleared
+        // whitelisting was already refunded and a newer whitelisting keeps its own entry
+        let pending_whitelist_mapper = self.pending_whitelist(&contract_address);
+        let pending_cleared = pending_whitelist_mapper.is_empty();
+        let is_current_whitelisting =
+            !pending_cleared && pending_whitelist_mapper.get().start_block == start_block;
+        let bond = if is_current_whitelisting {
+            pending_whitelist_mapper.clear();
+            self.pending_whitelist_addresses()
+                .swap_remove(&contract_address);
+            bond
+        } else {
+            BigUint::zero()
+        };
+
         match result {
             ManagedAsyncCallResult::Ok(()) => {
-                self.delegation_contract_data(&contract_address)
-                    .set(contract_data);
-
-                self.add_and_order_delegation_address_in_list(contract_address, apy);
+                let delegation_address_mapper = self.delegation_contract_data(&contract_address);
+                if (is_current_whitelisting || pending_cleared)
+                    && delegation_address_mapper.is_empty()
+                {
+                    delegation_address_mapper.set(contract_data);
+                    if bond > 0 {
+                        self.provider_bond(&contract_address).set(bond);
+                    }
+
+                    self.add_delegation_address(contract_address);
+                } else if bond > 0 {
+                    self.send().direct_egld(&caller, &bond);
+                }
             }
             ManagedAsyncCallResult::Err(_) => {
-                self.send().direct_egld(&caller, &EGLD_TO_WHITELIST.into());
+                self.send().direct_egld(&caller, &(whitelist_fee + bond));
             }
         }
-
-        self.last_whitelisting_delegation_nonce().clear();
     }
 
     #[only_owner]
@@ -207,66 +287,170 @@ pub trait DelegationModule:
             ERROR_DELEGATION_CAP
         );
 
-        if old_contract_data.apy != apy {
-            self.remove_delegation_address_from_list(&contract_address);
-            self.add_and_order_delegation_address_in_list(contract_address, apy)
-        }
-
         delegation_address_mapper.update(|contract_data| {
             contract_data.total_staked = total_staked;
             contract_data.delegation_contract_cap = delegation_contract_cap;
             contract_data.nr_nodes = nr_nodes;
             contract_data.apy = apy;
         });
+
+        // updated parameters also give a deprioritized delegation contract a new chance
+        self.reorder_delegation_address(contract_address);
     }
 
-    fn add_address_to_be_claimed(&self, contract_address: ManagedAddress) {
-        if self.addresses_to_claim().is_empty() {
-            self.addresses_to_claim().push_front(contract_address);
-        } else {
-            self.addresses_to_claim().push_back(contract_address);
+    fn add_delegation_address(&self, contract_address: ManagedAddress) {
+        let list_node_id = self
+            .delegation_addresses_list()
+            .push_back(contract_address.clone())
+            .get_node_id();
+        // an ongoing claim operation reaches the new address as well
+        if self.claim_cursor().get() != 0 {
+            self.remaining_claim_count().update(|count| *count += 1);
         }
+
+        self.delegation_address_nodes(&contract_address)
+            .set(DelegationAddressNodes {
+                list_node_id,
+                priority_node_id: 0,
+                apy: 0,
+                low_priority: false,
+            });
+        self.insert_by_priority(contract_address);
     }
 
-    fn add_and_order_delegation_address_in_list(&self, contract_address: ManagedAddress, apy: u64) {
-        let mut delegation_addresses_mapper = self.delegation_addresses_list();
-        if delegation_addresses_mapper.is_empty() {
-            delegation_addresses_mapper.push_front(contract_address);
-        } else {
-            let mut check_if_added = false;
-            for delegation_address_element in delegation_addresses_mapper.iter() {
-                let node_id = delegation_address_element.get_node_id();
-                let delegation_address = delegation_address_element.into_value();
-                let delegation_contract_data =
-                    self.delegation_contract_data(&delegation_address).get();
-                if apy >= delegation_contract_data.apy {
-                    self.delegation_addresses_list()
-                        .push_before_node_id(node_id, contract_address.clone());
-                    check_if_added = true;
-                    break;
-                }
-            }
-            if !check_if_added {
-                delegation_addresses_mapper.push_back(contract_address);
-            }
+    // delegation contracts without free capacity are only used for undelegation
+    fn insert_by_priority(&self, contract_address: ManagedAddress) {
+        let contract_data = self.delegation_contract_data(&contract_address).get();
+        let free_capacity = &contract_data.delegation_contract_cap - &contract_data.total_staked;
+        if free_capacity < self.min_egld_to_delegate().get() {
+            self.push_low_priority(contract_address);
+            return;
+        }
+
+        let apy = contract_data.apy;
+        let mut apy_bucket = self.apy_bucket(apy);
+        if apy_bucket.is_empty() {
+            self.insert_delegation_apy(apy);
         }
+        let priority_node_id = apy_bucket
+            .push_front(contract_address.clone())
+            .get_node_id();
+        self.delegation_address_nodes(&contract_address)
+            .update(|nodes| {
+                nodes.priority_node_id = priority_node_id;
+                nodes.apy = apy;
+                nodes.low_priority = false;
+            });
     }
 
-    fn remove_delegation_address_from_list(&self, contract_address: &ManagedAddress) {
-        for delegation_address_element in self.delegation_addresses_list().iter() {
-            let node_id = delegation_address_element.get_node_id();
-            let delegation_address = delegation_address_element.into_value();
-            if contract_address == &delegation_address {
-                self.delegation_addresses_list().remove_node_by_id(node_id);
+    fn push_low_priority(&self, contract_address: ManagedAddress) {
+        let priority_node_id = self
+            .low_priority_delegation_addresses()
+            .push_back(contract_address.clone())
+            .get_node_id();
+        self.delegation_address_nodes(&contract_address)
+            .update(|nodes| {
+                nodes.priority_node_id = priority_node_id;
+                nodes.low_priority = true;
+            });
+    }
+
+    fn remove_by_priority(&self, contract_address: &ManagedAddress) {
+        let nodes = self.delegation_address_nodes(contract_address).get();
+        if nodes.low_priority {
+            self.low_priority_delegation_addresses()
+                .remove_node_by_id(nodes.priority_node_id);
+            return;
+        }
+
+        let mut apy_bucket = self.apy_bucket(nodes.apy);
+        apy_bucket.remove_node_by_id(nodes.priority_node_id);
+        if apy_bucket.is_empty() {
+            let apy_node_id = self.delegation_apy_node_id(nodes.apy).take();
+            self.delegation_apys().remove_node_by_id(apy_node_id);
+        }
+    }
+
+    // the distinct APYs are kept in descending order, so only they are walked on insert
+    fn insert_delegation_apy(&self, apy: u64) {
+        let mut delegation_apys = self.delegation_apys();
+        let mut lower_apy_node_id = 0;
+        for apy_node in delegation_apys.iter() {
+            if apy > *apy_node.get_value_as_ref() {
+                lower_apy_node_id = apy_node.get_node_id();
                 break;
             }
         }
+
+        let apy_node_id = if lower_apy_node_id == 0 {
+            delegation_apys.push_back(apy).get_node_id()
+        } else {
+            delegation_apys
+                .push_before_node_id(lower_apy_node_id, apy)
+                .unwrap()
+                .get_node_id()
+        };
+        self.delegation_apy_node_id(apy).set(apy_node_id);
+    }
+
+    fn reorder_delegation_address(&self, contract_address: ManagedAddress) {
+        self.remove_by_priority(&contract_address);
+        self.insert_by_priority(contract_address);
     }
 
     fn move_delegation_contract_to_back(&self, delegation_contract: ManagedAddress) {
-        self.remove_delegation_address_from_list(&delegation_contract);
-        self.delegation_addresses_list()
-            .push_back(delegation_contract);
+        self.remove_by_priority(&delegation_contract);
+        self.push_low_priority(delegation_contract);
+    }
+
+    // a delegation contract moved to the back after a failed call regains its priority
+    // on the next successful call, unless it has no free capacity left
+    fn restore_delegation_priority(&self, delegation_contract: &ManagedAddress) {
+        let nodes_mapper = self.delegation_address_nodes(delegation_contract);
+        if !nodes_mapper.is_empty() && nodes_mapper.get().low_priority {
+            self.reorder_delegation_address(delegation_contract.clone());
+        }
+    }
+
+    // deployments from before the priority index only have the ordered delegation list
+    fn index_delegation_addresses(&self) {
+        let mut legacy_addresses_to_claim = self.addresses_to_claim();
+        if !legacy_addresses_to_claim.is_empty() {
+            legacy_addresses_to_claim.clear();
+            if let Some(front_node) = self.delegation_addresses_list().front() {
+                self.claim_cursor().set(front_node.get_node_id());
+                self.remaining_claim_count()
+                    .set(self.delegation_addresses_list().len());
+            }
+        }
+
+        for delegation_address_node in self.delegation_addresses_list().iter() {
+            let delegation_address = delegation_address_node.get_value_cloned();
+            let nodes_mapper = self.delegation_address_nodes(&delegation_address);
+            if !nodes_mapper.is_empty() {
+                continue;
+            }
+
+            nodes_mapper.set(DelegationAddressNodes {
+                list_node_id: delegation_address_node.get_node_id(),
+                priority_node_id: 0,
+                apy: 0,
+                low_priority: false,
+            });
+            self.insert_by_priority(delegation_address);
+        }
+    }
+
+    fn has_delegation_space(
+        &self,
+        delegation_address: &ManagedAddress,
+        amount_to_delegate: &BigUint,
+    ) -> bool {
+        let delegation_contract_data = self.delegation_contract_data(delegation_address).get();
+        let delegation_space_left = &delegation_contract_data.delegation_contract_cap
+            - &delegation_contract_data.total_staked;
+
+        amount_to_delegate <= &delegation_space_left
     }
 
     fn get_delegation_contract_for_delegate(
@@ -278,15 +462,18 @@ pub trait DelegationModule:
             ERROR_NO_DELEGATION_CONTRACTS
         );
 
-        let delegation_addresses_mapper = self.delegation_addresses_list();
-
-        for delegation_address_element in delegation_addresses_mapper.iter() {
-            let delegation_address = delegation_address_element.into_value();
-            let delegation_contract_data = self.delegation_contract_data(&delegation_address).get();
+        for apy_node in self.delegation_apys().iter() {
+            for delegation_address_node in self.apy_bucket(apy_node.into_value()).iter() {
+                let delegation_address = delegation_address_node.into_value();
+                if self.has_delegation_space(&delegation_address, amount_to_delegate) {
+                    return delegation_address;
+                }
+            }
+        }
 
-            let delegation_space_left = &delegation_contract_data.delegation_contract_cap
-                - &delegation_contract_data.total_staked;
-            if amount_to_delegate <= &delegation_space_left {
+        for delegation_address_node in self.low_priority_delegation_addresses().iter() {
+            let delegation_address = delegation_address_node.into_value();
+            if self.has_delegation_space(&delegation_address, amount_to_delegate) {
                 return delegation_address;
             }
         }
@@ -302,7 +489,34 @@ pub trait DelegationModule:
             ERROR_NO_DELEGATION_CONTRACTS
         );
 
-        let delegation_addresses_mapper = self.delegation_addresses_list();
+        // undelegation starts from the lowest priority delegation contracts
+        let low_priority_addresses = self.low_priority_delegation_addresses();
+        if let Some(delegation_address) =
+            self.find_undelegation_address(&low_priority_addresses, amount_to_undelegate)
+        {
+            return delegation_address;
+        }
+
+        let delegation_apys = self.delegation_apys();
+        let mut wrapped_apy_node = delegation_apys.back();
+        while let Some(apy_node) = wrapped_apy_node {
+            wrapped_apy_node = delegation_apys.get_node_by_id(apy_node.get_prev_node_id());
+
+            let apy_bucket = self.apy_bucket(apy_node.into_value());
+            if let Some(delegation_address) =
+                self.find_undelegation_address(&apy_bucket, amount_to_undelegate)
+            {
+                return delegation_address;
+            }
+        }
+        sc_panic!(ERROR_BAD_DELEGATION_ADDRESS);
+    }
+
+    fn find_undelegation_address(
+        &self,
+        delegation_addresses_mapper: &LinkedListMapper<Self::Api, ManagedAddress<Self::Api>>,
+        amount_to_undelegate: &BigUint,
+    ) -> Option<ManagedAddress<Self::Api>> {
         let mut wrapped_last_node = delegation_addresses_mapper.back();
 
         while wrapped_last_node.is_some() {
@@ -318,10 +532,11 @@ pub trait DelegationModule:
             if delegation_contract_data.total_staked_from_ls_contract
                 >= amount_to_undelegate + &delegation_contract_data.egld_in_ongoing_undelegation
             {
-                return delegation_address;
+                return Some(delegation_address);
             }
         }
-        sc_panic!(ERROR_BAD_DELEGATION_ADDRESS);
+
+        None
     }
 
     fn check_claim_operation(&self, old_claim_status: ClaimStatus, current_epoch: u64) {
@@ -336,24 +551,45 @@ pub trait DelegationModule:
         );
     }
 
+    // the claim operation walks the delegation list through a cursor, instead of copying it
     fn prepare_claim_operation(&self) {
-        require!(
-            self.addresses_to_claim().is_empty(),
-            ERROR_CLAIM_IN_PROGRESS
-        );
+        require!(self.claim_cursor().get() == 0, ERROR_CLAIM_IN_PROGRESS);
         let delegation_addresses_mapper = self.delegation_addresses_list();
-        require!(
-            delegation_addresses_mapper.front().unwrap().get_node_id() != 0,
-            ERROR_FIRST_DELEGATION_NODE
-        );
+        let front_node_id = delegation_addresses_mapper.front().unwrap().get_node_id();
+        require!(front_node_id != 0, ERROR_FIRST_DELEGATION_NODE);
+
+        self.claim_cursor().set(front_node_id);
+        self.remaining_claim_count()
+            .set(delegation_addresses_mapper.len());
+    }
+
+    #[view(getDelegationAddressesPage)]
+    fn get_delegation_addresses_page(
+        &self,
+        from_node_id: u32,
+        count: usize,
+    ) -> MultiValue2<u32, ManagedVec<ManagedAddress>> {
+        let delegation_addresses_mapper = self.delegation_addresses_list();
+        let mut node_id = if from_node_id == 0 {
+            delegation_addresses_mapper
+                .front()
+                .map_or(0, |node| node.get_node_id())
+        } else {
+            from_node_id
+        };
 
-        for delegation in delegation_addresses_mapper.iter() {
-            let current_node = delegation_addresses_mapper
-                .get_node_by_id(delegation.get_node_id())
-                .unwrap();
-            let current_address = current_node.clone().into_value();
-            self.add_address_to_be_claimed(current_address);
+        let mut delegation_addresses = ManagedVec::new();
+        while node_id != 0 && delegation_addresses.len() < count {
+            match delegation_addresses_mapper.get_node_by_id(node_id) {
+                Some(node) => {
+                    node_id = node.get_next_node_id();
+                    delegation_addresses.push(node.into_value());
+                }
+                None => node_id = 0,
+            }
         }
+
+        (node_id, delegation_addresses).into()
     }
 
     #[view(getDelegationStatus)]
@@ -411,10 +647,39 @@ pub trait DelegationModule:
     #[storage_mapper("delegationAddressesList")]
     fn delegation_addresses_list(&self) -> LinkedListMapper<ManagedAddress>;
 
-    #[view(getAddressesToClaim)]
+    // only read on upgrade, replaced by the claim cursor
     #[storage_mapper("addressesToClaim")]
     fn addresses_to_claim(&self) -> LinkedListMapper<ManagedAddress>;
 
+    #[view(getClaimCursor)]
+    #[storage_mapper("claimCursor")]
+    fn claim_cursor(&self) -> SingleValueMapper<u32>;
+
+    #[view(getRemainingClaimCount)]
+    #[storage_mapper("remainingClaimCount")]
+    fn remaining_claim_count(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("delegationAddressNodes")]
+    fn delegation_address_nodes(
+        &self,
+        contract_address: &ManagedAddress,
+    ) -> SingleValueMapper<DelegationAddressNodes>;
+
+    #[view(getDelegationApys)]
+    #[storage_mapper("delegationApys")]
+    fn delegation_apys(&self) -> LinkedListMapper<u64>;
+
+    #[storage_mapper("delegationApyNodeId")]
+    fn delegation_apy_node_id(&self, apy: u64) -> SingleValueMapper<u32>;
+
+    #[view(getApyBucket)]
+    #[storage_mapper("apyBucket")]
+    fn apy_bucket(&self, apy: u64) -> LinkedListMapper<ManagedAddress>;
+
+    #[view(getLowPriorityDelegationAddresses)]
+    #[storage_mapper("lowPriorityDelegationAddresses")]
+    fn low_priority_delegation_addresses(&self) -> LinkedListMapper<ManagedAddress>;
+
     #[view(getDelegationClaimStatus)]
     #[storage_mapper("delegationClaimStatus")]
     fn delegation_claim_status(&self) -> SingleValueMapper<ClaimStatus>;
@@ -426,6 +691,19 @@ pub trait DelegationModule:
         contract_address: &ManagedAddress,
     ) -> SingleValueMapper<DelegationContractData<Self::Api>>;
 
-    #[storage_mapper("whitelistingDelegationOngoing")]
-    fn last_whitelisting_delegation_nonce(&self) -> SingleValueMapper<u64>;
+    // bond posted through a whitelist application, locked while the contract is whitelisted
+    #[view(getProviderBond)]
+    #[storage_mapper("providerBond")]
+    fn provider_bond(&self, contract_address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getPendingWhitelist)]
+    #[storage_mapper("pendingWhitelist")]
+    fn pending_whitelist(
+        &self,
+        contract_address: &ManagedAddress,
+    ) -> SingleValueMapper<PendingWhitelist<Self::Api>>;
+
+    #[view(getPendingWhitelistAddresses)]
+    #[storage_mapper("pendingWhitelistAddresses")]
+    fn pending_whitelist_addresses(&self) -> UnorderedSetMapper<ManagedAddress>;
 }
@@ -0,0 +1,58 @@
+multiversx_sc::imports!();
+
+use crate::basics::errors::ERROR_CONTRACT_NOT_WHITELISTED;
+
+#[multiversx_sc::module]
+pub trait IntegratorsModule {
+    #[only_owner]
+    #[endpoint(setIntegratorWhitelistEnabled)]
+    fn set_integrator_whitelist_enabled(&self, enabled: bool) {
+        self.integrator_whitelist_disabled().set(!enabled);
+    }
+
+    #[only_owner]
+    #[endpoint(addIntegrators)]
+    fn add_integrators(&self, integrators: MultiValueEncoded<ManagedAddress>) {
+        let mut whitelist = self.integrator_whitelist();
+        for integrator in integrators {
+            whitelist.insert(integrator);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeIntegrators)]
+    fn remove_integrators(&self, integrators: MultiValueEncoded<ManagedAddress>) {
+        let mut whitelist = self.integrator_whitelist();
+        for integrator in integrators {
+            whitelist.swap_remove(&integrator);
+        }
+    }
+
+    // user accounts are always allowed, contracts only when they were whitelisted
+    // by the owner, or when the owner disabled the whitelist
+    fn require_caller_allowed(&self, caller: &ManagedAddress) {
+        if !self.blockchain().is_smart_contract(caller) {
+            return;
+        }
+
+        if self.is_integrator_whitelist_enabled() {
+            require!(
+                self.integrator_whitelist().contains(caller),
+                ERROR_CONTRACT_NOT_WHITELISTED
+            );
+        }
+    }
+
+    // the whitelist is enabled by default, so the storage keeps the disabled flag
+    #[view(isIntegratorWhitelistEnabled)]
+    fn is_integrator_whitelist_enabled(&self) -> bool {
+        !self.integrator_whitelist_disabled().get()
+    }
+
+    #[storage_mapper("integratorWhitelistDisabled")]
+    fn integrator_whitelist_disabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getIntegratorWhitelist)]
+    #[storage_mapper("integratorWhitelist")]
+    fn integrator_whitelist(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -1,3 +1,10 @@
 pub mod config;
 pub mod delegation;
+pub mod integrators;
+pub mod onboarding;
+pub mod parameters;
+pub mod protocol_vote;
+pub mod verification;
 pub mod vote;
+pub mod vote_lock;
+pub mod wegld;
@@ -0,0 +1,262 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    basics::{
+        self,
+        constants::{Epoch, DELEGATION_CONFIG_OWNER_INDEX, WHITELIST_APPLICATION_EXPIRY},
+        errors::{
+            ERROR_ALREADY_WHITELISTED, ERROR_APPLICATION_EXISTS, ERROR_APPLICATION_NOT_EXPIRED,
+            ERROR_APPLICATION_NOT_FOUND, ERROR_APPLICATION_NOT_VERIFIED, ERROR_BAD_WHITELIST_FEE,
+            ERROR_DELEGATION_CAP, ERROR_INSUFFICIENT_GAS_FOR_CALLBACK, ERROR_INVALID_CALLER,
+            ERROR_NO_PROVIDER_BOND,
+        },
+    },
+    proxies::delegation_views_proxy,
+    setup::{self, delegation::DelegationContractData},
+};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct WhitelistApplication<M: ManagedTypeApi> {
+    pub applicant: ManagedAddress<M>,
+    pub total_staked: BigUint<M>,
+    pub delegation_contract_cap: BigUint<M>,
+    pub nr_nodes: u64,
+    pub apy: u64,
+    pub whitelist_fee: BigUint<M>,
+    pub bond: BigUint<M>,
+    pub application_epoch: Epoch,
+}
+
+#[multiversx_sc::module]
+pub trait OnboardingModule:
+    setup::config::ConfigModule
+    + setup::parameters::ParametersModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + setup::delegation::DelegationModule
+    + basics::events::EventsModule
+{
+    // the applicant becomes the admin of the delegation contract once approved,
+    // the payment covers the whitelisting fee and the bond; the application is
+    // only pending after the delegation contract confirms the applicant as its owner
+    #[payable("EGLD")]
+    #[endpoint(applyForWhitelist)]
+    fn apply_for_whitelist(
+        &self,
+        contract_address: ManagedAddress,
+        total_staked: BigUint,
+        delegation_contract_cap: BigUint,
+        nr_nodes: u64,
+        apy: u64,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().egld().clone_value();
+        let whitelist_fee = self.egld_to_whitelist().get();
+        let bond = self.whitelist_bond().get();
+        require!(payment == &whitelist_fee + &bond, ERROR_BAD_WHITELIST_FEE);
+
+        let application_mapper = self.whitelist_application(&contract_address);
+        require!(application_mapper.is_empty(), ERROR_APPLICATION_EXISTS);
+        require!(
+            self.delegation_contract_data(&contract_address).is_empty(),
+            ERROR_ALREADY_WHITELISTED
+        );
+        require!(
+            delegation_contract_cap >= total_staked,
+            ERROR_DELEGATION_CAP
+        );
+
+        let gas_for_async_call = self.min_gas_for_async_call().get();
+        let gas_for_callback = self.min_gas_for_callback().get();
+        require!(
+            self.blockchain().get_gas_left()
+                > gas_for_async_call + gas_for_callback + self.min_gas_finish_exec().get(),
+            ERROR_INSUFFICIENT_GAS_FOR_CALLBACK
+        );
+
+        let application = WhitelistApplication {
+            applicant: caller,
+            total_staked,
+            delegation_contract_cap,
+            nr_nodes,
+            apy,
+            whitelist_fee,
+            bond,
+            application_epoch: self.blockchain().get_block_epoch(),
+        };
+        application_mapper.set(&application);
+
+        self.tx()
+            .to(contract_address.clone())
+            .typed(delegation_views_proxy::DelegationViewsProxy)
+            .get_contract_config()
+            .gas(gas_for_async_call)
+            .callback(
+                OnboardingModule::callbacks(self)
+                    .whitelist_application_callback(contract_address, application.applicant),
+            )
+            .gas_for_callback(gas_for_callback)
+            .register_promise();
+    }
+
+    // an application that was rejected or replaced meanwhile is left untouched
+    #[promises_callback]
+    fn whitelist_application_callback(
+        &self,
+        contract_address: ManagedAddress,
+        applicant: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+    ) {
+        let application_mapper = self.whitelist_application(&contract_address);
+        if application_mapper.is_empty()
+            || application_mapper.get().applicant != applicant
+            || self.pending_applications().contains(&contract_address)
+        {
+            return;
+        }
+
+        let is_owner = match result {
+            ManagedAsyncCallResult::Ok(config) => {
+                self.is_delegation_contract_owner(config, &applicant)
+            }
+            ManagedAsyncCallResult::Err(_) => false,
+        };
+        if !is_owner {
+            let application = application_mapper.take();
+            self.send().direct_egld(
+                &application.applicant,
+                &(application.whitelist_fee + application.bond),
+            );
+            self.whitelist_application_rejected_event(&contract_address, &applicant);
+            return;
+        }
+
+        self.pending_applications().insert(contract_address.clone());
+        self.whitelist_application_event(&contract_address, &application_mapper.get());
+    }
+
+    // approval is an owner decision only, there is no governance vote path for applications
+    #[only_owner]
+    #[endpoint(approveWhitelistApplication)]
+    fn approve_whitelist_application(&self, contract_address: ManagedAddress) {
+        require!(
+            self.pending_applications().contains(&contract_address),
+            ERROR_APPLICATION_NOT_VERIFIED
+        );
+        let application = self.take_whitelist_application(&contract_address);
+        self.require_whitelisting_allowed(&contract_address);
+
+        let contract_data = DelegationContractData {
+            admin_address: application.applicant.clone(),
+            total_staked: application.total_staked,
+            delegation_contract_cap: application.delegation_contract_cap,
+            nr_nodes: application.nr_nodes,
+            apy: application.apy,
+            total_staked_from_ls_contract: BigUint::zero(),
+            total_unstaked_from_ls_contract: BigUint::zero(),
+            total_unbonded_from_ls_contract: BigUint::zero(),
+            egld_in_ongoing_undelegation: BigUint::zero(),
+        };
+
+        self.call_whitelist_delegation_contract(
+            application.applicant,
+            contract_address,
+            contract_data,
+            application.whitelist_fee,
+            application.bond,
+        );
+    }
+
+    #[only_owner]
+    #[endpoint(rejectWhitelistApplication)]
+    fn reject_whitelist_application(&self, contract_address: ManagedAddress) {
+        let application = self.take_whitelist_application(&contract_address);
+        self.send().direct_egld(
+            &application.applicant,
+            &(application.whitelist_fee + application.bond),
+        );
+    }
+
+    // an application that was neither approved nor rejected within the expiry can be withdrawn
+    // by its applicant, refunding the fee and the bond; a late verification callback is ignored
+    #[endpoint(withdrawWhitelistApplication)]
+    fn withdraw_whitelist_application(&self, contract_address: ManagedAddress) {
+        let application_mapper = self.whitelist_application(&contract_address);
+        require!(!application_mapper.is_empty(), ERROR_APPLICATION_NOT_FOUND);
+        let caller = self.blockchain().get_caller();
+        let application = application_mapper.get();
+        require!(application.applicant == caller, ERROR_INVALID_CALLER);
+        require!(
+            self.blockchain().get_block_epoch()
+                >= application.application_epoch + WHITELIST_APPLICATION_EXPIRY,
+            ERROR_APPLICATION_NOT_EXPIRED
+        );
+
+        self.take_whitelist_application(&contract_address);
+        self.send()
+            .direct_egld(&caller, &(application.whitelist_fee + application.bond));
+        self.whitelist_application_withdrawn_event(&contract_address, &caller);
+    }
+
+    // the bond of a provider that reported false parameters is added to the rewards reserve,
+    // so it is redelegated in favour of the lsEGLD holders
+    #[only_owner]
+    #[endpoint(slashProviderBond)]
+    fn slash_provider_bond(&self, contract_address: ManagedAddress) {
+        let bond = self.provider_bond(&contract_address).take();
+        require!(bond > 0, ERROR_NO_PROVIDER_BOND);
+
+        self.rewards_reserve().update(|value| *value += &bond);
+        self.provider_bond_slashed_event(&contract_address, &bond);
+    }
+
+    // the bond of a provider that operated as reported is returned to the admin of its delegation contract
+    #[only_owner]
+    #[endpoint(releaseProviderBond)]
+    fn release_provider_bond(&self, contract_address: ManagedAddress) {
+        let bond = self.provider_bond(&contract_address).take();
+        require!(bond > 0, ERROR_NO_PROVIDER_BOND);
+
+        let admin_address = self
+            .delegation_contract_data(&contract_address)
+            .get()
+            .admin_address;
+        self.send().direct_egld(&admin_address, &bond);
+        self.provider_bond_released_event(&contract_address, &admin_address, &bond);
+    }
+
+    // the owner is the first buffer of the config returned by the delegation system contract
+    fn is_delegation_contract_owner(
+        &self,
+        config: MultiValueEncoded<ManagedBuffer>,
+        applicant: &ManagedAddress,
+    ) -> bool {
+        match config.to_vec().try_get(DELEGATION_CONFIG_OWNER_INDEX) {
+            Some(owner) => *owner == *applicant.as_managed_buffer(),
+            None => false,
+        }
+    }
+
+    fn take_whitelist_application(
+        &self,
+        contract_address: &ManagedAddress,
+    ) -> WhitelistApplication<Self::Api> {
+        let application_mapper = self.whitelist_application(contract_address);
+        require!(!application_mapper.is_empty(), ERROR_APPLICATION_NOT_FOUND);
+        self.pending_applications().swap_remove(contract_address);
+
+        application_mapper.take()
+    }
+
+    #[view(getWhitelistApplication)]
+    #[storage_mapper("whitelistApplication")]
+    fn whitelist_application(
+        &self,
+        contract_address: &ManagedAddress,
+    ) -> SingleValueMapper<WhitelistApplication<Self::Api>>;
+
+    #[view(getPendingApplications)]
+    #[storage_mapper("pendingApplications")]
+    fn pending_applications(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -0,0 +1,231 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::basics::{
+    constants::{
+        Blocks, Epoch, GasLimit, DEFAULT_GAS_TO_CLAIM_REWARDS, DEFAULT_MIN_GAS_TO_SAVE_PROGRESS,
+        EGLD_TO_WHITELIST, MAX_DELEGATION_ADDRESSES, MAX_DELEGATION_ADDRESSES_LIMIT,
+        MAX_GAS_PER_TX, MAX_MIN_EGLD_TO_DELEGATE_EGLD, MAX_RECOMPUTE_BLOCK_OFFSET,
+        MAX_UNBOND_PERIOD, MAX_WHITELIST_BOND_EGLD, MIN_DELEGATION_SC_AMOUNT, MIN_EGLD_TO_DELEGATE,
+        MIN_GAS_FINISH_EXEC, MIN_GAS_FOR_ASYNC_CALL, MIN_GAS_FOR_CALLBACK, MIN_WHITELIST_BOND,
+        ONE_EGLD, RECOMPUTE_BLOCK_OFFSET, UNBOND_PERIOD, WHITELIST_BOND,
+    },
+    errors::ERROR_PARAMETER_OUT_OF_BOUNDS,
+};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Parameters<M: ManagedTypeApi> {
+    pub min_egld_to_delegate: BigUint<M>,
+    pub egld_to_whitelist: BigUint<M>,
+    pub whitelist_bond: BigUint<M>,
+    pub unbond_period: Epoch,
+    pub max_delegation_addresses: usize,
+    pub recompute_block_offset: Blocks,
+    pub gas_to_claim_rewards: GasLimit,
+    pub min_gas_to_save_progress: GasLimit,
+    pub min_gas_for_async_call: GasLimit,
+    pub min_gas_for_callback: GasLimit,
+    pub min_gas_finish_exec: GasLimit,
+}
+
+#[multiversx_sc::module]
+pub trait ParametersModule {
+    fn init_parameters(&self) {
+        self.min_egld_to_delegate()
+            .set_if_empty(BigUint::from(MIN_EGLD_TO_DELEGATE));
+        self.egld_to_whitelist()
+            .set_if_empty(BigUint::from(EGLD_TO_WHITELIST));
+        self.whitelist_bond()
+            .set_if_empty(BigUint::from(WHITELIST_BOND));
+        self.unbond_period().set_if_empty(UNBOND_PERIOD);
+        self.max_delegation_addresses()
+            .set_if_empty(MAX_DELEGATION_ADDRESSES);
+        self.recompute_block_offset()
+            .set_if_empty(RECOMPUTE_BLOCK_OFFSET);
+        self.gas_to_claim_rewards()
+            .set_if_empty(DEFAULT_GAS_TO_CLAIM_REWARDS);
+        self.min_gas_to_save_progress()
+            .set_if_empty(DEFAULT_MIN_GAS_TO_SAVE_PROGRESS);
+        self.min_gas_for_async_call()
+            .set_if_empty(MIN_GAS_FOR_ASYNC_CALL);
+        self.min_gas_for_callback()
+            .set_if_empty(MIN_GAS_FOR_CALLBACK);
+        self.min_gas_finish_exec().set_if_empty(MIN_GAS_FINISH_EXEC);
+    }
+
+    #[only_owner]
+    #[endpoint(setMinEgldToDelegate)]
+    fn set_min_egld_to_delegate(&self, amount: BigUint) {
+        require!(
+            amount >= MIN_DELEGATION_SC_AMOUNT
+                && amount <= BigUint::from(ONE_EGLD) * MAX_MIN_EGLD_TO_DELEGATE_EGLD,
+            ERROR_PARAMETER_OUT_OF_BOUNDS
+        );
+        self.min_egld_to_delegate().set(amount);
+        self.emit_parameters_changed_event();
+    }
+
+    #[only_owner]
+    #[endpoint(setEgldToWhitelist)]
+    fn set_egld_to_whitelist(&self, amount: BigUint) {
+        require!(
+            amount >= MIN_DELEGATION_SC_AMOUNT,
+            ERROR_PARAMETER_OUT_OF_BOUNDS
+        );
+        self.egld_to_whitelist().set(amount);
+        self.emit_parameters_changed_event();
+    }
+
+    #[only_owner]
+    #[endpoint(setWhitelistBond)]
+    fn set_whitelist_bond(&self, amount: BigUint) {
+        require!(
+            amount >= MIN_WHITELIST_BOND
+                && amount <= BigUint::from(ONE_EGLD) * MAX_WHITELIST_BOND_EGLD,
+            ERROR_PARAMETER_OUT_OF_BOUNDS
+        );
+        self.whitelist_bond().set(amount);
+        self.emit_parameters_changed_event();
+    }
+
+    #[only_owner]
+    #[endpoint(setUnbondPeriod)]
+    fn set_unbond_period(&self, unbond_period: Epoch) {
+        require!(
+            unbond_period > 0 && unbond_period <= MAX_UNBOND_PERIOD,
+            ERROR_PARAMETER_OUT_OF_BOUNDS
+        );
+        self.unbond_period().set(unbond_period);
+        self.emit_parameters_changed_event();
+    }
+
+    #[only_owner]
+    #[endpoint(setMaxDelegationAddresses)]
+    fn set_max_delegation_addresses(&self, max_delegation_addresses: usize) {
+        require!(
+            max_delegation_addresses > 0
+                && max_delegation_addresses <= MAX_DELEGATION_ADDRESSES_LIMIT,
+            ERROR_PARAMETER_OUT_OF_BOUNDS
+        );
+        self.max_delegation_addresses()
+            .set(max_delegation_addresses);
+        self.emit_parameters_changed_event();
+    }
+
+    #[only_owner]
+    #[endpoint(setRecomputeBlockOffset)]
+    fn set_recompute_block_offset(&self, recompute_block_offset: Blocks) {
+        require!(
+            recompute_block_offset > 0 && recompute_block_offset <= MAX_RECOMPUTE_BLOCK_OFFSET,
+            ERROR_PARAMETER_OUT_OF_BOUNDS
+        );
+        self.recompute_block_offset().set(recompute_block_offset);
+        self.emit_parameters_changed_event();
+    }
+
+    #[only_owner]
+    #[endpoint(setClaimRewardsGas)]
+    fn set_claim_rewards_gas(
+        &self,
+        gas_to_claim_rewards: GasLimit,
+        min_gas_to_save_progress: GasLimit,
+    ) {
+        require!(
+            gas_to_claim_rewards > 0
+                && gas_to_claim_rewards < min_gas_to_save_progress
+                && min_gas_to_save_progress <= MAX_GAS_PER_TX,
+            ERROR_PARAMETER_OUT_OF_BOUNDS
+        );
+        self.gas_to_claim_rewards().set(gas_to_claim_rewards);
+        self.min_gas_to_save_progress()
+            .set(min_gas_to_save_progress);
+        self.emit_parameters_changed_event();
+    }
+
+    #[only_owner]
+    #[endpoint(setAsyncCallGas)]
+    fn set_async_call_gas(
+        &self,
+        min_gas_for_async_call: GasLimit,
+        min_gas_for_callback: GasLimit,
+        min_gas_finish_exec: GasLimit,
+    ) {
+        require!(
+            min_gas_for_async_call > 0 && min_gas_for_callback > 0 && min_gas_finish_exec > 0,
+            ERROR_PARAMETER_OUT_OF_BOUNDS
+        );
+        require!(
+            min_gas_for_async_call + min_gas_for_callback + min_gas_finish_exec <= MAX_GAS_PER_TX,
+            ERROR_PARAMETER_OUT_OF_BOUNDS
+        );
+        self.min_gas_for_async_call().set(min_gas_for_async_call);
+        self.min_gas_for_callback().set(min_gas_for_callback);
+        self.min_gas_finish_exec().set(min_gas_finish_exec);
+        self.emit_parameters_changed_event();
+    }
+
+    fn emit_parameters_changed_event(&self) {
+        let caller = self.blockchain().get_caller();
+        let epoch = self.blockchain().get_block_epoch();
+        self.parameters_changed_event(&caller, epoch, &self.get_parameters());
+    }
+
+    #[view(getParameters)]
+    fn get_parameters(&self) -> Parameters<Self::Api> {
+        Parameters {
+            min_egld_to_delegate: self.min_egld_to_delegate().get(),
+            egld_to_whitelist: self.egld_to_whitelist().get(),
+            whitelist_bond: self.whitelist_bond().get(),
+            unbond_period: self.unbond_period().get(),
+            max_delegation_addresses: self.max_delegation_addresses().get(),
+            recompute_block_offset: self.recompute_block_offset().get(),
+            gas_to_claim_rewards: self.gas_to_claim_rewards().get(),
+            min_gas_to_save_progress: self.min_gas_to_save_progress().get(),
+            min_gas_for_async_call: self.min_gas_for_async_call().get(),
+            min_gas_for_callback: self.min_gas_for_callback().get(),
+            min_gas_finish_exec: self.min_gas_finish_exec().get(),
+        }
+    }
+
+    #[event("parameters_changed")]
+    fn parameters_changed_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] epoch: u64,
+        parameters: &Parameters<Self::Api>,
+    );
+
+    #[storage_mapper("minEgldToDelegate")]
+    fn min_egld_to_delegate(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("egldToWhitelist")]
+    fn egld_to_whitelist(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("whitelistBond")]
+    fn whitelist_bond(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("unbondPeriod")]
+    fn unbond_period(&self) -> SingleValueMapper<Epoch>;
+
+    #[storage_mapper("maxDelegationAddresses")]
+    fn max_delegation_addresses(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("recomputeBlockOffset")]
+    fn recompute_block_offset(&self) -> SingleValueMapper<Blocks>;
+
+    #[storage_mapper("gasToClaimRewards")]
+    fn gas_to_claim_rewards(&self) -> SingleValueMapper<GasLimit>;
+
+    #[storage_mapper("minGasToSaveProgress")]
+    fn min_gas_to_save_progress(&self) -> SingleValueMapper<GasLimit>;
+
+    #[storage_mapper("minGasForAsyncCall")]
+    fn min_gas_for_async_call(&self) -> SingleValueMapper<GasLimit>;
+
+    #[storage_mapper("minGasForCallback")]
+    fn min_gas_for_callback(&self) -> SingleValueMapper<GasLimit>;
+
+    #[storage_mapper("minGasFinishExec")]
+    fn min_gas_finish_exec(&self) -> SingleValueMapper<GasLimit>;
+}
@@ -0,0 +1,267 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    basics::{
+        self,
+        constants::{Epoch, VOTE_TYPES},
+        errors::{
+            ERROR_ALREADY_VOTED, ERROR_INVALID_VOTE_TYPE, ERROR_INVALID_VOTING_WINDOW,
+            ERROR_NO_PROTOCOL_DECISION, ERROR_NO_VOTES_TO_CAST, ERROR_NO_VOTING_POWER,
+            ERROR_PROTOCOL_PROPOSAL_EXISTS, ERROR_PROTOCOL_PROPOSAL_NOT_FOUND,
+            ERROR_PROTOCOL_VOTED_PROPOSAL, ERROR_PROTOCOL_VOTE_ALREADY_CAST,
+            ERROR_PROTOCOL_VOTING_NOT_ENDED, ERROR_PROTOCOL_VOTING_NOT_OPEN,
+            ERROR_VOTE_CONTRACT_VOTED_PROPOSAL, ERROR_WRONG_PROTOCOL_VOTE_MODE,
+        },
+    },
+    setup,
+};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProtocolVoteMode {
+    Owner,
+    Dao,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProtocolProposal<M: ManagedTypeApi> {
+    pub start_epoch: Epoch,
+    pub end_epoch: Epoch,
+    pub decision: ManagedBuffer<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProtocolVote<M: ManagedTypeApi> {
+    pub vote_type: ManagedBuffer<M>,
+    pub voting_power: BigUint<M>,
+    pub epoch: Epoch,
+}
+
+// governance proposals registered here are voted by the protocol itself, with its whole
+// stake, instead of being delegated through the vote contract; the direction is decided
+// by the owner, or by the lsEGLD locked for voting before the start epoch in the DAO mode
+#[multiversx_sc::module]
+pub trait ProtocolVoteModule:
+    setup::config::ConfigModule
+    + setup::parameters::ParametersModule
+    + setup::vote_lock::VoteLockModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + basics::events::EventsModule
+{
+    #[only_owner]
+    #[endpoint(setProtocolVoteMode)]
+    fn set_protocol_vote_mode(&self, mode: ProtocolVoteMode) {
+        self.protocol_vote_mode().set(mode);
+    }
+
+    #[only_owner]
+    #[endpoint(registerProtocolProposal)]
+    fn register_protocol_proposal(&self, proposal: u32, start_epoch: Epoch, end_epoch: Epoch) {
+        let proposal_mapper = self.protocol_proposal(proposal);
+        require!(proposal_mapper.is_empty(), ERROR_PROTOCOL_PROPOSAL_EXISTS);
+        require!(
+            self.delegated_voting_power(proposal).get() == 0
+                && !self.aggregated_vote_cast(proposal).get(),
+            ERROR_VOTE_CONTRACT_VOTED_PROPOSAL
+        );
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            start_epoch < end_epoch && current_epoch < end_epoch,
+            ERROR_INVALID_VOTING_WINDOW
+        );
+
+        proposal_mapper.set(ProtocolProposal {
+            start_epoch,
+            end_epoch,
+            decision: ManagedBuffer::new(),
+        });
+    }
+
+    #[only_owner]
+    #[endpoint(decideProtocolVote)]
+    fn decide_protocol_vote(&self, proposal: u32, vote_type: ManagedBuffer) {
+        require!(
+            self.protocol_vote_mode().get() == ProtocolVoteMode::Owner,
+            ERROR_WRONG_PROTOCOL_VOTE_MODE
+        );
+        self.require_valid_vote_type(&vote_type);
+        let mut protocol_proposal = self.get_protocol_proposal(proposal);
+        self.require_protocol_voting_open(&protocol_proposal);
+        require!(
+            self.protocol_vote(proposal).is_empty(),
+            ERROR_PROTOCOL_VOTE_ALREADY_CAST
+        );
+
+        protocol_proposal.decision = vote_type;
+        self.protocol_proposal(proposal).set(protocol_proposal);
+    }
+
+    #[endpoint(voteProtocolProposal)]
+    fn vote_protocol_proposal(&self, proposal: u32, vote_type: ManagedBuffer) {
+        require!(
+            self.protocol_vote_mode().get() == ProtocolVoteMode::Dao,
+            ERROR_WRONG_PROTOCOL_VOTE_MODE
+        );
+        self.require_valid_vote_type(&vote_type);
+        let protocol_proposal = self.get_protocol_proposal(proposal);
+        self.require_protocol_voting_open(&protocol_proposal);
+
+        let caller = self.blockchain().get_caller();
+        let voted_mapper = self.protocol_proposal_voted(proposal, &caller);
+        require!(!voted_mapper.get(), ERROR_ALREADY_VOTED);
+        let voting_power = self.get_vote_power_at(caller.clone(), protocol_proposal.start_epoch);
+        require!(voting_power > 0, ERROR_NO_VOTING_POWER);
+
+        voted_mapper.set(true);
+        self.protocol_proposal_tally(proposal, &vote_type)
+            .update(|tally| *tally += &voting_power);
+        self.protocol_proposal_vote_event(&caller, proposal, &vote_type, &voting_power);
+    }
+
+    // anyone can cast the protocol vote once it is decided; in the DAO mode the vote type
+    // with the highest tally wins after the end epoch, ties going to the earlier one in VOTE_TYPES
+    #[endpoint(castProtocolVote)]
+    fn cast_protocol_vote(&self, proposal: u32) {
+        let protocol_proposal = self.get_protocol_proposal(proposal);
+        let protocol_vote_mapper = self.protocol_vote(proposal);
+        require!(
+            protocol_vote_mapper.is_empty(),
+            ERROR_PROTOCOL_VOTE_ALREADY_CAST
+        );
+
+        let vote_type = match self.protocol_vote_mode().get() {
+            ProtocolVoteMode::Owner => {
+                self.require_protocol_voting_open(&protocol_proposal);
+                require!(
+                    !protocol_proposal.decision.is_empty(),
+                    ERROR_NO_PROTOCOL_DECISION
+                );
+                protocol_proposal.decision
+            }
+            ProtocolVoteMode::Dao => {
+                require!(
+                    self.blockchain().get_block_epoch() >= protocol_proposal.end_epoch,
+                    ERROR_PROTOCOL_VOTING_NOT_ENDED
+                );
+                self.get_winning_vote_type(proposal)
+            }
+        };
+
+        let protocol_vote = ProtocolVote {
+            vote_type: vote_type.clone(),
+            voting_power: self.virtual_egld_reserve().get(),
+            epoch: self.blockchain().get_block_epoch(),
+        };
+        self.protocol_vote_cast_event(proposal, &protocol_vote);
+        protocol_vote_mapper.set(protocol_vote);
+        self.protocol_voted_proposals().insert(proposal);
+
+        let gas_for_async_call = self.get_gas_for_async_call_with_callback();
+        self.tx()
+            .to(GovernanceSystemSCAddress)
+            .typed(GovernanceSCProxy)
+            .vote(proposal, vote_type)
+            .gas(gas_for_async_call)
+            .callback(ProtocolVoteModule::callbacks(self).protocol_vote_callback(proposal))
+            .gas_for_callback(self.min_gas_for_callback().get())
+            .register_promise();
+    }
+
+    // a rejected vote is forgotten, so that it can be cast again
+    #[promises_callback]
+    fn protocol_vote_callback(
+        &self,
+        proposal: u32,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        if let ManagedAsyncCallResult::Err(_) = result {
+            self.protocol_vote(proposal).clear();
+            self.protocol_voted_proposals().swap_remove(&proposal);
+            self.protocol_vote_failed_event(proposal);
+        }
+    }
+
+    fn get_winning_vote_type(&self, proposal: u32) -> ManagedBuffer {
+        let mut winning_vote_type = ManagedBuffer::new();
+        let mut winning_tally = BigUint::zero();
+        for vote_type in VOTE_TYPES.iter() {
+            let vote_type = ManagedBuffer::from(*vote_type);
+            let tally = self.protocol_proposal_tally(proposal, &vote_type).get();
+            if tally > winning_tally {
+                winning_tally = tally;
+                winning_vote_type = vote_type;
+            }
+        }
+        require!(winning_tally > 0, ERROR_NO_VOTES_TO_CAST);
+
+        winning_vote_type
+    }
+
+    fn get_protocol_proposal(&self, proposal: u32) -> ProtocolProposal<Self::Api> {
+        let proposal_mapper = self.protocol_proposal(proposal);
+        require!(
+            !proposal_mapper.is_empty(),
+            ERROR_PROTOCOL_PROPOSAL_NOT_FOUND
+        );
+
+        proposal_mapper.get()
+    }
+
+    fn require_valid_vote_type(&self, vote_type: &ManagedBuffer) {
+        require!(
+            VOTE_TYPES.iter().any(|valid_type| vote_type == *valid_type),
+            ERROR_INVALID_VOTE_TYPE
+        );
+    }
+
+    fn require_protocol_voting_open(&self, protocol_proposal: &ProtocolProposal<Self::Api>) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            current_epoch >= protocol_proposal.start_epoch
+                && current_epoch < protocol_proposal.end_epoch,
+            ERROR_PROTOCOL_VOTING_NOT_OPEN
+        );
+    }
+
+    fn require_not_protocol_proposal(&self, proposal: u32) {
+        require!(
+            self.protocol_proposal(proposal).is_empty(),
+            ERROR_PROTOCOL_VOTED_PROPOSAL
+        );
+    }
+
+    #[view(getProtocolVoteMode)]
+    #[storage_mapper("protocolVoteMode")]
+    fn protocol_vote_mode(&self) -> SingleValueMapper<ProtocolVoteMode>;
+
+    #[view(getProtocolProposal)]
+    #[storage_mapper("protocolProposal")]
+    fn protocol_proposal(&self, proposal: u32) -> SingleValueMapper<ProtocolProposal<Self::Api>>;
+
+    #[view(getProtocolProposalTally)]
+    #[storage_mapper("protocolProposalTally")]
+    fn protocol_proposal_tally(
+        &self,
+        proposal: u32,
+        vote_type: &ManagedBuffer,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(hasVotedProtocolProposal)]
+    #[storage_mapper("protocolProposalVoted")]
+    fn protocol_proposal_voted(
+        &self,
+        proposal: u32,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<bool>;
+
+    #[view(getProtocolVote)]
+    #[storage_mapper("protocolVote")]
+    fn protocol_vote(&self, proposal: u32) -> SingleValueMapper<ProtocolVote<Self::Api>>;
+
+    #[view(getProtocolVotedProposals)]
+    #[storage_mapper("protocolVotedProposals")]
+    fn protocol_voted_proposals(&self) -> UnorderedSetMapper<u32>;
+}
@@ -0,0 +1,263 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    basics::{
+        constants::{
+            Epoch, BLS_KEY_LENGTH, DELEGATION_CONFIG_CAP_INDEX, DELEGATION_CONFIG_TRUE,
+            DELEGATION_CONFIG_WITH_CAP_INDEX, DELEGATION_NODE_STATUS_STAKED,
+        },
+        errors::{
+            ERROR_ALREADY_VERIFIED_THIS_EPOCH, ERROR_INSUFFICIENT_GAS_FOR_CALLBACK,
+            ERROR_NOT_WHITELISTED,
+        },
+    },
+    proxies::delegation_views_proxy,
+    setup,
+};
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug, Default,
+)]
+pub struct VerifiedContractData<M: ManagedTypeApi> {
+    pub total_staked: Option<BigUint<M>>,
+    // None when the delegation contract has no delegation cap
+    pub delegation_contract_cap: Option<BigUint<M>>,
+    pub nr_nodes: Option<u64>,
+    pub last_verification_epoch: Epoch,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct DelegationContractVerification<M: ManagedTypeApi> {
+    pub reported_total_staked: BigUint<M>,
+    pub reported_delegation_contract_cap: BigUint<M>,
+    pub reported_nr_nodes: u64,
+    pub verified: VerifiedContractData<M>,
+    pub total_staked_mismatch: bool,
+    pub delegation_contract_cap_mismatch: bool,
+    pub nr_nodes_mismatch: bool,
+}
+
+#[multiversx_sc::module]
+pub trait VerificationModule:
+    setup::config::ConfigModule
+    + setup::parameters::ParametersModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + setup::delegation::DelegationModule
+{
+    // permissionless, each delegation contract can be verified once per epoch
+    #[endpoint(verifyDelegationContract)]
+    fn verify_delegation_contract(&self, contract_address: ManagedAddress) {
+        require!(
+            !self.delegation_contract_data(&contract_address).is_empty(),
+            ERROR_NOT_WHITELISTED
+        );
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut verified_data = self.get_verified_contract_data(&contract_address);
+        require!(
+            self.verified_contract_data(&contract_address).is_empty()
+                || verified_data.last_verification_epoch < current_epoch,
+            ERROR_ALREADY_VERIFIED_THIS_EPOCH
+        );
+
+        let gas_for_async_call = self.min_gas_for_async_call().get();
+        let gas_for_callback = self.min_gas_for_callback().get();
+        require!(
+            self.blockchain().get_gas_left()
+                > 3 * (gas_for_async_call + gas_for_callback) + self.min_gas_finish_exec().get(),
+            ERROR_INSUFFICIENT_GAS_FOR_CALLBACK
+        );
+
+        verified_data.last_verification_epoch = current_epoch;
+        self.verified_contract_data(&contract_address)
+            .set(verified_data);
+
+        self.tx()
+            .to(contract_address.clone())
+            .typed(delegation_views_proxy::DelegationViewsProxy)
+            .get_contract_config()
+            .gas(gas_for_async_call)
+            .callback(
+                VerificationModule::callbacks(self)
+                    .contract_config_callback(contract_address.clone()),
+            )
+            .gas_for_callback(gas_for_callback)
+            .register_promise();
+
+        self.tx()
+            .to(contract_address.clone())
+            .typed(delegation_views_proxy::DelegationViewsProxy)
+            .get_total_active_stake()
+            .gas(gas_for_async_call)
+            .callback(
+                VerificationModule::callbacks(self)
+                    .total_active_stake_callback(contract_address.clone()),
+            )
+            .gas_for_callback(gas_for_callback)
+            .register_promise();
+
+        self.tx()
+            .to(contract_address.clone())
+            .typed(delegation_views_proxy::DelegationViewsProxy)
+            .get_all_node_states()
+            .gas(gas_for_async_call)
+            .callback(VerificationModule::callbacks(self).node_states_callback(contract_address))
+            .gas_for_callback(gas_for_callback)
+            .register_promise();
+    }
+
+    #[promises_callback]
+    fn contract_config_callback(
+        &self,
+        contract_address: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(config) = result {
+            let mut verified_data = self.get_verified_contract_data(&contract_address);
+            verified_data.delegation_contract_cap = self.parse_delegation_contract_cap(config);
+            self.verified_contract_data(&contract_address)
+                .set(verified_data);
+        }
+    }
+
+    #[promises_callback]
+    fn total_active_stake_callback(
+        &self,
+        contract_address: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<BigUint>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(total_active_stake) = result {
+            let mut verified_data = self.get_verified_contract_data(&contract_address);
+            verified_data.total_staked = Some(total_active_stake);
+            self.verified_contract_data(&contract_address)
+                .set(verified_data);
+        }
+    }
+
+    #[promises_callback]
+    fn node_states_callback(
+        &self,
+        contract_address: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(node_states) = result {
+            let mut verified_data = self.get_verified_contract_data(&contract_address);
+            verified_data.nr_nodes = Some(self.count_staked_nodes(node_states));
+            self.verified_contract_data(&contract_address)
+                .set(verified_data);
+        }
+    }
+
+    // the config is returned as a list of buffers, in the order defined by the delegation system contract
+    fn parse_delegation_contract_cap(
+        &self,
+        config: MultiValueEncoded<ManagedBuffer>,
+    ) -> Option<BigUint> {
+        let config = config.to_vec();
+        if config.len() <= DELEGATION_CONFIG_WITH_CAP_INDEX {
+            return None;
+        }
+
+        let with_delegation_cap = config.get(DELEGATION_CONFIG_WITH_CAP_INDEX);
+        if *with_delegation_cap != *DELEGATION_CONFIG_TRUE {
+            return None;
+        }
+
+        Some(BigUint::from_bytes_be_buffer(
+            &config.get(DELEGATION_CONFIG_CAP_INDEX),
+        ))
+    }
+
+    // node states are returned as a status label followed by the BLS keys having that status
+    fn count_staked_nodes(&self, node_states: MultiValueEncoded<ManagedBuffer>) -> u64 {
+        let staked_status = ManagedBuffer::from(DELEGATION_NODE_STATUS_STAKED);
+        let mut is_staked_status = false;
+        let mut nr_nodes = 0u64;
+        for node_state in node_states {
+            if node_state.len() != BLS_KEY_LENGTH {
+                is_staked_status = node_state == staked_status;
+            } else if is_staked_status {
+                nr_nodes += 1;
+            }
+        }
+
+        nr_nodes
+    }
+
+    #[view(getDelegationContractVerification)]
+    fn get_delegation_contract_verification(
+        &self,
+        contract_address: ManagedAddress,
+    ) -> DelegationContractVerification<Self::Api> {
+        let contract_data = self.delegation_contract_data(&contract_address).get();
+        let verified = self.get_verified_contract_data(&contract_address);
+
+        let total_staked_mismatch = match &verified.total_staked {
+            Some(total_staked) => total_staked != &contract_data.total_staked,
+            None => false,
+        };
+        let delegation_contract_cap_mismatch = match &verified.delegation_contract_cap {
+            Some(delegation_contract_cap) => {
+                delegation_contract_cap != &contract_data.delegation_contract_cap
+            }
+            None => false,
+        };
+        let nr_nodes_mismatch = match verified.nr_nodes {
+            Some(nr_nodes) => nr_nodes != contract_data.nr_nodes,
+            None => false,
+        };
+
+        DelegationContractVerification {
+            reported_total_staked: contract_data.total_staked,
+            reported_delegation_contract_cap: contract_data.delegation_contract_cap,
+            reported_nr_nodes: contract_data.nr_nodes,
+            verified,
+            total_staked_mismatch,
+            delegation_contract_cap_mismatch,
+            nr_nodes_mismatch,
+        }
+    }
+
+    #[view(getMismatchedDelegationContracts)]
+    fn get_mismatched_delegation_contracts(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        for delegation_address in self.delegation_addresses_list().iter() {
+            let contract_address = delegation_address.into_value();
+            let verification = self.get_delegation_contract_verification(contract_address.clone());
+            if verification.total_staked_mismatch
+                || verification.delegation_contract_cap_mismatch
+                || verification.nr_nodes_mismatch
+            {
+                result.push(contract_address);
+            }
+        }
+
+        result
+    }
+
+    fn get_verified_contract_data(
+        &self,
+        contract_address: &ManagedAddress,
+    ) -> VerifiedContractData<Self::Api> {
+        let verified_mapper = self.verified_contract_data(contract_address);
+        if verified_mapper.is_empty() {
+            return VerifiedContractData {
+                total_staked: None,
+                delegation_contract_cap: None,
+                nr_nodes: None,
+                last_verification_epoch: 0,
+            };
+        }
+
+        verified_mapper.get()
+    }
+
+    #[storage_mapper("verifiedContractData")]
+    fn verified_contract_data(
+        &self,
+        contract_address: &ManagedAddress,
+    ) -> SingleValueMapper<VerifiedContractData<Self::Api>>;
+}
@@ -1,15 +1,21 @@
 multiversx_sc::imports!();
 
 use crate::basics::{
-    constants::{GasLimit, MIN_GAS_FINISH_EXEC, MIN_GAS_FOR_ASYNC_CALL},
+    constants::GasLimit,
     errors::{
-        ERROR_INSUFFICIENT_GAS_FOR_ASYNC, ERROR_INVALID_CALLER, ERROR_INVALID_SC_ADDRESS,
-        ERROR_VOTE_SC_NOT_SET,
+        ERROR_AGGREGATED_VOTE_CAST, ERROR_INSUFFICIENT_GAS_FOR_ASYNC, ERROR_INVALID_CALLER,
+        ERROR_INVALID_SC_ADDRESS, ERROR_NO_VOTING_POWER, ERROR_VOTE_SC_NOT_SET,
+        ERROR_VOTING_POWER_EXCEEDED,
     },
 };
 #[multiversx_sc::module]
 pub trait VoteModule:
-    multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    crate::setup::config::ConfigModule
+    + crate::setup::parameters::ParametersModule
+    + crate::setup::protocol_vote::ProtocolVoteModule
+    + crate::setup::vote_lock::VoteLockModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + crate::basics::events::EventsModule
 {
     #[only_owner]
     #[endpoint]
@@ -29,8 +35,84 @@ pub trait VoteModule:
         let caller = self.blockchain().get_caller();
 
         self.check_caller_is_vote_contract(&caller);
+        self.require_not_protocol_proposal(proposal);
+        require!(
+            !self.aggregated_vote_cast(proposal).get(),
+            ERROR_AGGREGATED_VOTE_CAST
+        );
+        require!(
+            self.delegated_voting_power(proposal).get() + &voting_power
+                <= self.virtual_egld_reserve().get(),
+            ERROR_VOTING_POWER_EXCEEDED
+        );
 
-        self.call_delegate_vote(proposal, vote_type, &delegate_to, &voting_power);
+        let gas = self.get_gas_for_async_call();
+        require!(
+            gas >= self.min_gas_for_async_call().get() + self.min_gas_for_callback().get(),
+            ERROR_INSUFFICIENT_GAS_FOR_ASYNC
+        );
+        self.call_delegate_vote(proposal, vote_type, delegate_to, voting_power, gas);
+    }
+
+    // the aggregated holder votes are cast once, as a single governance vote of the protocol
+    // for the winning vote type, with the stake not already delegated through delegateVote
+    #[endpoint(castAggregatedVote)]
+    fn cast_aggregated_vote(&self, proposal: u32, vote_type: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.check_caller_is_vote_contract(&caller);
+        self.require_not_protocol_proposal(proposal);
+        self.require_valid_vote_type(&vote_type);
+        let aggregated_vote_cast_mapper = self.aggregated_vote_cast(proposal);
+        require!(
+            !aggregated_vote_cast_mapper.get(),
+            ERROR_AGGREGATED_VOTE_CAST
+        );
+
+        let total_stake = self.virtual_egld_reserve().get();
+        let delegated_voting_power = self.delegated_voting_power(proposal).get();
+        require!(total_stake > delegated_voting_power, ERROR_NO_VOTING_POWER);
+        let voting_power = total_stake - delegated_voting_power;
+
+        aggregated_vote_cast_mapper.set(true);
+        self.aggregated_vote_cast_event(proposal, &vote_type, &voting_power);
+
+        let gas_for_async_call = self.get_gas_for_async_call_with_callback();
+        self.tx()
+            .to(GovernanceSystemSCAddress)
+            .typed(GovernanceSCProxy)
+            .vote(proposal, vote_type)
+            .gas(gas_for_async_call)
+            .callback(VoteModule::callbacks(self).aggregated_vote_callback(proposal))
+            .gas_for_callback(self.min_gas_for_callback().get())
+            .register_promise();
+    }
+
+    // a rejected delegated vote releases its voting power
+    #[promises_callback]
+    fn delegate_vote_callback(
+        &self,
+        proposal: u32,
+        delegate_to: ManagedAddress,
+        voting_power: BigUint,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        if let ManagedAsyncCallResult::Err(_) = result {
+            self.delegated_voting_power(proposal)
+                .update(|power| *power -= &voting_power);
+            self.delegate_vote_failed_event(proposal, &delegate_to, &voting_power);
+        }
+    }
+
+    // the vote contract casts an aggregated vote only once, so a rejected one stays cast
+    #[promises_callback]
+    fn aggregated_vote_callback(
+        &self,
+        proposal: u32,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        if let ManagedAsyncCallResult::Err(_) = result {
+            self.aggregated_vote_failed_event(proposal);
+        }
     }
 
     fn check_caller_is_vote_contract(&self, caller: &ManagedAddress) {
@@ -45,15 +127,25 @@ pub trait VoteModule:
         &self,
         proposal: u32,
         vote_type: ManagedBuffer,
-        delegate_to: &ManagedAddress,
-        voting_power: &BigUint,
+        delegate_to: ManagedAddress,
+        voting_power: BigUint,
+        gas: GasLimit,
     ) {
-        let gas = self.get_gas_for_async_call();
+        self.delegated_voting_power(proposal)
+            .update(|power| *power += &voting_power);
+
+        let gas_for_callback = self.min_gas_for_callback().get();
         self.tx()
             .to(GovernanceSystemSCAddress)
             .typed(GovernanceSCProxy)
-            .delegate_vote(proposal, vote_type, delegate_to, voting_power)
-            .gas(gas)
+            .delegate_vote(proposal, vote_type, &delegate_to, &voting_power)
+            .gas(gas - gas_for_callback)
+            .callback(VoteModule::callbacks(self).delegate_vote_callback(
+                proposal,
+                delegate_to,
+                voting_power,
+            ))
+            .gas_for_callback(gas_for_callback)
             .register_promise();
     }
 
@@ -66,11 +158,12 @@ pub trait VoteModule:
 
     fn get_gas_for_async_call(&self) -> GasLimit {
         let gas_left = self.blockchain().get_gas_left();
+        let min_gas_finish_exec = self.min_gas_finish_exec().get();
         require!(
-            gas_left > MIN_GAS_FOR_ASYNC_CALL + MIN_GAS_FINISH_EXEC,
+            gas_left > self.min_gas_for_async_call().get() + min_gas_finish_exec,
             ERROR_INSUFFICIENT_GAS_FOR_ASYNC
         );
-        gas_left - MIN_GAS_FINISH_EXEC
+        gas_left - min_gas_finish_exec
     }
 
     #[view(getVoteContract)]
@@ -0,0 +1,152 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    basics::{
+        self,
+        constants::Epoch,
+        errors::{
+            ERROR_BAD_PAYMENT_AMOUNT, ERROR_BAD_PAYMENT_TOKEN, ERROR_INSUFFICIENT_LOCKED_BALANCE,
+        },
+    },
+    setup,
+};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct VoteLockCheckpoint<M: ManagedTypeApi> {
+    pub epoch: Epoch,
+    pub locked_balance: BigUint<M>,
+}
+
+// lsEGLD locked here gives on-chain voting power, as recorded at the start epoch of a proposal
+#[multiversx_sc::module]
+pub trait VoteLockModule:
+    setup::config::ConfigModule
+    + setup::parameters::ParametersModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + basics::events::EventsModule
+{
+    #[payable("*")]
+    #[endpoint(lockForVote)]
+    fn lock_for_vote(&self) {
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.ls_token().get_token_id(),
+            ERROR_BAD_PAYMENT_TOKEN
+        );
+        require!(payment.amount > 0, ERROR_BAD_PAYMENT_AMOUNT);
+
+        let locked_balance = self.vote_locked_balance(&caller).get() + &payment.amount;
+        self.update_vote_locked_balance(&caller, locked_balance);
+        self.vote_lock_event(&caller, &payment.amount);
+    }
+
+    #[endpoint(unlockFromVote)]
+    fn unlock_from_vote(&self, amount: BigUint) -> EsdtTokenPayment {
+        let caller = self.blockchain().get_caller();
+        let locked_balance = self.vote_locked_balance(&caller).get();
+        require!(amount > 0, ERROR_BAD_PAYMENT_AMOUNT);
+        require!(amount <= locked_balance, ERROR_INSUFFICIENT_LOCKED_BALANCE);
+
+        self.update_vote_locked_balance(&caller, locked_balance - &amount);
+        self.vote_unlock_event(&caller, &amount);
+
+        let ls_token_id = self.ls_token().get_token_id();
+        self.send().direct_esdt(&caller, &ls_token_id, 0, &amount);
+
+        EsdtTokenPayment::new(ls_token_id, 0, amount)
+    }
+
+    fn update_vote_locked_balance(&self, user: &ManagedAddress, locked_balance: BigUint) {
+        let old_locked_balance = self.vote_locked_balance(user).get();
+        let total_vote_locked =
+            self.total_vote_locked().get() + &locked_balance - old_locked_balance;
+
+        self.vote_locked_balance(user).set(&locked_balance);
+        self.total_vote_locked().set(&total_vote_locked);
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.push_vote_lock_checkpoint(
+            self.vote_lock_checkpoints(user),
+            current_epoch,
+            locked_balance,
+        );
+        self.push_vote_lock_checkpoint(
+            self.total_vote_lock_checkpoints(),
+            current_epoch,
+            total_vote_locked,
+        );
+    }
+
+    // a single checkpoint is kept per epoch, holding the balance at the end of that epoch
+    fn push_vote_lock_checkpoint(
+        &self,
+        mut checkpoints: VecMapper<VoteLockCheckpoint<Self::Api>>,
+        epoch: Epoch,
+        locked_balance: BigUint,
+    ) {
+        let checkpoint = VoteLockCheckpoint {
+            epoch,
+            locked_balance,
+        };
+        let len = checkpoints.len();
+        if len > 0 && checkpoints.get(len).epoch == epoch {
+            checkpoints.set(len, &checkpoint);
+        } else {
+            checkpoints.push(&checkpoint);
+        }
+    }
+
+    // only balances locked before the start epoch count, so the snapshot
+    // cannot change anymore once the start epoch is reached
+    fn find_vote_locked_balance(
+        &self,
+        checkpoints: VecMapper<VoteLockCheckpoint<Self::Api>>,
+        start_epoch: Epoch,
+    ) -> BigUint {
+        let mut low = 1;
+        let mut high = checkpoints.len();
+        let mut locked_balance = BigUint::zero();
+        while low <= high {
+            let middle = (low + high) / 2;
+            let checkpoint = checkpoints.get(middle);
+            if checkpoint.epoch < start_epoch {
+                locked_balance = checkpoint.locked_balance;
+                low = middle + 1;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        locked_balance
+    }
+
+    #[view(getVotePowerAt)]
+    fn get_vote_power_at(&self, user: ManagedAddress, start_epoch: Epoch) -> BigUint {
+        self.find_vote_locked_balance(self.vote_lock_checkpoints(&user), start_epoch)
+    }
+
+    #[view(getTotalVotePowerAt)]
+    fn get_total_vote_power_at(&self, start_epoch: Epoch) -> BigUint {
+        self.find_vote_locked_balance(self.total_vote_lock_checkpoints(), start_epoch)
+    }
+
+    #[view(getVoteLockedBalance)]
+    #[storage_mapper("voteLockedBalance")]
+    fn vote_locked_balance(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalVoteLocked)]
+    #[storage_mapper("totalVoteLocked")]
+    fn total_vote_locked(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("voteLockCheckpoints")]
+    fn vote_lock_checkpoints(
+        &self,
+        user: &ManagedAddress,
+    ) -> VecMapper<VoteLockCheckpoint<Self::Api>>;
+
+    #[storage_mapper("totalVoteLockCheckpoints")]
+    fn total_vote_lock_checkpoints(&self) -> VecMapper<VoteLockCheckpoint<Self::Api>>;
+}
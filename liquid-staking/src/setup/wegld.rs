@@ -0,0 +1,108 @@
+multiversx_sc::imports!();
+
+use crate::{
+    basics::errors::{ERROR_BAD_PAYMENT_TOKEN, ERROR_NOTHING_TO_CLAIM, ERROR_WEGLD_SWAP_NOT_SET},
+    proxies::wegld_swap_proxy,
+};
+
+#[multiversx_sc::module]
+pub trait WegldModule {
+    // the swap contract must be on the same shard, as wrapping and unwrapping use sync calls
+    #[only_owner]
+    #[endpoint(setWegldSwap)]
+    fn set_wegld_swap(&self, wegld_swap_address: ManagedAddress) {
+        let wegld_token_id = self
+            .tx()
+            .to(&wegld_swap_address)
+            .typed(wegld_swap_proxy::EgldEsdtSwapProxy)
+            .get_wrapped_egld_token_id()
+            .returns(ReturnsResult)
+            .sync_call();
+
+        self.wegld_swap_address().set(wegld_swap_address);
+        self.wegld_token_id().set(wegld_token_id);
+    }
+
+    // refunds of failed WEGLD delegations, paid as WEGLD or, if the swap contract fails, as EGLD
+    #[endpoint(claimWegldRefund)]
+    fn claim_wegld_refund(&self, opt_as_egld: OptionalValue<bool>) {
+        let caller = self.blockchain().get_caller();
+        let amount = self.wegld_refund(&caller).take();
+        require!(amount > 0, ERROR_NOTHING_TO_CLAIM);
+
+        if opt_as_egld.into_option().unwrap_or_default() {
+            self.send().direct_egld(&caller, &amount);
+            return;
+        }
+
+        let wegld_payment = self.wrap_egld(&amount);
+        self.send().direct_esdt(
+            &caller,
+            &wegld_payment.token_identifier,
+            wegld_payment.token_nonce,
+            &wegld_payment.amount,
+        );
+    }
+
+    fn unwrap_wegld(&self, payment: &EsdtTokenPayment) -> BigUint {
+        require!(
+            !self.wegld_swap_address().is_empty(),
+            ERROR_WEGLD_SWAP_NOT_SET
+        );
+        require!(
+            payment.token_identifier == self.wegld_token_id().get(),
+            ERROR_BAD_PAYMENT_TOKEN
+        );
+
+        let egld_balance_before = self
+            .blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        self.tx()
+            .to(self.wegld_swap_address().get())
+            .typed(wegld_swap_proxy::EgldEsdtSwapProxy)
+            .unwrap_egld()
+            .single_esdt(
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            )
+            .sync_call();
+        let egld_balance_after = self
+            .blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+
+        egld_balance_after - egld_balance_before
+    }
+
+    fn wrap_egld(&self, amount: &BigUint) -> EsdtTokenPayment {
+        require!(
+            !self.wegld_swap_address().is_empty(),
+            ERROR_WEGLD_SWAP_NOT_SET
+        );
+
+        self.tx()
+            .to(self.wegld_swap_address().get())
+            .typed(wegld_swap_proxy::EgldEsdtSwapProxy)
+            .wrap_egld()
+            .egld(amount)
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
+    #[view(getWegldSwapAddress)]
+    #[storage_mapper("wegldSwapAddress")]
+    fn wegld_swap_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getWegldTokenId)]
+    #[storage_mapper("wegldTokenId")]
+    fn wegld_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getWegldRefund)]
+    #[storage_mapper("wegldRefund")]
+    fn wegld_refund(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // unstake positions that are paid out in WEGLD when unbonded
+    #[view(isWegldPayout)]
+    #[storage_mapper("wegldPayout")]
+    fn wegld_payout(&self, token_nonce: u64) -> SingleValueMapper<bool>;
+}
@@ -0,0 +1,998 @@
+use delegation_mock::delegation_mock_proxy::{self, DelegationOperation};
+use governance_mock::governance_mock_proxy::{self, GovernanceOperation};
+use liquid_staking::{
+    liquid_staking_proxy::{self, ClaimStatusType},
+    setup::config::{ConfigModule, UnstakeTokenAttributes},
+};
+use multiversx_sc_scenario::imports::*;
+
+pub const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+pub const USER_ADDRESS: TestAddress = TestAddress::new("user");
+pub const DELEGATION_OWNER_ADDRESS: TestAddress = TestAddress::new("delegation-owner");
+pub const LIQUID_STAKING_ADDRESS: TestSCAddress = TestSCAddress::new("liquid-staking");
+pub const DELEGATION_ADDRESS: TestSCAddress = TestSCAddress::new("delegation");
+pub const VOTE_CONTRACT_ADDRESS: TestSCAddress = TestSCAddress::new("vote-contract");
+pub const INTEGRATOR_ADDRESS: TestSCAddress = TestSCAddress::new("integrator");
+
+pub const LIQUID_STAKING_CODE_PATH: MxscPath = MxscPath::new("output/liquid-staking.mxsc.json");
+pub const DELEGATION_MOCK_CODE_PATH: MxscPath =
+    MxscPath::new("../delegation-mock/output/delegation-mock.mxsc.json");
+pub const GOVERNANCE_MOCK_CODE_PATH: MxscPath =
+    MxscPath::new("../governance-mock/output/governance-mock.mxsc.json");
+
+pub const LS_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("LSTOKEN-123456");
+pub const UNSTAKE_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("UNSTAKE-123456");
+
+pub const INITIAL_EGLD_BALANCE: u64 = 1_000;
+pub const DELEGATION_CONTRACT_CAP: u64 = 1_000;
+pub const DELEGATION_CONTRACT_APY: u64 = 1_000;
+pub const UNBOND_PERIOD: u64 = 10;
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;
+pub const TX_GAS: u64 = 600_000_000;
+
+pub fn exp18(value: u64) -> BigUint<StaticApi> {
+    BigUint::from(value) * BigUint::from(10u64).pow(18)
+}
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("liquid-staking");
+    blockchain.register_contract(LIQUID_STAKING_CODE_PATH, liquid_staking::ContractBuilder);
+    blockchain.register_contract(DELEGATION_MOCK_CODE_PATH, delegation_mock::ContractBuilder);
+    blockchain.register_contract(GOVERNANCE_MOCK_CODE_PATH, governance_mock::ContractBuilder);
+    blockchain
+}
+
+// the liquid staking contract, deployed next to a mock delegation contract and a mock
+// governance contract, with every call to them going through the Rust VM async call support
+pub struct LiquidStakingTestState {
+    pub world: ScenarioWorld,
+}
+
+impl LiquidStakingTestState {
+    pub fn new() -> Self {
+        let mut world = world();
+        for address in [OWNER_ADDRESS, USER_ADDRESS, DELEGATION_OWNER_ADDRESS] {
+            world
+                .account(address)
+                .nonce(1)
+                .balance(exp18(INITIAL_EGLD_BALANCE));
+        }
+
+        world
+            .tx()
+            .from(DELEGATION_OWNER_ADDRESS)
+            .typed(delegation_mock_proxy::DelegationMockProxy)
+            .init()
+            .code(DELEGATION_MOCK_CODE_PATH)
+            .new_address(DELEGATION_ADDRESS)
+            .run();
+
+        // the governance contract is a system contract, so its code is set at the system address
+        world
+            .account(GovernanceSystemSCAddress)
+            .nonce(1)
+            .code(GOVERNANCE_MOCK_CODE_PATH)
+            .owner(OWNER_ADDRESS);
+        // the vote contract only has to be a contract sending the delegated votes
+        world
+            .account(VOTE_CONTRACT_ADDRESS)
+            .nonce(1)
+            .code(GOVERNANCE_MOCK_CODE_PATH);
+        // any contract code works for an integrating contract calling the For endpoints
+        world
+            .account(INTEGRATOR_ADDRESS)
+            .nonce(1)
+            .balance(exp18(INITIAL_EGLD_BALANCE))
+            .code(GOVERNANCE_MOCK_CODE_PATH);
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .init()
+            .code(LIQUID_STAKING_CODE_PATH)
+            .new_address(LIQUID_STAKING_ADDRESS)
+            .run();
+
+        // the token issuance is replaced by setting the token ids and their local roles
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .whitebox(liquid_staking::contract_obj, |sc| {
+                sc.ls_token()
+                    .set_token_id(LS_TOKEN_ID.to_token_identifier());
+                sc.unstake_token()
+                    .set_token_id(UNSTAKE_TOKEN_ID.to_token_identifier());
+            });
+        world.set_esdt_local_roles(
+            LIQUID_STAKING_ADDRESS,
+            LS_TOKEN_ID.as_bytes(),
+            &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+        );
+        world.set_esdt_local_roles(
+            LIQUID_STAKING_ADDRESS,
+            UNSTAKE_TOKEN_ID.as_bytes(),
+            &[
+                EsdtLocalRole::NftCreate,
+                EsdtLocalRole::NftAddQuantity,
+                EsdtLocalRole::NftBurn,
+            ],
+        );
+
+        Self { world }
+    }
+
+    // whitelists the mock delegation contract, activates the contract
+    // and adds the initial liquidity from the owner account
+    pub fn new_active(initial_liquidity: u64) -> Self {
+        let mut state = Self::new();
+        state.whitelist_delegation_contract();
+        state.set_state_active();
+        state.add_liquidity(OWNER_ADDRESS, initial_liquidity);
+
+        state
+    }
+
+    pub fn set_block(&mut self, epoch: u64, nonce: u64) {
+        self.world
+            .current_block()
+            .block_epoch(epoch)
+            .block_nonce(nonce);
+    }
+
+    pub fn whitelist_delegation_contract(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .whitelist_delegation_contract(
+                DELEGATION_ADDRESS,
+                DELEGATION_OWNER_ADDRESS,
+                BigUint::<StaticApi>::zero(),
+                exp18(DELEGATION_CONTRACT_CAP),
+                1u64,
+                DELEGATION_CONTRACT_APY,
+            )
+            .egld(exp18(1))
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn set_state_active(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .set_state_active()
+            .run();
+    }
+
+    pub fn add_liquidity(&mut self, caller: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(caller)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .add_liquidity(OptionalValue::<ManagedAddress<StaticApi>>::None)
+            .egld(exp18(amount))
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn register_partner(&mut self, partner: TestAddress) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .register_partner(partner)
+            .run();
+    }
+
+    pub fn add_integrator(&mut self, integrator: TestSCAddress) {
+        let mut integrators = MultiValueEncoded::new();
+        integrators.push(integrator.to_managed_address());
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .add_integrators(integrators)
+            .run();
+    }
+
+    // the minted payment is the result of the callback, empty when the delegation failed
+    pub fn add_liquidity_for<R>(
+        &mut self,
+        receiver: R,
+        amount: u64,
+        opt_referrer: Option<TestAddress>,
+    ) where
+        R: ProxyArg<ManagedAddress<StaticApi>>,
+    {
+        let opt_referrer: OptionalValue<ManagedAddress<StaticApi>> = opt_referrer
+            .map(|referrer| referrer.to_managed_address())
+            .into();
+        self.world
+            .tx()
+            .from(INTEGRATOR_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .add_liquidity_for(receiver, opt_referrer)
+            .egld(exp18(amount))
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn add_liquidity_for_check_error(&mut self, amount: u64, error_message: &str) {
+        self.world
+            .tx()
+            .from(INTEGRATOR_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .add_liquidity_for(
+                USER_ADDRESS,
+                OptionalValue::<ManagedAddress<StaticApi>>::None,
+            )
+            .egld(exp18(amount))
+            .gas(TX_GAS)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn remove_liquidity_for(
+        &mut self,
+        receiver: TestAddress,
+        ls_token_amount: BigUint<StaticApi>,
+    ) {
+        self.world
+            .tx()
+            .from(INTEGRATOR_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .remove_liquidity_for(receiver)
+            .single_esdt(&LS_TOKEN_ID.to_token_identifier(), 0, &ls_token_amount)
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn remove_liquidity(&mut self, caller: TestAddress, ls_token_amount: BigUint<StaticApi>) {
+        self.world
+            .tx()
+            .from(caller)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .remove_liquidity()
+            .single_esdt(&LS_TOKEN_ID.to_token_identifier(), 0, &ls_token_amount)
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn withdraw_all(&mut self, caller: TestAddress) {
+        self.world
+            .tx()
+            .from(caller)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .withdraw_all(DELEGATION_ADDRESS)
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn unbond_tokens(&mut self, caller: TestAddress, token_nonce: u64) {
+        self.world
+            .tx()
+            .from(caller)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .unbond_tokens()
+            .single_esdt(
+                &UNSTAKE_TOKEN_ID.to_token_identifier(),
+                token_nonce,
+                &BigUint::from(1u64),
+            )
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn claim_rewards(&mut self) {
+        self.world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .claim_rewards()
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn recompute_token_reserve(&mut self) {
+        self.world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .recompute_token_reserve()
+            .run();
+    }
+
+    pub fn delegate_rewards(&mut self) {
+        self.world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .delegate_rewards()
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn set_operation_failure(&mut self, operation: DelegationOperation, fail: bool) {
+        self.world
+            .tx()
+            .from(DELEGATION_OWNER_ADDRESS)
+            .to(DELEGATION_ADDRESS)
+            .typed(delegation_mock_proxy::DelegationMockProxy)
+            .set_operation_failure(operation, fail)
+            .run();
+    }
+
+    pub fn add_delegation_rewards(&mut self, amount: u64) {
+        self.world
+            .tx()
+            .from(DELEGATION_OWNER_ADDRESS)
+            .to(DELEGATION_ADDRESS)
+            .typed(delegation_mock_proxy::DelegationMockProxy)
+            .add_rewards(LIQUID_STAKING_ADDRESS)
+            .egld(exp18(amount))
+            .run();
+    }
+
+    pub fn unbond_tokens_check_error(
+        &mut self,
+        caller: TestAddress,
+        token_nonce: u64,
+        error_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(caller)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .unbond_tokens()
+            .single_esdt(
+                &UNSTAKE_TOKEN_ID.to_token_identifier(),
+                token_nonce,
+                &BigUint::from(1u64),
+            )
+            .gas(TX_GAS)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn set_rewards_per_epoch(&mut self, rewards_per_epoch: u64, funding: u64) {
+        self.world
+            .tx()
+            .from(DELEGATION_OWNER_ADDRESS)
+            .to(DELEGATION_ADDRESS)
+            .typed(delegation_mock_proxy::DelegationMockProxy)
+            .set_rewards_per_epoch(exp18(rewards_per_epoch))
+            .egld(exp18(funding))
+            .run();
+    }
+
+    pub fn modify_total_delegation_cap(&mut self, total_delegation_cap: u64) {
+        self.world
+            .tx()
+            .from(DELEGATION_OWNER_ADDRESS)
+            .to(DELEGATION_ADDRESS)
+            .typed(delegation_mock_proxy::DelegationMockProxy)
+            .modify_total_delegation_cap(exp18(total_delegation_cap))
+            .run();
+    }
+
+    pub fn set_staked_nodes(&mut self, nr_nodes: u8) {
+        let mut bls_keys = MultiValueEncoded::new();
+        for node_index in 0..nr_nodes {
+            bls_keys.push(ManagedBuffer::from(&[node_index; 96][..]));
+        }
+        self.world
+            .tx()
+            .from(DELEGATION_OWNER_ADDRESS)
+            .to(DELEGATION_ADDRESS)
+            .typed(delegation_mock_proxy::DelegationMockProxy)
+            .set_staked_nodes(bls_keys)
+            .run();
+    }
+
+    pub fn verify_delegation_contract(&mut self) {
+        self.world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .verify_delegation_contract(DELEGATION_ADDRESS)
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn apply_for_whitelist(&mut self, caller: TestAddress) {
+        self.world
+            .tx()
+            .from(caller)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .apply_for_whitelist(
+                DELEGATION_ADDRESS,
+                BigUint::<StaticApi>::zero(),
+                exp18(DELEGATION_CONTRACT_CAP),
+                1u64,
+                DELEGATION_CONTRACT_APY,
+            )
+            .egld(exp18(6))
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn approve_whitelist_application(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .approve_whitelist_application(DELEGATION_ADDRESS)
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn approve_whitelist_application_check_error(&mut self, error_message: &str) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .approve_whitelist_application(DELEGATION_ADDRESS)
+            .gas(TX_GAS)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn withdraw_whitelist_application(&mut self, caller: TestAddress) {
+        self.world
+            .tx()
+            .from(caller)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .withdraw_whitelist_application(DELEGATION_ADDRESS)
+            .run();
+    }
+
+    pub fn withdraw_whitelist_application_check_error(
+        &mut self,
+        caller: TestAddress,
+        error_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(caller)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .withdraw_whitelist_application(DELEGATION_ADDRESS)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn release_provider_bond(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .release_provider_bond(DELEGATION_ADDRESS)
+            .run();
+    }
+
+    pub fn set_delegation_unbond_period(&mut self, unbond_period: u64) {
+        self.world
+            .tx()
+            .from(DELEGATION_OWNER_ADDRESS)
+            .to(DELEGATION_ADDRESS)
+            .typed(delegation_mock_proxy::DelegationMockProxy)
+            .set_unbond_period(unbond_period)
+            .run();
+    }
+
+    pub fn set_governance_operation_failure(&mut self, operation: GovernanceOperation, fail: bool) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(GovernanceSystemSCAddress)
+            .typed(governance_mock_proxy::GovernanceMockProxy)
+            .set_operation_failure(operation, fail)
+            .run();
+    }
+
+    pub fn create_governance_proposal(&mut self, start_vote_epoch: u64, end_vote_epoch: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(GovernanceSystemSCAddress)
+            .typed(governance_mock_proxy::GovernanceMockProxy)
+            .proposal(
+                ManagedBuffer::from("commit-hash"),
+                start_vote_epoch,
+                end_vote_epoch,
+            )
+            .run();
+    }
+
+    pub fn set_vote_contract(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .set_vote_contract(VOTE_CONTRACT_ADDRESS)
+            .run();
+    }
+
+    pub fn delegate_vote(
+        &mut self,
+        proposal: u32,
+        vote_type: &str,
+        delegate_to: TestAddress,
+        voting_power: u64,
+    ) {
+        self.world
+            .tx()
+            .from(VOTE_CONTRACT_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .delegate_vote(proposal, vote_type, delegate_to, exp18(voting_power))
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn delegate_vote_check_error(
+        &mut self,
+        caller: TestAddress,
+        proposal: u32,
+        vote_type: &str,
+        error_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(caller)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .delegate_vote(proposal, vote_type, caller, exp18(1))
+            .gas(TX_GAS)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn cast_aggregated_vote(&mut self, proposal: u32, vote_type: &str) {
+        self.world
+            .tx()
+            .from(VOTE_CONTRACT_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .cast_aggregated_vote(proposal, vote_type)
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn cast_aggregated_vote_check_error(&mut self, proposal: u32, error_message: &str) {
+        self.world
+            .tx()
+            .from(VOTE_CONTRACT_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .cast_aggregated_vote(proposal, "yes")
+            .gas(TX_GAS)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn check_aggregated_vote_cast(&mut self, proposal: u32, expected: bool) {
+        self.world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .aggregated_vote_cast(proposal)
+            .returns(ExpectValue(expected))
+            .run();
+    }
+
+    pub fn register_protocol_proposal(&mut self, proposal: u32, start_epoch: u64, end_epoch: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .register_protocol_proposal(proposal, start_epoch, end_epoch)
+            .run();
+    }
+
+    pub fn register_protocol_proposal_check_error(
+        &mut self,
+        proposal: u32,
+        start_epoch: u64,
+        end_epoch: u64,
+        error_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .register_protocol_proposal(proposal, start_epoch, end_epoch)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn decide_protocol_vote(&mut self, proposal: u32, vote_type: &str) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .decide_protocol_vote(proposal, vote_type)
+            .run();
+    }
+
+    pub fn decide_protocol_vote_check_error(
+        &mut self,
+        proposal: u32,
+        vote_type: &str,
+        error_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .decide_protocol_vote(proposal, vote_type)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn cast_protocol_vote_check_error(&mut self, proposal: u32, error_message: &str) {
+        self.world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .cast_protocol_vote(proposal)
+            .gas(TX_GAS)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn cast_protocol_vote(&mut self, proposal: u32) {
+        self.world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .cast_protocol_vote(proposal)
+            .gas(TX_GAS)
+            .run();
+    }
+
+    pub fn check_delegation_stake(
+        &mut self,
+        active: BigUint<StaticApi>,
+        unstaked: BigUint<StaticApi>,
+    ) {
+        self.world
+            .query()
+            .to(DELEGATION_ADDRESS)
+            .typed(delegation_mock_proxy::DelegationMockProxy)
+            .active_stake(LIQUID_STAKING_ADDRESS)
+            .returns(ExpectValue(active))
+            .run();
+        self.world
+            .query()
+            .to(DELEGATION_ADDRESS)
+            .typed(delegation_mock_proxy::DelegationMockProxy)
+            .unstaked_stake(LIQUID_STAKING_ADDRESS)
+            .returns(ExpectValue(unstaked))
+            .run();
+    }
+
+    pub fn check_delegation_addresses(&mut self, expected: &[TestSCAddress]) {
+        let delegation_addresses = self
+            .world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .delegation_addresses_list()
+            .returns(ReturnsResultUnmanaged)
+            .run();
+        let expected: Vec<Address> = expected
+            .iter()
+            .map(|address| address.to_address())
+            .collect();
+        assert_eq!(delegation_addresses.into_vec(), expected);
+    }
+
+    pub fn check_low_priority_delegation_addresses(&mut self, expected: &[TestSCAddress]) {
+        let delegation_addresses = self
+            .world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .low_priority_delegation_addresses()
+            .returns(ReturnsResultUnmanaged)
+            .run();
+        let expected: Vec<Address> = expected
+            .iter()
+            .map(|address| address.to_address())
+            .collect();
+        assert_eq!(delegation_addresses.into_vec(), expected);
+    }
+
+    pub fn check_failed_claims(&mut self, expected: &[TestSCAddress]) {
+        let failed_claims = self
+            .world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .failed_claims()
+            .returns(ReturnsResultUnmanaged)
+            .run();
+        let expected: Vec<Address> = expected
+            .iter()
+            .map(|address| address.to_address())
+            .collect();
+        assert_eq!(failed_claims.into_vec(), expected);
+    }
+
+    pub fn check_staked_amounts(
+        &mut self,
+        staked: BigUint<StaticApi>,
+        unstaked: BigUint<StaticApi>,
+        unbonded: BigUint<StaticApi>,
+    ) {
+        self.world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .get_delegation_contract_staked_amount(DELEGATION_ADDRESS)
+            .returns(ExpectValue(staked))
+            .run();
+        self.world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .get_delegation_contract_unstaked_amount(DELEGATION_ADDRESS)
+            .returns(ExpectValue(unstaked))
+            .run();
+        self.world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .get_delegation_contract_unbonded_amount(DELEGATION_ADDRESS)
+            .returns(ExpectValue(unbonded))
+            .run();
+    }
+
+    pub fn check_rewards_reserve(&mut self, expected: BigUint<StaticApi>) {
+        self.world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .rewards_reserve()
+            .returns(ExpectValue(expected))
+            .run();
+    }
+
+    pub fn check_virtual_egld_reserve(&mut self, expected: BigUint<StaticApi>) {
+        self.world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .virtual_egld_reserve()
+            .returns(ExpectValue(expected))
+            .run();
+    }
+
+    pub fn check_delegation_status(&mut self, expected: ClaimStatusType) {
+        let status = self
+            .world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .get_delegation_status()
+            .returns(ReturnsResult)
+            .run();
+        assert!(status == expected);
+    }
+
+    pub fn check_egld_balance<A>(&mut self, address: A, expected: BigUint<StaticApi>)
+    where
+        A: AnnotatedValue<ScenarioTxEnvData, ManagedAddress<StaticApi>>,
+    {
+        self.world.check_account(address).balance(expected);
+    }
+
+    pub fn check_ls_token_balance<A>(&mut self, address: A, expected: BigUint<StaticApi>)
+    where
+        A: AnnotatedValue<ScenarioTxEnvData, ManagedAddress<StaticApi>>,
+    {
+        self.world
+            .check_account(address)
+            .esdt_balance(LS_TOKEN_ID, expected);
+    }
+
+    // the NFT carries display attributes, the position itself is kept in the contract storage
+    pub fn check_unstake_token(
+        &mut self,
+        address: TestAddress,
+        token_nonce: u64,
+        unstake_amount: BigUint<StaticApi>,
+        unstake_epoch: u64,
+    ) {
+        let unbond_epoch = unstake_epoch + UNBOND_PERIOD;
+        let attributes = UnstakeTokenAttributes::<StaticApi> {
+            delegation_contract: DELEGATION_ADDRESS.to_managed_address(),
+            unstake_epoch,
+            unstake_amount,
+            unbond_epoch,
+        };
+        self.world
+            .check_account(address)
+            .esdt_nft_balance_and_attributes(UNSTAKE_TOKEN_ID, token_nonce, 1u64, attributes);
+    }
+
+    pub fn check_governance_vote<A>(
+        &mut self,
+        proposal: u32,
+        voter: A,
+        vote_type: &str,
+        voting_power: BigUint<StaticApi>,
+    ) where
+        A: ProxyArg<ManagedAddress<StaticApi>>,
+    {
+        let vote = self
+            .world
+            .query()
+            .to(GovernanceSystemSCAddress)
+            .typed(governance_mock_proxy::GovernanceMockProxy)
+            .votes(proposal, voter)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(vote.vote_type, ManagedBuffer::from(vote_type));
+        assert_eq!(vote.voting_power, voting_power);
+    }
+
+    pub fn check_governance_tally(
+        &mut self,
+        proposal: u32,
+        vote_type: &str,
+        expected: BigUint<StaticApi>,
+    ) {
+        self.world
+            .query()
+            .to(GovernanceSystemSCAddress)
+            .typed(governance_mock_proxy::GovernanceMockProxy)
+            .proposal_tally(proposal, vote_type)
+            .returns(ExpectValue(expected))
+            .run();
+    }
+
+    pub fn check_delegation_contract_data(
+        &mut self,
+        total_staked: BigUint<StaticApi>,
+        delegation_contract_cap: BigUint<StaticApi>,
+        nr_nodes: u64,
+    ) {
+        let contract_data = self
+            .world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .delegation_contract_data(DELEGATION_ADDRESS)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(contract_data.total_staked, total_staked);
+        assert_eq!(
+            contract_data.delegation_contract_cap,
+            delegation_contract_cap
+        );
+        assert_eq!(contract_data.nr_nodes, nr_nodes);
+    }
+
+    pub fn check_flagged_delegation_contract(
+        &mut self,
+        total_staked: BigUint<StaticApi>,
+        delegation_contract_cap: Option<BigUint<StaticApi>>,
+        nr_nodes: u64,
+    ) {
+        let verification = self
+            .world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .get_delegation_contract_verification(DELEGATION_ADDRESS)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(verification.verified.total_staked, Some(total_staked));
+        assert_eq!(
+            verification.verified.delegation_contract_cap,
+            delegation_contract_cap
+        );
+        assert_eq!(verification.verified.nr_nodes, Some(nr_nodes));
+        assert!(verification.total_staked_mismatch);
+        assert!(verification.delegation_contract_cap_mismatch);
+        assert!(verification.nr_nodes_mismatch);
+    }
+
+    pub fn check_mismatched_delegation_contracts(&mut self, expected: &[TestSCAddress]) {
+        let mismatched = self
+            .world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .get_mismatched_delegation_contracts()
+            .returns(ReturnsResultUnmanaged)
+            .run();
+        let expected: Vec<Address> = expected
+            .iter()
+            .map(|address| address.to_address())
+            .collect();
+        assert_eq!(mismatched.into_vec(), expected);
+    }
+
+    pub fn check_referral_amount<U>(&mut self, user: U, expected: BigUint<StaticApi>)
+    where
+        U: ProxyArg<ManagedAddress<StaticApi>>,
+    {
+        let referral = self
+            .world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .user_referral(user)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(referral.ls_token_amount, expected);
+    }
+
+    pub fn check_partner_tvl(&mut self, partner: TestAddress, expected: BigUint<StaticApi>) {
+        self.world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .get_partner_tvl(partner)
+            .returns(ExpectValue(expected))
+            .run();
+    }
+
+    pub fn check_delegated_voting_power(&mut self, proposal: u32, expected: BigUint<StaticApi>) {
+        self.world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .delegated_voting_power(proposal)
+            .returns(ExpectValue(expected))
+            .run();
+    }
+
+    pub fn check_protocol_voted_proposals(&mut self, expected: &[u32]) {
+        let voted_proposals = self
+            .world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .protocol_voted_proposals()
+            .returns(ReturnsResultUnmanaged)
+            .run();
+        assert_eq!(voted_proposals.into_vec(), expected);
+    }
+}
@@ -0,0 +1,471 @@
+mod blackbox_setup;
+
+use blackbox_setup::*;
+use delegation_mock::delegation_mock_proxy::DelegationOperation;
+use governance_mock::governance_mock_proxy::GovernanceOperation;
+use liquid_staking::{basics::constants::MAX_CLAIM_RETRIES, liquid_staking_proxy::ClaimStatusType};
+use multiversx_sc_scenario::imports::*;
+
+#[test]
+fn blackbox_whitelist_callback_test() {
+    let mut state = LiquidStakingTestState::new();
+
+    state.set_operation_failure(DelegationOperation::Delegate, true);
+    state.whitelist_delegation_contract();
+    state.check_delegation_addresses(&[]);
+    state.check_egld_balance(OWNER_ADDRESS, exp18(INITIAL_EGLD_BALANCE));
+
+    state.set_operation_failure(DelegationOperation::Delegate, false);
+    state.whitelist_delegation_contract();
+    state.check_delegation_addresses(&[DELEGATION_ADDRESS]);
+    state.check_egld_balance(OWNER_ADDRESS, exp18(INITIAL_EGLD_BALANCE - 1));
+    state.check_delegation_stake(exp18(1), BigUint::zero());
+}
+
+#[test]
+fn blackbox_add_liquidity_callback_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+    state.check_ls_token_balance(OWNER_ADDRESS, exp18(10) - MINIMUM_LIQUIDITY);
+
+    state.add_liquidity(USER_ADDRESS, 5);
+    state.check_ls_token_balance(USER_ADDRESS, exp18(5));
+    state.check_staked_amounts(exp18(15), BigUint::zero(), BigUint::zero());
+    state.check_delegation_stake(exp18(16), BigUint::zero());
+
+    // the failed delegation is refunded and the delegation contract loses its priority
+    state.set_operation_failure(DelegationOperation::Delegate, true);
+    state.add_liquidity(USER_ADDRESS, 5);
+    state.check_egld_balance(USER_ADDRESS, exp18(INITIAL_EGLD_BALANCE - 5));
+    state.check_ls_token_balance(USER_ADDRESS, exp18(5));
+    state.check_staked_amounts(exp18(15), BigUint::zero(), BigUint::zero());
+    state.check_low_priority_delegation_addresses(&[DELEGATION_ADDRESS]);
+
+    // the next successful delegation gives the delegation contract its priority back
+    state.set_operation_failure(DelegationOperation::Delegate, false);
+    state.add_liquidity(USER_ADDRESS, 5);
+    state.check_ls_token_balance(USER_ADDRESS, exp18(10));
+    state.check_low_priority_delegation_addresses(&[]);
+}
+
+#[test]
+fn blackbox_remove_liquidity_callback_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+    state.add_liquidity(USER_ADDRESS, 5);
+    state.set_block(5, 5);
+
+    // the failed undelegation gives the lsEGLD back to the caller
+    state.set_operation_failure(DelegationOperation::Undelegate, true);
+    state.remove_liquidity(USER_ADDRESS, exp18(2));
+    state.check_ls_token_balance(USER_ADDRESS, exp18(5));
+    state.check_staked_amounts(exp18(15), BigUint::zero(), BigUint::zero());
+    state.check_low_priority_delegation_addresses(&[DELEGATION_ADDRESS]);
+
+    state.set_operation_failure(DelegationOperation::Undelegate, false);
+    state.remove_liquidity(USER_ADDRESS, exp18(2));
+    state.check_ls_token_balance(USER_ADDRESS, exp18(3));
+    state.check_unstake_token(USER_ADDRESS, 1, exp18(2), 5);
+    state.check_staked_amounts(exp18(13), exp18(2), BigUint::zero());
+    state.check_delegation_stake(exp18(14), exp18(2));
+    state.check_low_priority_delegation_addresses(&[]);
+}
+
+#[test]
+fn blackbox_withdraw_callback_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+    state.add_liquidity(USER_ADDRESS, 5);
+    state.set_block(5, 5);
+    state.remove_liquidity(USER_ADDRESS, exp18(2));
+
+    // a failed withdrawal leaves the undelegated stake in the delegation contract
+    state.set_block(15, 15);
+    state.set_operation_failure(DelegationOperation::Withdraw, true);
+    state.withdraw_all(USER_ADDRESS);
+    state.check_staked_amounts(exp18(13), exp18(2), BigUint::zero());
+    state.check_delegation_stake(exp18(14), exp18(2));
+
+    state.set_operation_failure(DelegationOperation::Withdraw, false);
+    state.withdraw_all(USER_ADDRESS);
+    state.check_staked_amounts(exp18(13), exp18(2), exp18(2));
+    state.check_delegation_stake(exp18(14), BigUint::zero());
+
+    state.unbond_tokens(USER_ADDRESS, 1);
+    state.check_egld_balance(USER_ADDRESS, exp18(INITIAL_EGLD_BALANCE - 3));
+}
+
+#[test]
+fn blackbox_integrator_transfers_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+
+    // contracts have to be whitelisted by default
+    state.add_liquidity_for_check_error(5, "Caller contract is not whitelisted");
+    state.add_integrator(INTEGRATOR_ADDRESS);
+
+    // the minted lsEGLD and unstake NFT only reach the receiver as transfers
+    state.add_liquidity_for(USER_ADDRESS, 5, None);
+    state.check_ls_token_balance(USER_ADDRESS, exp18(5));
+    state.check_egld_balance(INTEGRATOR_ADDRESS, exp18(INITIAL_EGLD_BALANCE - 5));
+
+    state.add_liquidity_for(INTEGRATOR_ADDRESS, 3, None);
+    state.check_ls_token_balance(INTEGRATOR_ADDRESS, exp18(3));
+    state.remove_liquidity_for(USER_ADDRESS, exp18(2));
+    state.check_ls_token_balance(INTEGRATOR_ADDRESS, exp18(1));
+    state.check_unstake_token(USER_ADDRESS, 1, exp18(2), 0);
+
+    // a failed delegation refunds the EGLD to the integrator
+    state.set_operation_failure(DelegationOperation::Delegate, true);
+    state.add_liquidity_for(USER_ADDRESS, 5, None);
+    state.check_ls_token_balance(USER_ADDRESS, exp18(5));
+    state.check_egld_balance(INTEGRATOR_ADDRESS, exp18(INITIAL_EGLD_BALANCE - 8));
+}
+
+#[test]
+fn blackbox_integrator_referral_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+    state.add_integrator(INTEGRATOR_ADDRESS);
+    state.register_partner(DELEGATION_OWNER_ADDRESS);
+
+    // the referral is credited to the receiver of the minted lsEGLD
+    state.add_liquidity_for(USER_ADDRESS, 5, Some(DELEGATION_OWNER_ADDRESS));
+    state.add_liquidity_for(INTEGRATOR_ADDRESS, 3, Some(DELEGATION_OWNER_ADDRESS));
+    state.check_referral_amount(USER_ADDRESS, exp18(5));
+    state.check_referral_amount(INTEGRATOR_ADDRESS, exp18(3));
+    state.check_partner_tvl(DELEGATION_OWNER_ADDRESS, exp18(8));
+
+    // and removed from the caller that burns it, whoever receives the unstake NFT
+    state.remove_liquidity_for(USER_ADDRESS, exp18(2));
+    state.check_referral_amount(USER_ADDRESS, exp18(5));
+    state.check_referral_amount(INTEGRATOR_ADDRESS, exp18(1));
+    state.check_partner_tvl(DELEGATION_OWNER_ADDRESS, exp18(6));
+}
+
+#[test]
+fn blackbox_claim_rewards_callback_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+    state.add_delegation_rewards(2);
+    state.set_block(1, 1);
+
+    // a failed claim is recorded and retried by the next claim operation
+    state.set_operation_failure(DelegationOperation::ClaimRewards, true);
+    state.claim_rewards();
+    state.check_failed_claims(&[DELEGATION_ADDRESS]);
+    state.check_rewards_reserve(BigUint::zero());
+    state.check_delegation_status(ClaimStatusType::Finished);
+
+    state.set_operation_failure(DelegationOperation::ClaimRewards, false);
+    state.claim_rewards();
+    state.check_failed_claims(&[]);
+    state.check_rewards_reserve(exp18(2));
+}
+
+#[test]
+fn blackbox_failing_claim_rewards_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+    state.add_delegation_rewards(2);
+    state.set_operation_failure(DelegationOperation::ClaimRewards, true);
+
+    // a failed claim left from the previous epoch does not block the next claim operation
+    state.set_block(1, 1);
+    state.claim_rewards();
+    state.check_failed_claims(&[DELEGATION_ADDRESS]);
+    state.set_block(1, 11);
+    state.recompute_token_reserve();
+    state.check_delegation_status(ClaimStatusType::Insufficient);
+
+    state.set_block(2, 12);
+    state.claim_rewards();
+    state.check_failed_claims(&[DELEGATION_ADDRESS]);
+    state.check_delegation_status(ClaimStatusType::Finished);
+
+    // the retries are bounded, the failing delegation contract is dropped afterwards
+    for _ in 0..MAX_CLAIM_RETRIES {
+        state.check_failed_claims(&[DELEGATION_ADDRESS]);
+        state.claim_rewards();
+    }
+    state.check_failed_claims(&[]);
+    state.check_rewards_reserve(BigUint::zero());
+
+    state.set_operation_failure(DelegationOperation::ClaimRewards, false);
+    state.set_block(2, 22);
+    state.recompute_token_reserve();
+    state.set_block(3, 23);
+    state.claim_rewards();
+    state.check_failed_claims(&[]);
+    state.check_rewards_reserve(exp18(2));
+}
+
+#[test]
+fn blackbox_delegate_rewards_callback_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+    state.add_delegation_rewards(2);
+    state.set_block(1, 1);
+    state.claim_rewards();
+    state.set_block(1, 11);
+    state.recompute_token_reserve();
+    state.check_delegation_status(ClaimStatusType::Delegable);
+
+    // the failed delegation puts the rewards back in the reserve
+    state.set_operation_failure(DelegationOperation::Delegate, true);
+    state.delegate_rewards();
+    state.check_rewards_reserve(exp18(2));
+    state.check_delegation_status(ClaimStatusType::Delegable);
+    state.check_low_priority_delegation_addresses(&[DELEGATION_ADDRESS]);
+
+    state.set_operation_failure(DelegationOperation::Delegate, false);
+    state.delegate_rewards();
+    state.check_rewards_reserve(BigUint::zero());
+    state.check_delegation_status(ClaimStatusType::Redelegated);
+    state.check_virtual_egld_reserve(exp18(12));
+    state.check_staked_amounts(exp18(12), BigUint::zero(), BigUint::zero());
+    state.check_delegation_stake(exp18(13), BigUint::zero());
+}
+
+#[test]
+fn blackbox_epoch_rewards_test() {
+    let mut state = LiquidStakingTestState::new_active(9);
+    state.set_rewards_per_epoch(1, 10);
+
+    // the contract holds the whole stake of the delegation contract, whitelist fee included
+    state.set_block(3, 3);
+    state.claim_rewards();
+    state.check_rewards_reserve(exp18(3));
+
+    // the rewards do not fit under the cap of the delegation contract anymore
+    state.set_block(3, 13);
+    state.recompute_token_reserve();
+    state.modify_total_delegation_cap(12);
+    state.delegate_rewards();
+    state.check_rewards_reserve(exp18(3));
+    state.check_low_priority_delegation_addresses(&[DELEGATION_ADDRESS]);
+
+    state.modify_total_delegation_cap(13);
+    state.delegate_rewards();
+    state.check_rewards_reserve(BigUint::zero());
+    state.check_virtual_egld_reserve(exp18(12));
+    state.check_delegation_stake(exp18(13), BigUint::zero());
+}
+
+#[test]
+fn blackbox_delegation_cap_test() {
+    let mut state = LiquidStakingTestState::new_active(9);
+    state.modify_total_delegation_cap(12);
+
+    state.add_liquidity(USER_ADDRESS, 5);
+    state.check_egld_balance(USER_ADDRESS, exp18(INITIAL_EGLD_BALANCE));
+    state.check_low_priority_delegation_addresses(&[DELEGATION_ADDRESS]);
+
+    state.add_liquidity(USER_ADDRESS, 2);
+    state.check_ls_token_balance(USER_ADDRESS, exp18(2));
+    state.check_staked_amounts(exp18(11), BigUint::zero(), BigUint::zero());
+    state.check_delegation_stake(exp18(12), BigUint::zero());
+}
+
+#[test]
+fn blackbox_delayed_withdraw_test() {
+    let mut state = LiquidStakingTestState::new_active(9);
+    state.set_delegation_unbond_period(UNBOND_PERIOD);
+    state.add_liquidity(USER_ADDRESS, 5);
+
+    state.set_block(5, 5);
+    state.remove_liquidity(USER_ADDRESS, exp18(2));
+    state.set_block(7, 7);
+    state.remove_liquidity(USER_ADDRESS, exp18(1));
+
+    // nothing is released before the unbond period of the delegation contract
+    state.set_block(12, 12);
+    state.withdraw_all(USER_ADDRESS);
+    state.check_staked_amounts(exp18(11), exp18(3), BigUint::zero());
+    state.check_delegation_stake(exp18(12), exp18(3));
+    state.unbond_tokens_check_error(USER_ADDRESS, 1, "The unstake period has not passed");
+
+    state.set_block(15, 15);
+    state.withdraw_all(USER_ADDRESS);
+    state.check_staked_amounts(exp18(11), exp18(3), exp18(2));
+    state.check_delegation_stake(exp18(12), exp18(1));
+    state.unbond_tokens(USER_ADDRESS, 1);
+    state.check_egld_balance(USER_ADDRESS, exp18(INITIAL_EGLD_BALANCE - 3));
+    state.unbond_tokens_check_error(USER_ADDRESS, 2, "The unstake period has not passed");
+
+    state.set_block(17, 17);
+    state.withdraw_all(USER_ADDRESS);
+    state.check_staked_amounts(exp18(11), exp18(1), exp18(1));
+    state.unbond_tokens(USER_ADDRESS, 2);
+    state.check_egld_balance(USER_ADDRESS, exp18(INITIAL_EGLD_BALANCE - 2));
+}
+
+#[test]
+fn blackbox_delegation_contract_verification_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+    state.check_delegation_contract_data(BigUint::zero(), exp18(DELEGATION_CONTRACT_CAP), 1);
+
+    // the provider over-reports its cap and its nodes, the reported values are kept
+    state.set_staked_nodes(0);
+    state.modify_total_delegation_cap(500);
+    state.verify_delegation_contract();
+    state.check_delegation_contract_data(BigUint::zero(), exp18(DELEGATION_CONTRACT_CAP), 1);
+    state.check_flagged_delegation_contract(exp18(11), Some(exp18(500)), 0);
+    state.check_mismatched_delegation_contracts(&[DELEGATION_ADDRESS]);
+
+    // the false report is still flagged after the next verification
+    state.set_block(1, 1);
+    state.verify_delegation_contract();
+    state.check_delegation_contract_data(BigUint::zero(), exp18(DELEGATION_CONTRACT_CAP), 1);
+    state.check_mismatched_delegation_contracts(&[DELEGATION_ADDRESS]);
+}
+
+#[test]
+fn blackbox_delegate_vote_test() {
+    let mut state = LiquidStakingTestState::new_active(9);
+    state.set_vote_contract();
+    state.create_governance_proposal(1, 5);
+    state.set_block(2, 2);
+
+    state.delegate_vote_check_error(USER_ADDRESS, 1, "yes", "Invalid caller");
+
+    state.delegate_vote(1, "yes", USER_ADDRESS, 3);
+    state.check_governance_vote(1, USER_ADDRESS, "yes", exp18(3));
+    state.check_governance_tally(1, "yes", exp18(3));
+
+    state.check_delegated_voting_power(1, exp18(3));
+
+    // a rejected vote releases its voting power
+    state.set_governance_operation_failure(GovernanceOperation::DelegateVote, true);
+    state.delegate_vote(1, "no", OWNER_ADDRESS, 2);
+    state.check_governance_tally(1, "no", BigUint::zero());
+    state.check_delegated_voting_power(1, exp18(3));
+
+    state.set_governance_operation_failure(GovernanceOperation::DelegateVote, false);
+    state.delegate_vote(1, "no", OWNER_ADDRESS, 2);
+    state.check_governance_vote(1, OWNER_ADDRESS, "no", exp18(2));
+    state.check_governance_tally(1, "no", exp18(2));
+    state.check_delegated_voting_power(1, exp18(5));
+}
+
+#[test]
+fn blackbox_aggregated_vote_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+    state.set_vote_contract();
+    state.create_governance_proposal(1, 5);
+    state.set_block(2, 2);
+    state.delegate_vote(1, "yes", USER_ADDRESS, 4);
+
+    // a rejected aggregated vote is not recorded, but stays cast
+    state.set_governance_operation_failure(GovernanceOperation::Vote, true);
+    state.cast_aggregated_vote(1, "no");
+    state.check_aggregated_vote_cast(1, true);
+    state.cast_aggregated_vote_check_error(1, "Aggregated vote was already cast");
+    state.set_governance_operation_failure(GovernanceOperation::Vote, false);
+
+    // the aggregated vote is a single governance vote of the protocol
+    state.create_governance_proposal(1, 5);
+    state.delegate_vote(2, "yes", USER_ADDRESS, 4);
+    state.cast_aggregated_vote(2, "no");
+    state.check_governance_vote(2, LIQUID_STAKING_ADDRESS, "no", BigUint::zero());
+    state.check_governance_vote(2, USER_ADDRESS, "yes", exp18(4));
+    state.check_aggregated_vote_cast(2, true);
+    state.check_delegated_voting_power(2, exp18(4));
+
+    state.cast_aggregated_vote_check_error(2, "Aggregated vote was already cast");
+}
+
+#[test]
+fn blackbox_protocol_proposal_conflicts_test() {
+    let mut state = LiquidStakingTestState::new_active(10);
+    state.set_vote_contract();
+    state.create_governance_proposal(1, 5);
+    state.create_governance_proposal(1, 5);
+    state.create_governance_proposal(1, 5);
+    state.set_block(2, 2);
+
+    // a proposal voted through the vote contract cannot be voted by the protocol
+    state.delegate_vote(1, "yes", USER_ADDRESS, 4);
+    state.register_protocol_proposal_check_error(
+        1,
+        2,
+        5,
+        "Proposal is already voted through the vote contract",
+    );
+    state.cast_aggregated_vote(2, "no");
+    state.register_protocol_proposal_check_error(
+        2,
+        2,
+        5,
+        "Proposal is already voted through the vote contract",
+    );
+
+    // and a protocol proposal cannot be voted through the vote contract
+    state.register_protocol_proposal(3, 3, 5);
+    state.cast_aggregated_vote_check_error(3, "Proposal is voted directly by the protocol");
+
+    // the owner decides and casts the vote within the voting window only
+    state.decide_protocol_vote_check_error(3, "yes", "Protocol voting is not open");
+    state.set_block(3, 3);
+    state.cast_protocol_vote_check_error(3, "Protocol vote is not decided");
+    state.decide_protocol_vote(3, "yes");
+    state.set_block(5, 5);
+    state.cast_protocol_vote_check_error(3, "Protocol voting is not open");
+    state.check_protocol_voted_proposals(&[]);
+}
+
+#[test]
+fn blackbox_protocol_vote_callback_test() {
+    let mut state = LiquidStakingTestState::new_active(9);
+    state.create_governance_proposal(1, 5);
+    state.set_block(2, 2);
+    state.register_protocol_proposal(1, 2, 5);
+    state.decide_protocol_vote(1, "no");
+
+    // a rejected protocol vote is forgotten, so that it can be cast again
+    state.set_governance_operation_failure(GovernanceOperation::Vote, true);
+    state.cast_protocol_vote(1);
+    state.check_protocol_voted_proposals(&[]);
+
+    state.set_governance_operation_failure(GovernanceOperation::Vote, false);
+    state.cast_protocol_vote(1);
+    state.check_protocol_voted_proposals(&[1]);
+    state.check_governance_vote(1, LIQUID_STAKING_ADDRESS, "no", BigUint::zero());
+}
+
+#[test]
+fn blackbox_whitelist_application_test() {
+    let mut state = LiquidStakingTestState::new();
+
+    // only the owner of the delegation contract can apply, other applicants are refunded
+    state.apply_for_whitelist(USER_ADDRESS);
+    state.check_egld_balance(USER_ADDRESS, exp18(INITIAL_EGLD_BALANCE));
+    state.approve_whitelist_application_check_error("Whitelist application is not verified yet");
+
+    state.apply_for_whitelist(DELEGATION_OWNER_ADDRESS);
+    state.check_egld_balance(DELEGATION_OWNER_ADDRESS, exp18(INITIAL_EGLD_BALANCE - 6));
+    state.approve_whitelist_application();
+    state.check_delegation_addresses(&[DELEGATION_ADDRESS]);
+
+    // the bond of a reliable provider is returned to the admin of the delegation contract
+    state.release_provider_bond();
+    state.check_egld_balance(DELEGATION_OWNER_ADDRESS, exp18(INITIAL_EGLD_BALANCE - 1));
+}
+
+#[test]
+fn blackbox_whitelist_application_expiry_test() {
+    let mut state = LiquidStakingTestState::new();
+
+    state.set_block(1, 1);
+    state.apply_for_whitelist(DELEGATION_OWNER_ADDRESS);
+    state.check_egld_balance(DELEGATION_OWNER_ADDRESS, exp18(INITIAL_EGLD_BALANCE - 6));
+
+    // only the applicant can withdraw, and only once the application expired
+    state.withdraw_whitelist_application_check_error(USER_ADDRESS, "Invalid caller");
+    state.set_block(10, 10);
+    state.withdraw_whitelist_application_check_error(
+        DELEGATION_OWNER_ADDRESS,
+        "Whitelist application has not expired yet",
+    );
+
+    state.set_block(11, 11);
+    state.withdraw_whitelist_application(DELEGATION_OWNER_ADDRESS);
+    state.check_egld_balance(DELEGATION_OWNER_ADDRESS, exp18(INITIAL_EGLD_BALANCE));
+    state.approve_whitelist_application_check_error("Whitelist application is not verified yet");
+    state.withdraw_whitelist_application_check_error(
+        DELEGATION_OWNER_ADDRESS,
+        "Whitelist application does not exist",
+    );
+}
@@ -1,17 +1,46 @@
-use crate::contract_setup::LiquidStakingContractSetup;
+use crate::contract_setup::{
+    LiquidStakingContractSetup, DELEGATION_MOCK_WASM_PATH, LS_TOKEN_ID, UNSTAKE_TOKEN_ID,
+    WEGLD_SWAP_MOCK_WASM_PATH, WEGLD_TOKEN_ID,
+};
+use crate::wegld_swap_mock::{self, WegldSwapMock};
 use basics::views::ViewsModule;
+use delegation_mock::DelegationMock;
 use funds::{
     claim::ClaimModule, delegate_rewards::DelegateRewardsModule,
     recompute_token_reserve::RecomputeTokenReserveModule, unbond::UnbondModule,
-    withdraw::WithdrawModule,
+    undelegation::UndelegationModule, withdraw::WithdrawModule,
 };
 
 use liquid_staking::*;
-use liquidity::{add_liquidity::AddLiquidityModule, remove_liquidity::RemoveLiquidityModule};
-use multiversx_sc::types::Address;
-use multiversx_sc_scenario::{managed_address, num_bigint, rust_biguint, DebugApi};
+use liquidity::{
+    add_liquidity::AddLiquidityModule, rebasing_wrapper::RebasingWrapperModule,
+    remove_liquidity::RemoveLiquidityModule,
+};
+use liquidity_pool::{LiquidityPoolModule, State};
+use market::unstake_market::UnstakeMarketModule;
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    contract_base::ContractBase,
+    types::{
+        Address, BigUint, ManagedAsyncCallError, ManagedAsyncCallResult, ManagedBuffer,
+        MultiValueEncoded,
+    },
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_buffer, managed_token_id, num_bigint, rust_biguint,
+    testing_framework::ContractObjWrapper, DebugApi,
+};
+use partners::{partner_share::PartnerShareModule, referral::ReferralModule};
 use setup::config::{ConfigModule, UnstakeTokenAttributes};
-use setup::delegation::DelegationModule;
+use setup::delegation::{DelegationContractData, DelegationModule, PendingWhitelist};
+use setup::integrators::IntegratorsModule;
+use setup::onboarding::OnboardingModule;
+use setup::parameters::ParametersModule;
+use setup::protocol_vote::{ProtocolVoteMode, ProtocolVoteModule};
+use setup::verification::VerificationModule;
+use setup::vote::VoteModule;
+use setup::vote_lock::VoteLockModule;
+use setup::wegld::WegldModule;
 
 // pub const EGLD_TO_WHITELIST: u64 = 1;
 pub const FIRST_ADD_LIQUIDITY_AMOUNT: u64 = 100;
@@ -183,6 +212,63 @@ where
             .assert_ok();
     }
 
+    pub fn set_unbond_period(&mut self, caller: &Address, unbond_period: u64) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.set_unbond_period(unbond_period);
+            })
+            .assert_ok();
+    }
+
+    pub fn set_unbond_period_check_error(
+        &mut self,
+        caller: &Address,
+        unbond_period: u64,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.set_unbond_period(unbond_period);
+            })
+            .assert_user_error(err_message);
+    }
+
+    pub fn set_min_egld_to_delegate_check_error(&mut self, amount: u64, err_message: &str) {
+        let owner_address = self.owner_address.clone();
+        self.b_mock
+            .execute_tx(
+                &owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_min_egld_to_delegate(Self::to_managed_biguint(Self::exp18(amount)));
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn set_recompute_block_offset_check_error(&mut self, offset: u64, err_message: &str) {
+        let owner_address = self.owner_address.clone();
+        self.b_mock
+            .execute_tx(
+                &owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_recompute_block_offset(offset);
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn check_unbond_period(&mut self, unbond_period: u64) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(sc.get_parameters().unbond_period, unbond_period);
+            })
+            .assert_ok();
+    }
+
     pub fn add_liquidity(&mut self, caller: &Address, payment_amount: u64) {
         self.b_mock
             .execute_tx(
@@ -190,7 +276,7 @@ where
                 &self.sc_wrapper,
                 &Self::exp18(payment_amount),
                 |sc| {
-                    sc.add_liquidity();
+                    sc.add_liquidity(OptionalValue::None);
                 },
             )
             .assert_ok();
@@ -277,6 +363,1512 @@ where
             .assert_ok();
     }
 
+    pub fn record_undelegation(&mut self, provider: &Address, amount: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.record_undelegation(
+                        &managed_address!(provider),
+                        &Self::to_managed_biguint(Self::exp18(amount)),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn record_withdrawal(&mut self, provider: &Address, amount: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.record_withdrawal(
+                        &managed_address!(provider),
+                        &Self::to_managed_biguint(Self::exp18(amount)),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn set_legacy_undelegation(&mut self, provider: &Address, unstaked: u64, unbonded: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    let provider = managed_address!(provider);
+                    let mut delegation_addresses = sc.delegation_addresses_list();
+                    if !delegation_addresses
+                        .iter()
+                        .any(|node| node.into_value() == provider)
+                    {
+                        delegation_addresses.push_back(provider.clone());
+                    }
+                    sc.delegation_contract_data(&provider)
+                        .set(DelegationContractData {
+                            admin_address: provider.clone(),
+                            total_staked: BigUint::zero(),
+                            delegation_contract_cap: BigUint::zero(),
+                            nr_nodes: 1u64,
+                            apy: 0u64,
+                            total_staked_from_ls_contract: BigUint::zero(),
+                            total_unstaked_from_ls_contract: Self::to_managed_biguint(Self::exp18(
+                                unstaked,
+                            )),
+                            total_unbonded_from_ls_contract: Self::to_managed_biguint(Self::exp18(
+                                unbonded,
+                            )),
+                            egld_in_ongoing_undelegation: BigUint::zero(),
+                        });
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn upgrade(&mut self) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.upgrade();
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_untracked_undelegation(&mut self, provider: &Address, expected: u64) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(
+                    sc.untracked_undelegation(&managed_address!(provider)).get(),
+                    Self::to_managed_biguint(Self::exp18(expected))
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn check_undelegation_batch(
+        &mut self,
+        provider: &Address,
+        unstake_epoch: u64,
+        withdrawn_amount: u64,
+        withdraw_epoch: Option<u64>,
+    ) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                let batch = sc
+                    .undelegation_batch(&managed_address!(provider), unstake_epoch)
+                    .get();
+                assert_eq!(
+                    batch.withdrawn_amount,
+                    Self::to_managed_biguint(Self::exp18(withdrawn_amount))
+                );
+                assert_eq!(batch.withdraw_epoch, withdraw_epoch);
+            })
+            .assert_ok();
+    }
+
+    pub fn set_unstake_token_metadata(&mut self, uri_template: &[u8], royalties: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_unstake_token_metadata(
+                        managed_buffer!(uri_template),
+                        BigUint::from(royalties),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn mint_unstake_token(
+        &mut self,
+        unstake_amount: num_bigint::BigUint,
+        unstake_epoch: u64,
+        unbond_epoch: u64,
+    ) {
+        let delegation_contract = self.owner_address.clone();
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.mint_unstake_tokens(&UnstakeTokenAttributes {
+                        delegation_contract: managed_address!(&delegation_contract),
+                        unstake_epoch,
+                        unstake_amount: Self::to_managed_biguint(unstake_amount),
+                        unbond_epoch,
+                    });
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_unstake_token_metadata(
+        &mut self,
+        token_nonce: u64,
+        expected_name: &[u8],
+        expected_uri: &[u8],
+        expected_unbond_epoch: u64,
+    ) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                let token_data = sc.blockchain().get_esdt_token_data(
+                    &sc.blockchain().get_sc_address(),
+                    &managed_token_id!(UNSTAKE_TOKEN_ID),
+                    token_nonce,
+                );
+                assert_eq!(token_data.name, managed_buffer!(expected_name));
+                assert_eq!(
+                    token_data.uris.get(0).clone_value(),
+                    ManagedBuffer::<DebugApi>::from(expected_uri)
+                );
+                assert_eq!(
+                    sc.get_unstake_token_attributes(token_nonce).unbond_epoch,
+                    expected_unbond_epoch
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn force_state_active(&mut self) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.state().set(State::Active);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn set_user_unstake_token(
+        &mut self,
+        address: &Address,
+        token_nonce: u64,
+        unstake_amount: u64,
+        unbond_epoch: u64,
+    ) {
+        let attributes = UnstakeTokenAttributes::<DebugApi> {
+            delegation_contract: managed_address!(&self.owner_address),
+            unstake_epoch: 0u64,
+            unstake_amount: Self::to_managed_biguint(Self::exp18(unstake_amount)),
+            unbond_epoch,
+        };
+        self.b_mock.set_nft_balance(
+            address,
+            UNSTAKE_TOKEN_ID,
+            token_nonce,
+            &rust_biguint!(1u64),
+            &attributes,
+        );
+    }
+
+    pub fn set_market_fee(&mut self, fee_percentage: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_market_fee(fee_percentage);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn list_unstake_token(&mut self, caller: &Address, token_nonce: u64, price: u64) {
+        self.b_mock
+            .execute_esdt_transfer(
+                caller,
+                &self.sc_wrapper,
+                UNSTAKE_TOKEN_ID,
+                token_nonce,
+                &rust_biguint!(1u64),
+                |sc| {
+                    sc.list_unstake_token(Self::to_managed_biguint(Self::exp18(price)));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn list_unstake_token_check_error(
+        &mut self,
+        caller: &Address,
+        token_nonce: u64,
+        price: u64,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_esdt_transfer(
+                caller,
+                &self.sc_wrapper,
+                UNSTAKE_TOKEN_ID,
+                token_nonce,
+                &rust_biguint!(1u64),
+                |sc| {
+                    sc.list_unstake_token(Self::to_managed_biguint(Self::exp18(price)));
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn cancel_unstake_listing(&mut self, caller: &Address, token_nonce: u64) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.cancel_unstake_listing(token_nonce);
+            })
+            .assert_ok();
+    }
+
+    pub fn buy_unstake_token(&mut self, caller: &Address, token_nonce: u64, payment_amount: u64) {
+        self.b_mock
+            .execute_tx(
+                caller,
+                &self.sc_wrapper,
+                &Self::exp18(payment_amount),
+                |sc| {
+                    sc.buy_unstake_token(token_nonce);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_unstake_listing_wegld_payout(&mut self, token_nonce: u64, wegld_payout: bool) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(
+                    sc.unstake_listing(token_nonce).get().wegld_payout,
+                    wegld_payout
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn check_market_fees_reserve(&mut self, market_fees: u128) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(
+                    sc.market_fees_reserve().get(),
+                    Self::to_managed_biguint(num_bigint::BigUint::from(market_fees))
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn set_pool_reserves(&mut self, ls_token_supply: u64, virtual_egld_reserve: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.ls_token_supply()
+                        .set(Self::to_managed_biguint(Self::exp18(ls_token_supply)));
+                    sc.virtual_egld_reserve()
+                        .set(Self::to_managed_biguint(Self::exp18(virtual_egld_reserve)));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn wrap_ls_token(&mut self, caller: &Address, payment_amount: u64) {
+        self.b_mock
+            .execute_esdt_transfer(
+                caller,
+                &self.sc_wrapper,
+                LS_TOKEN_ID,
+                0,
+                &Self::exp18(payment_amount),
+                |sc| {
+                    sc.wrap_ls_token();
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn unwrap_ls_token(&mut self, caller: &Address, rebasing_amount: Option<u64>) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                let opt_rebasing_amount = match rebasing_amount {
+                    Some(amount) => {
+                        OptionalValue::Some(Self::to_managed_biguint(Self::exp18(amount)))
+                    }
+                    None => OptionalValue::None,
+                };
+                sc.unwrap_ls_token(opt_rebasing_amount);
+            })
+            .assert_ok();
+    }
+
+    pub fn check_rebasing_balance(&mut self, address: &Address, rebasing_balance: u64) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(
+                    sc.get_rebasing_balance(managed_address!(address)),
+                    Self::to_managed_biguint(Self::exp18(rebasing_balance))
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn lock_for_vote(&mut self, caller: &Address, payment_amount: u64) {
+        self.b_mock
+            .execute_esdt_transfer(
+                caller,
+                &self.sc_wrapper,
+                LS_TOKEN_ID,
+                0,
+                &Self::exp18(payment_amount),
+                |sc| {
+                    sc.lock_for_vote();
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn unlock_from_vote(&mut self, caller: &Address, amount: u64) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.unlock_from_vote(Self::to_managed_biguint(Self::exp18(amount)));
+            })
+            .assert_ok();
+    }
+
+    pub fn check_vote_power_at(&mut self, user: &Address, start_epoch: u64, vote_power: u64) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(
+                    sc.get_vote_power_at(managed_address!(user), start_epoch),
+                    Self::to_managed_biguint(Self::exp18(vote_power))
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn check_total_vote_power_at(&mut self, start_epoch: u64, vote_power: u64) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(
+                    sc.get_total_vote_power_at(start_epoch),
+                    Self::to_managed_biguint(Self::exp18(vote_power))
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn set_vote_contract_direct(&mut self, vote_contract: &Address) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.vote_contract().set(managed_address!(vote_contract));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn cast_aggregated_vote_check_error(
+        &mut self,
+        caller: &Address,
+        vote_type: &[u8],
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.cast_aggregated_vote(1u32, managed_buffer!(vote_type));
+            })
+            .assert_user_error(err_message);
+    }
+
+    pub fn set_protocol_vote_mode(&mut self, mode: ProtocolVoteMode) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_protocol_vote_mode(mode);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn register_protocol_proposal(&mut self, proposal: u32, start_epoch: u64, end_epoch: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.register_protocol_proposal(proposal, start_epoch, end_epoch);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn decide_protocol_vote_check_error(
+        &mut self,
+        proposal: u32,
+        vote_type: &[u8],
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.decide_protocol_vote(proposal, managed_buffer!(vote_type));
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn vote_protocol_proposal(&mut self, caller: &Address, proposal: u32, vote_type: &[u8]) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.vote_protocol_proposal(proposal, managed_buffer!(vote_type));
+            })
+            .assert_ok();
+    }
+
+    pub fn vote_protocol_proposal_check_error(
+        &mut self,
+        caller: &Address,
+        proposal: u32,
+        vote_type: &[u8],
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.vote_protocol_proposal(proposal, managed_buffer!(vote_type));
+            })
+            .assert_user_error(err_message);
+    }
+
+    pub fn cast_protocol_vote(&mut self, caller: &Address, proposal: u32) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.cast_protocol_vote(proposal);
+            })
+            .assert_ok();
+    }
+
+    pub fn cast_protocol_vote_check_error(
+        &mut self,
+        caller: &Address,
+        proposal: u32,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.cast_protocol_vote(proposal);
+            })
+            .assert_user_error(err_message);
+    }
+
+    pub fn protocol_vote_callback_error(&mut self, proposal: u32) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.protocol_vote_callback(
+                        proposal,
+                        ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                            err_code: 4,
+                            err_msg: managed_buffer!(b"vote failed"),
+                        }),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_protocol_proposal_tally(&mut self, proposal: u32, vote_type: &[u8], tally: u64) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(
+                    sc.protocol_proposal_tally(proposal, &managed_buffer!(vote_type))
+                        .get(),
+                    Self::to_managed_biguint(Self::exp18(tally))
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn check_protocol_vote(&mut self, proposal: u32, vote_type: Option<&[u8]>) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                let protocol_vote_mapper = sc.protocol_vote(proposal);
+                match vote_type {
+                    Some(vote_type) => {
+                        assert_eq!(
+                            protocol_vote_mapper.get().vote_type,
+                            managed_buffer!(vote_type)
+                        );
+                        assert!(sc.protocol_voted_proposals().contains(&proposal));
+                    }
+                    None => {
+                        assert!(protocol_vote_mapper.is_empty());
+                        assert!(!sc.protocol_voted_proposals().contains(&proposal));
+                    }
+                }
+            })
+            .assert_ok();
+    }
+
+    pub fn register_partner(&mut self, partner: &Address) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.register_partner(managed_address!(partner));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn record_referral(&mut self, user: &Address, referrer: &Address, ls_token_amount: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.record_referral(
+                        &managed_address!(user),
+                        Some(managed_address!(referrer)),
+                        &Self::to_managed_biguint(Self::exp18(ls_token_amount)),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn remove_referral_amount(&mut self, user: &Address, ls_token_amount: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.remove_referral_amount(
+                        &managed_address!(user),
+                        &Self::to_managed_biguint(Self::exp18(ls_token_amount)),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_partner_tvl(&mut self, partner: &Address, tvl: u64) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(
+                    sc.get_partner_tvl(managed_address!(partner)),
+                    Self::to_managed_biguint(Self::exp18(tvl))
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn distribute_partner_share(&mut self, market_fees: u64, share_percentage: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    let market_fees = Self::to_managed_biguint(Self::exp18(market_fees));
+                    sc.market_fees_reserve()
+                        .update(|value| *value += &market_fees);
+                    sc.total_market_fees().update(|value| *value += market_fees);
+                    sc.set_partner_share_percentage(share_percentage);
+                    sc.distribute_partner_share();
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn distribute_partner_share_check_error(&mut self, err_message: &str) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.distribute_partner_share();
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn claim_market_fees(&mut self) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.claim_market_fees();
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn claim_partner_rewards(&mut self, partner: &Address) {
+        self.b_mock
+            .execute_tx(partner, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.claim_partner_rewards();
+            })
+            .assert_ok();
+    }
+
+    pub fn set_unbonded_delegation_contract(&mut self, provider: &Address, unbonded_amount: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    let unbonded_amount = Self::to_managed_biguint(Self::exp18(unbonded_amount));
+                    sc.delegation_contract_data(&managed_address!(provider))
+                        .set(DelegationContractData {
+                            admin_address: managed_address!(provider),
+                            total_staked: BigUint::zero(),
+                            delegation_contract_cap: BigUint::zero(),
+                            nr_nodes: 0u64,
+                            apy: 0u64,
+                            total_staked_from_ls_contract: BigUint::zero(),
+                            total_unstaked_from_ls_contract: unbonded_amount.clone(),
+                            total_unbonded_from_ls_contract: unbonded_amount,
+                            egld_in_ongoing_undelegation: BigUint::zero(),
+                        });
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn set_integrator_whitelist(&mut self, enabled: bool, integrator: &Address) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_integrator_whitelist_enabled(enabled);
+                    let mut integrators = MultiValueEncoded::new();
+                    integrators.push(managed_address!(integrator));
+                    sc.add_integrators(integrators);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn unbond_tokens_for(
+        &mut self,
+        caller: &Address,
+        token_nonce: u64,
+        receiver: &Address,
+        unbonded_amount: u64,
+    ) {
+        self.b_mock
+            .execute_esdt_transfer(
+                caller,
+                &self.sc_wrapper,
+                UNSTAKE_TOKEN_ID,
+                token_nonce,
+                &rust_biguint!(1u64),
+                |sc| {
+                    let result = sc.unbond_tokens_for(managed_address!(receiver));
+                    assert_eq!(
+                        result,
+                        Self::to_managed_biguint(Self::exp18(unbonded_amount))
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn unbond_tokens_for_check_error(
+        &mut self,
+        caller: &Address,
+        token_nonce: u64,
+        receiver: &Address,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_esdt_transfer(
+                caller,
+                &self.sc_wrapper,
+                UNSTAKE_TOKEN_ID,
+                token_nonce,
+                &rust_biguint!(1u64),
+                |sc| {
+                    sc.unbond_tokens_for(managed_address!(receiver));
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn deploy_wegld_swap(
+        &mut self,
+        liquidity: u64,
+    ) -> ContractObjWrapper<
+        wegld_swap_mock::ContractObj<DebugApi>,
+        impl 'static + Copy + Fn() -> wegld_swap_mock::ContractObj<DebugApi>,
+    > {
+        let wegld_swap_wrapper = self.b_mock.create_sc_account(
+            &Self::exp18(liquidity),
+            Some(&self.owner_address),
+            wegld_swap_mock::contract_obj,
+            WEGLD_SWAP_MOCK_WASM_PATH,
+        );
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &wegld_swap_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.init(managed_token_id!(WEGLD_TOKEN_ID));
+                },
+            )
+            .assert_ok();
+        self.b_mock.set_esdt_balance(
+            wegld_swap_wrapper.address_ref(),
+            WEGLD_TOKEN_ID,
+            &Self::exp18(liquidity),
+        );
+
+        let wegld_swap_address = wegld_swap_wrapper.address_ref().clone();
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_wegld_swap(managed_address!(&wegld_swap_address));
+                },
+            )
+            .assert_ok();
+
+        wegld_swap_wrapper
+    }
+
+    pub fn add_liquidity_wegld_check_error(
+        &mut self,
+        caller: &Address,
+        payment_token: &[u8],
+        payment_amount: u64,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_esdt_transfer(
+                caller,
+                &self.sc_wrapper,
+                payment_token,
+                0,
+                &Self::exp18(payment_amount),
+                |sc| {
+                    sc.add_liquidity_wegld(OptionalValue::None);
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn add_liquidity_wegld_failed_callback(
+        &mut self,
+        caller: &Address,
+        delegation_contract: &Address,
+        staked_tokens: u64,
+    ) {
+        self.b_mock
+            .set_egld_balance(self.sc_wrapper.address_ref(), &Self::exp18(staked_tokens));
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.add_liquidity_callback(
+                        managed_address!(caller),
+                        managed_address!(caller),
+                        managed_address!(delegation_contract),
+                        Self::to_managed_biguint(Self::exp18(staked_tokens)),
+                        None,
+                        true,
+                        ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                            err_code: 4u32,
+                            err_msg: ManagedBuffer::new(),
+                        }),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn set_wegld_wrap_failure<WegldSwapObjBuilder>(
+        &mut self,
+        wegld_swap_wrapper: &ContractObjWrapper<
+            wegld_swap_mock::ContractObj<DebugApi>,
+            WegldSwapObjBuilder,
+        >,
+        fail: bool,
+    ) where
+        WegldSwapObjBuilder: 'static + Copy + Fn() -> wegld_swap_mock::ContractObj<DebugApi>,
+    {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                wegld_swap_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_wrap_failure(fail);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn claim_wegld_refund(&mut self, caller: &Address, as_egld: bool) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.claim_wegld_refund(OptionalValue::Some(as_egld));
+            })
+            .assert_ok();
+    }
+
+    pub fn claim_wegld_refund_check_error(
+        &mut self,
+        caller: &Address,
+        as_egld: bool,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.claim_wegld_refund(OptionalValue::Some(as_egld));
+            })
+            .assert_user_error(err_message);
+    }
+
+    pub fn check_wegld_refund(&mut self, address: &Address, amount: u64) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(
+                    sc.wegld_refund(&managed_address!(address)).get(),
+                    Self::to_managed_biguint(Self::exp18(amount))
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn set_wegld_payout(&mut self, token_nonce: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.wegld_payout(token_nonce).set(true);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn set_provider_claim_gas(&mut self, provider: &Address, gas_limit: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_provider_claim_gas(managed_address!(provider), gas_limit);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_provider_claim_gas(&mut self, provider: &Address, gas_limit: u64) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(
+                    sc.get_provider_claim_gas(managed_address!(provider)),
+                    gas_limit
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn fail_claim_rewards_callback(&mut self, provider: &Address) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.claim_rewards_callback(
+                        managed_address!(provider),
+                        ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                            err_code: 4,
+                            err_msg: ManagedBuffer::new(),
+                        }),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_failed_claims(&mut self, providers: &[&Address]) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                let failed_claims = sc.failed_claims();
+                assert_eq!(failed_claims.len(), providers.len());
+                for provider in providers {
+                    assert!(failed_claims.contains(&managed_address!(provider)));
+                }
+            })
+            .assert_ok();
+    }
+
+    pub fn deploy_delegation_mock(&mut self, owner_address: &Address) -> Address {
+        let rust_zero = rust_biguint!(0u64);
+        let delegation_wrapper = self.b_mock.create_sc_account(
+            &rust_zero,
+            Some(owner_address),
+            delegation_mock::contract_obj,
+            DELEGATION_MOCK_WASM_PATH,
+        );
+
+        self.b_mock
+            .execute_tx(owner_address, &delegation_wrapper, &rust_zero, |sc| {
+                sc.init();
+            })
+            .assert_ok();
+
+        delegation_wrapper.address_ref().clone()
+    }
+
+    pub fn apply_for_whitelist(
+        &mut self,
+        caller: &Address,
+        contract_address: &Address,
+        payment_amount: u64,
+    ) {
+        self.b_mock
+            .execute_tx(
+                caller,
+                &self.sc_wrapper,
+                &Self::exp18(payment_amount),
+                |sc| {
+                    sc.apply_for_whitelist(
+                        managed_address!(contract_address),
+                        Self::to_managed_biguint(Self::exp18(100)),
+                        Self::to_managed_biguint(Self::exp18(1000)),
+                        3u64,
+                        1000u64,
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn apply_for_whitelist_check_error(
+        &mut self,
+        caller: &Address,
+        contract_address: &Address,
+        payment_amount: u64,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(
+                caller,
+                &self.sc_wrapper,
+                &Self::exp18(payment_amount),
+                |sc| {
+                    sc.apply_for_whitelist(
+                        managed_address!(contract_address),
+                        Self::to_managed_biguint(Self::exp18(100)),
+                        Self::to_managed_biguint(Self::exp18(1000)),
+                        3u64,
+                        1000u64,
+                    );
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn whitelist_application_callback(
+        &mut self,
+        caller: &Address,
+        contract_address: &Address,
+        contract_owner: &Address,
+        success: bool,
+    ) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    let result = if success {
+                        let mut config = MultiValueEncoded::new();
+                        config.push(managed_address!(contract_owner).as_managed_buffer().clone());
+                        ManagedAsyncCallResult::Ok(config)
+                    } else {
+                        ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                            err_code: 4u32,
+                            err_msg: ManagedBuffer::new(),
+                        })
+                    };
+                    sc.whitelist_application_callback(
+                        managed_address!(contract_address),
+                        managed_address!(caller),
+                        result,
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn approve_whitelist_application_check_error(
+        &mut self,
+        contract_address: &Address,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.approve_whitelist_application(managed_address!(contract_address));
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn reject_whitelist_application(&mut self, contract_address: &Address) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.reject_whitelist_application(managed_address!(contract_address));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_pending_applications(&mut self, count: usize) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(sc.pending_applications().len(), count);
+            })
+            .assert_ok();
+    }
+
+    pub fn slash_provider_bond(&mut self, contract_address: &Address, bond: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.provider_bond(&managed_address!(contract_address))
+                        .set(Self::to_managed_biguint(Self::exp18(bond)));
+                    sc.slash_provider_bond(managed_address!(contract_address));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn release_provider_bond(&mut self, contract_address: &Address, bond: u64) {
+        self.b_mock
+            .set_egld_balance(self.sc_wrapper.address_ref(), &Self::exp18(bond));
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.provider_bond(&managed_address!(contract_address))
+                        .set(Self::to_managed_biguint(Self::exp18(bond)));
+                    sc.release_provider_bond(managed_address!(contract_address));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn set_whitelist_bond_check_error(&mut self, amount: u64, err_message: &str) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_whitelist_bond(Self::to_managed_biguint(Self::exp18(amount)));
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn whitelist_delegation_contract_direct(
+        &mut self,
+        contract_address: &Address,
+        total_staked: u64,
+        delegation_contract_cap: u64,
+        apy: u64,
+    ) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    let contract_data = DelegationContractData {
+                        admin_address: managed_address!(contract_address),
+                        total_staked: Self::to_managed_biguint(Self::exp18(total_staked)),
+                        delegation_contract_cap: Self::to_managed_biguint(Self::exp18(
+                            delegation_contract_cap,
+                        )),
+                        nr_nodes: 1u64,
+                        apy,
+                        total_staked_from_ls_contract: Self::to_managed_biguint(Self::exp18(10)),
+                        total_unstaked_from_ls_contract: BigUint::zero(),
+                        total_unbonded_from_ls_contract: BigUint::zero(),
+                        egld_in_ongoing_undelegation: BigUint::zero(),
+                    };
+                    sc.whitelist_contract_callback(
+                        managed_address!(contract_address),
+                        managed_address!(contract_address),
+                        contract_data,
+                        BigUint::zero(),
+                        BigUint::zero(),
+                        0u64,
+                        ManagedAsyncCallResult::Ok(()),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn move_delegation_contract_to_back(&mut self, contract_address: &Address) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.move_delegation_contract_to_back(managed_address!(contract_address));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_delegation_contract_selection(
+        &mut self,
+        amount: u64,
+        expected_for_delegate: &Address,
+        expected_for_undelegate: &Address,
+    ) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                let amount = Self::to_managed_biguint(Self::exp18(amount));
+                assert_eq!(
+                    sc.get_delegation_contract_for_delegate(&amount),
+                    managed_address!(expected_for_delegate)
+                );
+                assert_eq!(
+                    sc.get_delegation_contract_for_undelegate(&amount),
+                    managed_address!(expected_for_undelegate)
+                );
+            })
+            .assert_ok();
+    }
+
+    pub fn check_delegation_addresses_page(
+        &mut self,
+        from_node_id: u32,
+        count: usize,
+        expected_addresses: &[&Address],
+    ) -> u32 {
+        let mut next_node_id = 0;
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                let (node_id, delegation_addresses) = sc
+                    .get_delegation_addresses_page(from_node_id, count)
+                    .into_tuple();
+                assert_eq!(delegation_addresses.len(), expected_addresses.len());
+                for (index, expected_address) in expected_addresses.iter().enumerate() {
+                    assert_eq!(
+                        *delegation_addresses.get(index),
+                        managed_address!(*expected_address)
+                    );
+                }
+                next_node_id = node_id;
+            })
+            .assert_ok();
+
+        next_node_id
+    }
+
+    pub fn prepare_claim_operation(&mut self, remaining_count: usize) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.prepare_claim_operation();
+                    assert_eq!(
+                        sc.claim_cursor().get(),
+                        sc.delegation_addresses_list()
+                            .front()
+                            .unwrap()
+                            .get_node_id()
+                    );
+                    assert_eq!(sc.remaining_claim_count().get(), remaining_count);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn set_pending_whitelist(
+        &mut self,
+        caller: &Address,
+        contract_address: &Address,
+        bond: u64,
+    ) {
+        self.b_mock
+            .set_egld_balance(self.sc_wrapper.address_ref(), &Self::exp18(bond));
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.require_whitelisting_allowed(&managed_address!(contract_address));
+                    sc.pending_whitelist(&managed_address!(contract_address))
+                        .set(PendingWhitelist {
+                            caller: managed_address!(caller),
+                            whitelist_fee: BigUint::zero(),
+                            bond: Self::to_managed_biguint(Self::exp18(bond)),
+                            start_block: sc.blockchain().get_block_nonce(),
+                        });
+                    sc.pending_whitelist_addresses()
+                        .insert(managed_address!(contract_address));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn set_max_delegation_addresses(&mut self, max_delegation_addresses: usize) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.set_max_delegation_addresses(max_delegation_addresses);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn set_pending_whitelist_check_error(
+        &mut self,
+        contract_address: &Address,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.require_whitelisting_allowed(&managed_address!(contract_address));
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn clear_pending_whitelist(&mut self, caller: &Address, contract_address: &Address) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.clear_pending_whitelist(managed_address!(contract_address));
+            })
+            .assert_ok();
+    }
+
+    pub fn clear_pending_whitelist_check_error(
+        &mut self,
+        caller: &Address,
+        contract_address: &Address,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(caller, &self.sc_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.clear_pending_whitelist(managed_address!(contract_address));
+            })
+            .assert_user_error(err_message);
+    }
+
+    pub fn whitelist_contract_callback(
+        &mut self,
+        caller: &Address,
+        contract_address: &Address,
+        bond: u64,
+        start_block: u64,
+        success: bool,
+    ) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    let contract_data = DelegationContractData {
+                        admin_address: managed_address!(contract_address),
+                        total_staked: BigUint::zero(),
+                        delegation_contract_cap: BigUint::zero(),
+                        nr_nodes: 1u64,
+                        apy: 0u64,
+                        total_staked_from_ls_contract: BigUint::zero(),
+                        total_unstaked_from_ls_contract: BigUint::zero(),
+                        total_unbonded_from_ls_contract: BigUint::zero(),
+                        egld_in_ongoing_undelegation: BigUint::zero(),
+                    };
+                    let result = if success {
+                        ManagedAsyncCallResult::Ok(())
+                    } else {
+                        ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                            err_code: 4u32,
+                            err_msg: ManagedBuffer::new(),
+                        })
+                    };
+                    sc.whitelist_contract_callback(
+                        managed_address!(caller),
+                        managed_address!(contract_address),
+                        contract_data,
+                        BigUint::zero(),
+                        Self::to_managed_biguint(Self::exp18(bond)),
+                        start_block,
+                        result,
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_delegation_addresses_count(&mut self, count: usize) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(sc.delegation_addresses_list().len(), count);
+            })
+            .assert_ok();
+    }
+
+    pub fn check_pending_whitelists(&mut self, count: usize) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                assert_eq!(sc.pending_whitelist_addresses().len(), count);
+            })
+            .assert_ok();
+    }
+
+    pub fn apply_verification_results(
+        &mut self,
+        provider: &Address,
+        delegation_contract_cap: u64,
+        total_active_stake: u64,
+        nr_staked_nodes: usize,
+    ) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.sc_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    let mut config = MultiValueEncoded::new();
+                    config.push(managed_buffer!(provider.as_bytes()));
+                    config.push(ManagedBuffer::new());
+                    config.push(
+                        Self::to_managed_biguint(Self::exp18(delegation_contract_cap))
+                            .to_bytes_be_buffer(),
+                    );
+                    config.push(ManagedBuffer::new());
+                    config.push(managed_buffer!(b"true"));
+                    config.push(managed_buffer!(b"true"));
+                    sc.contract_config_callback(
+                        managed_address!(provider),
+                        ManagedAsyncCallResult::Ok(config),
+                    );
+
+                    sc.total_active_stake_callback(
+                        managed_address!(provider),
+                        ManagedAsyncCallResult::Ok(Self::to_managed_biguint(Self::exp18(
+                            total_active_stake,
+                        ))),
+                    );
+
+                    let mut node_states = MultiValueEncoded::new();
+                    node_states.push(managed_buffer!(b"staked"));
+                    for _ in 0..nr_staked_nodes {
+                        node_states.push(ManagedBuffer::from(&[1u8; 96][..]));
+                    }
+                    node_states.push(managed_buffer!(b"notStaked"));
+                    node_states.push(ManagedBuffer::from(&[2u8; 96][..]));
+                    sc.node_states_callback(
+                        managed_address!(provider),
+                        ManagedAsyncCallResult::Ok(node_states),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_delegation_contract_mismatches(
+        &mut self,
+        provider: &Address,
+        total_staked_mismatch: bool,
+        delegation_contract_cap_mismatch: bool,
+        nr_nodes_mismatch: bool,
+    ) {
+        self.b_mock
+            .execute_query(&self.sc_wrapper, |sc| {
+                let verification =
+                    sc.get_delegation_contract_verification(managed_address!(provider));
+                assert_eq!(verification.total_staked_mismatch, total_staked_mismatch);
+                assert_eq!(
+                    verification.delegation_contract_cap_mismatch,
+                    delegation_contract_cap_mismatch
+                );
+                assert_eq!(verification.nr_nodes_mismatch, nr_nodes_mismatch);
+            })
+            .assert_ok();
+    }
+
     pub fn setup_new_user(&mut self, egld_token_amount: u64) -> Address {
         let rust_zero = rust_biguint!(0);
 
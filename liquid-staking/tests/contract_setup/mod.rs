@@ -9,9 +9,12 @@ use multiversx_sc_scenario::{
 };
 
 pub const LIQUID_STAKING_WASM_PATH: &str = "liquid-staking/output/liquid-staking.wasm";
+pub const WEGLD_SWAP_MOCK_WASM_PATH: &str = "wegld-swap-mock.wasm";
+pub const DELEGATION_MOCK_WASM_PATH: &str = "delegation-mock.wasm";
 
 pub static LS_TOKEN_ID: &[u8] = b"LSTOKEN-123456";
 pub static UNSTAKE_TOKEN_ID: &[u8] = b"UNSTAKE-123456";
+pub static WEGLD_TOKEN_ID: &[u8] = b"WEGLD-123456";
 
 pub static ESDT_ROLES: &[EsdtLocalRole] = &[
     EsdtLocalRole::Mint,
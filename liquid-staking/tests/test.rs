@@ -1,10 +1,12 @@
 mod contract_interactions;
 mod contract_setup;
+mod wegld_swap_mock;
 use std::ops::Mul;
 
 use contract_interactions::FIRST_ADD_LIQUIDITY_AMOUNT;
 use contract_setup::*;
 
+use liquid_staking::setup::protocol_vote::ProtocolVoteMode;
 use multiversx_sc_scenario::{num_bigint, rust_biguint, DebugApi};
 
 #[test]
@@ -12,6 +14,653 @@ fn init_test() {
     LiquidStakingContractSetup::new(liquid_staking::contract_obj);
 }
 
+#[test]
+fn liquid_staking_parameters_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let owner_address = sc_setup.owner_address.clone();
+
+    sc_setup.check_unbond_period(10u64);
+    sc_setup.set_unbond_period(&owner_address, 12u64);
+    sc_setup.check_unbond_period(12u64);
+
+    sc_setup.set_unbond_period_check_error(
+        &owner_address,
+        0u64,
+        "Parameter value is out of bounds",
+    );
+    sc_setup.set_unbond_period_check_error(
+        &owner_address,
+        31u64,
+        "Parameter value is out of bounds",
+    );
+    sc_setup.check_unbond_period(12u64);
+
+    sc_setup.set_min_egld_to_delegate_check_error(0u64, "Parameter value is out of bounds");
+    sc_setup.set_min_egld_to_delegate_check_error(101u64, "Parameter value is out of bounds");
+    sc_setup.set_recompute_block_offset_check_error(0u64, "Parameter value is out of bounds");
+}
+
+#[test]
+fn liquid_staking_undelegation_batches_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let provider = sc_setup.setup_new_user(0u64);
+
+    sc_setup.b_mock.set_block_epoch(5u64);
+    sc_setup.record_undelegation(&provider, 10u64);
+    sc_setup.record_undelegation(&provider, 5u64);
+    sc_setup.b_mock.set_block_epoch(6u64);
+    sc_setup.record_undelegation(&provider, 20u64);
+
+    sc_setup.b_mock.set_block_epoch(15u64);
+    sc_setup.record_withdrawal(&provider, 10u64);
+    sc_setup.check_undelegation_batch(&provider, 5u64, 10u64, None);
+
+    sc_setup.b_mock.set_block_epoch(17u64);
+    sc_setup.record_withdrawal(&provider, 15u64);
+    sc_setup.check_undelegation_batch(&provider, 5u64, 15u64, Some(17u64));
+    sc_setup.check_undelegation_batch(&provider, 6u64, 10u64, None);
+
+    sc_setup.b_mock.set_block_epoch(18u64);
+    sc_setup.record_withdrawal(&provider, 10u64);
+    sc_setup.check_undelegation_batch(&provider, 6u64, 20u64, Some(18u64));
+}
+
+#[test]
+fn liquid_staking_untracked_undelegation_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let provider = sc_setup.setup_new_user(0u64);
+
+    // 30 EGLD were undelegated before the batches were tracked, 5 of them already withdrawn
+    sc_setup.set_legacy_undelegation(&provider, 35u64, 5u64);
+    sc_setup.upgrade();
+    sc_setup.check_untracked_undelegation(&provider, 30u64);
+
+    // a later upgrade only counts what the batches do not cover
+    sc_setup.b_mock.set_block_epoch(5u64);
+    sc_setup.record_undelegation(&provider, 10u64);
+    sc_setup.set_legacy_undelegation(&provider, 45u64, 5u64);
+    sc_setup.upgrade();
+    sc_setup.check_untracked_undelegation(&provider, 30u64);
+
+    // the untracked undelegations are older, so they are withdrawn first
+    sc_setup.b_mock.set_block_epoch(15u64);
+    sc_setup.record_withdrawal(&provider, 25u64);
+    sc_setup.check_untracked_undelegation(&provider, 5u64);
+    sc_setup.check_undelegation_batch(&provider, 5u64, 0u64, None);
+
+    sc_setup.record_withdrawal(&provider, 10u64);
+    sc_setup.check_untracked_undelegation(&provider, 0u64);
+    sc_setup.check_undelegation_batch(&provider, 5u64, 5u64, None);
+
+    sc_setup.b_mock.set_block_epoch(16u64);
+    sc_setup.record_withdrawal(&provider, 5u64);
+    sc_setup.check_undelegation_batch(&provider, 5u64, 10u64, Some(16u64));
+}
+
+#[test]
+fn liquid_staking_unstake_token_metadata_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+
+    sc_setup.set_unstake_token_metadata(b"https://api.example.com/unstake/{nonce}.json", 0u64);
+    sc_setup.mint_unstake_token(exp17(125), 1224u64, 1234u64);
+    sc_setup.mint_unstake_token(exp18(3), 1225u64, 1235u64);
+
+    sc_setup.check_unstake_token_metadata(
+        1u64,
+        b"Unstake 12.5 EGLD - unlocks epoch 1234",
+        b"https://api.example.com/unstake/1.json",
+        1234u64,
+    );
+    sc_setup.check_unstake_token_metadata(
+        2u64,
+        b"Unstake 3 EGLD - unlocks epoch 1235",
+        b"https://api.example.com/unstake/2.json",
+        1235u64,
+    );
+}
+
+#[test]
+fn liquid_staking_unstake_market_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    sc_setup.force_state_active();
+    sc_setup.set_market_fee(1_000u64); // 1%
+
+    let seller = sc_setup.setup_new_user(0u64);
+    let buyer = sc_setup.setup_new_user(20u64);
+    sc_setup.set_user_unstake_token(&seller, 1u64, 10u64, 10u64);
+    sc_setup.set_user_unstake_token(&seller, 2u64, 10u64, 10u64);
+
+    sc_setup.list_unstake_token_check_error(
+        &seller,
+        1u64,
+        10u64,
+        "Listing price must be below the unstake amount",
+    );
+    sc_setup.set_wegld_payout(2u64);
+    sc_setup.list_unstake_token(&seller, 1u64, 9u64);
+    sc_setup.list_unstake_token(&seller, 2u64, 8u64);
+    sc_setup.check_unstake_listing_wegld_payout(1u64, false);
+    sc_setup.check_unstake_listing_wegld_payout(2u64, true);
+    sc_setup.check_user_nft_balance_denominated(&seller, UNSTAKE_TOKEN_ID, 1, 0);
+
+    sc_setup.cancel_unstake_listing(&seller, 2u64);
+    sc_setup.check_user_nft_balance_denominated(&seller, UNSTAKE_TOKEN_ID, 2, 1);
+
+    sc_setup.buy_unstake_token(&buyer, 1u64, 9u64);
+    sc_setup.check_user_nft_balance_denominated(&buyer, UNSTAKE_TOKEN_ID, 1, 1);
+    sc_setup.check_user_egld_balance(&buyer, 11u64);
+    sc_setup.check_user_egld_balance_denominated(&seller, 8_910_000_000_000_000_000u128);
+    sc_setup.check_market_fees_reserve(90_000_000_000_000_000u128);
+
+    sc_setup.b_mock.set_block_epoch(10u64);
+    sc_setup.list_unstake_token_check_error(
+        &seller,
+        2u64,
+        8u64,
+        "Unstake position is mature and can be unbonded directly",
+    );
+}
+
+#[test]
+fn liquid_staking_rebasing_wrapper_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    sc_setup.force_state_active();
+    sc_setup.set_pool_reserves(100u64, 100u64);
+
+    let first_user = sc_setup.setup_new_user(0u64);
+    sc_setup
+        .b_mock
+        .set_esdt_balance(&first_user, LS_TOKEN_ID, &exp18(50u64));
+
+    sc_setup.wrap_ls_token(&first_user, 40u64);
+    sc_setup.check_rebasing_balance(&first_user, 40u64);
+    sc_setup.check_user_balance(&first_user, LS_TOKEN_ID, 10u64);
+
+    // rewards are redelegated, the rebasing balance grows while the shares stay the same
+    sc_setup.set_pool_reserves(100u64, 150u64);
+    sc_setup.check_rebasing_balance(&first_user, 60u64);
+
+    sc_setup.unwrap_ls_token(&first_user, Some(30u64));
+    sc_setup.check_rebasing_balance(&first_user, 30u64);
+    sc_setup.check_user_balance(&first_user, LS_TOKEN_ID, 30u64);
+
+    sc_setup.unwrap_ls_token(&first_user, None);
+    sc_setup.check_rebasing_balance(&first_user, 0u64);
+    sc_setup.check_user_balance(&first_user, LS_TOKEN_ID, 50u64);
+}
+
+#[test]
+fn liquid_staking_partner_referral_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let first_partner = sc_setup.setup_new_user(0u64);
+    let second_partner = sc_setup.setup_new_user(0u64);
+    let first_user = sc_setup.setup_new_user(0u64);
+    let second_user = sc_setup.setup_new_user(0u64);
+    let unregistered_referrer = sc_setup.setup_new_user(0u64);
+
+    sc_setup.register_partner(&first_partner);
+    sc_setup.register_partner(&second_partner);
+
+    sc_setup.record_referral(&first_user, &first_partner, 30u64);
+    // a user stays attributed to the first partner that referred them
+    sc_setup.record_referral(&first_user, &second_partner, 30u64);
+    sc_setup.record_referral(&second_user, &second_partner, 20u64);
+    sc_setup.record_referral(&second_user, &unregistered_referrer, 10u64);
+    sc_setup.check_partner_tvl(&first_partner, 60u64);
+    sc_setup.check_partner_tvl(&second_partner, 30u64);
+    sc_setup.check_partner_tvl(&unregistered_referrer, 0u64);
+
+    sc_setup.remove_referral_amount(&first_user, 20u64);
+    sc_setup.check_partner_tvl(&first_partner, 40u64);
+
+    // half of the 10 EGLD market fees is split 4:3 between the partners
+    sc_setup
+        .b_mock
+        .set_egld_balance(sc_setup.sc_wrapper.address_ref(), &exp18(10u64));
+    sc_setup.distribute_partner_share(10u64, 50_000u64);
+    sc_setup.claim_partner_rewards(&second_partner);
+    sc_setup.check_user_egld_balance_denominated(&second_partner, 2_142_857_142_857_142_857);
+    sc_setup.claim_partner_rewards(&first_partner);
+    sc_setup.check_user_egld_balance_denominated(&first_partner, 2_857_142_857_142_857_142);
+
+    // the share of the same fees is not distributed twice, the rest is left to the owner
+    sc_setup.check_market_fees_reserve(5_000_000_000_000_000_001u128);
+    sc_setup.distribute_partner_share_check_error("Nothing to claim");
+    sc_setup.claim_market_fees();
+    sc_setup.check_market_fees_reserve(0u128);
+
+    // only the share of the fees collected since the last distribution is paid out
+    sc_setup
+        .b_mock
+        .set_egld_balance(sc_setup.sc_wrapper.address_ref(), &exp18(10u64));
+    sc_setup.distribute_partner_share(10u64, 50_000u64);
+    sc_setup.claim_partner_rewards(&second_partner);
+    sc_setup.check_user_egld_balance_denominated(&second_partner, 4_285_714_285_714_285_714);
+    sc_setup.check_market_fees_reserve(5_000_000_000_000_000_001u128);
+}
+
+#[test]
+fn liquid_staking_integrator_unbond_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    sc_setup.force_state_active();
+    let provider = sc_setup.owner_address.clone();
+    let receiver = sc_setup.setup_new_user(0u64);
+    let integrator = sc_setup
+        .b_mock
+        .create_sc_account(
+            &rust_biguint!(0u64),
+            None,
+            liquid_staking::contract_obj,
+            LIQUID_STAKING_WASM_PATH,
+        )
+        .address_ref()
+        .clone();
+
+    sc_setup
+        .b_mock
+        .set_egld_balance(sc_setup.sc_wrapper.address_ref(), &exp18(20u64));
+    sc_setup.set_unbonded_delegation_contract(&provider, 20u64);
+    sc_setup.set_user_unstake_token(&integrator, 1u64, 10u64, 0u64);
+    sc_setup.set_user_unstake_token(&integrator, 2u64, 10u64, 0u64);
+
+    // contracts must be whitelisted by default
+    sc_setup.unbond_tokens_for_check_error(
+        &integrator,
+        1u64,
+        &receiver,
+        "Caller contract is not whitelisted",
+    );
+
+    // the owner can open the access to all contracts, the payout goes to the receiver
+    let other_integrator = sc_setup.setup_new_user(0u64);
+    sc_setup.set_integrator_whitelist(false, &other_integrator);
+    sc_setup.unbond_tokens_for(&integrator, 1u64, &receiver, 10u64);
+    sc_setup.check_user_egld_balance(&receiver, 10u64);
+
+    sc_setup.set_integrator_whitelist(true, &other_integrator);
+    sc_setup.unbond_tokens_for_check_error(
+        &integrator,
+        2u64,
+        &receiver,
+        "Caller contract is not whitelisted",
+    );
+
+    sc_setup.set_integrator_whitelist(true, &integrator);
+    sc_setup.unbond_tokens_for(&integrator, 2u64, &receiver, 10u64);
+    sc_setup.check_user_egld_balance(&receiver, 20u64);
+}
+
+#[test]
+fn liquid_staking_wegld_test() {
+    DebugApi::dummy();
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    sc_setup.force_state_active();
+    let provider = sc_setup.owner_address.clone();
+    let first_user = sc_setup.setup_new_user(0u64);
+    sc_setup
+        .b_mock
+        .set_esdt_balance(&first_user, LS_TOKEN_ID, &exp18(10u64));
+
+    sc_setup.add_liquidity_wegld_check_error(
+        &first_user,
+        LS_TOKEN_ID,
+        10u64,
+        "WEGLD swap contract is not set",
+    );
+    sc_setup.deploy_wegld_swap(100u64);
+    sc_setup.add_liquidity_wegld_check_error(&first_user, LS_TOKEN_ID, 10u64, "Bad payment token");
+
+    sc_setup
+        .b_mock
+        .set_egld_balance(sc_setup.sc_wrapper.address_ref(), &exp18(20u64));
+    sc_setup.set_unbonded_delegation_contract(&provider, 20u64);
+    sc_setup.set_user_unstake_token(&first_user, 1u64, 10u64, 0u64);
+    sc_setup.set_user_unstake_token(&first_user, 2u64, 10u64, 0u64);
+    sc_setup.set_wegld_payout(1u64);
+
+    // marked positions are paid out in WEGLD, the others in EGLD
+    sc_setup.unbond_tokens(&first_user, UNSTAKE_TOKEN_ID, 1u64);
+    sc_setup.check_user_balance(&first_user, WEGLD_TOKEN_ID, 10u64);
+    sc_setup.check_user_egld_balance(&first_user, 0u64);
+    sc_setup.unbond_tokens(&first_user, UNSTAKE_TOKEN_ID, 2u64);
+    sc_setup.check_user_balance(&first_user, WEGLD_TOKEN_ID, 10u64);
+    sc_setup.check_user_egld_balance(&first_user, 10u64);
+}
+
+#[test]
+fn liquid_staking_wegld_refund_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let provider = sc_setup.setup_new_user(0u64);
+    let first_user = sc_setup.setup_new_user(0u64);
+    sc_setup.whitelist_delegation_contract_direct(&provider, 0u64, 1000u64, 1000u64);
+    let wegld_swap_wrapper = sc_setup.deploy_wegld_swap(100u64);
+
+    // the refund of a failed WEGLD delegation is claimed as WEGLD
+    sc_setup.add_liquidity_wegld_failed_callback(&first_user, &provider, 10u64);
+    sc_setup.check_wegld_refund(&first_user, 10u64);
+    sc_setup.claim_wegld_refund(&first_user, false);
+    sc_setup.check_user_balance(&first_user, WEGLD_TOKEN_ID, 10u64);
+    sc_setup.check_wegld_refund(&first_user, 0u64);
+    sc_setup.claim_wegld_refund_check_error(&first_user, false, "Nothing to claim");
+
+    // if wrapping fails, the refund is kept and can be claimed as EGLD
+    sc_setup.add_liquidity_wegld_failed_callback(&first_user, &provider, 5u64);
+    sc_setup.set_wegld_wrap_failure(&wegld_swap_wrapper, true);
+    sc_setup.claim_wegld_refund_check_error(&first_user, false, "Wrapping is paused");
+    sc_setup.check_wegld_refund(&first_user, 5u64);
+    sc_setup.claim_wegld_refund(&first_user, true);
+    sc_setup.check_user_egld_balance(&first_user, 5u64);
+    sc_setup.check_wegld_refund(&first_user, 0u64);
+}
+
+#[test]
+fn liquid_staking_claim_gas_and_failed_claims_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let first_provider = sc_setup.setup_new_user(0u64);
+    let second_provider = sc_setup.setup_new_user(0u64);
+
+    sc_setup.check_provider_claim_gas(&first_provider, 6_000_000u64);
+    sc_setup.set_provider_claim_gas(&first_provider, 15_000_000u64);
+    sc_setup.check_provider_claim_gas(&first_provider, 15_000_000u64);
+    sc_setup.check_provider_claim_gas(&second_provider, 6_000_000u64);
+    sc_setup.set_provider_claim_gas(&first_provider, 0u64);
+    sc_setup.check_provider_claim_gas(&first_provider, 6_000_000u64);
+
+    sc_setup.fail_claim_rewards_callback(&first_provider);
+    sc_setup.fail_claim_rewards_callback(&second_provider);
+    sc_setup.fail_claim_rewards_callback(&first_provider);
+    sc_setup.check_failed_claims(&[&first_provider, &second_provider]);
+}
+
+#[test]
+fn liquid_staking_whitelist_application_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let provider = sc_setup.setup_new_user(20u64);
+    let other_user = sc_setup.setup_new_user(20u64);
+    let delegation_contract = sc_setup.deploy_delegation_mock(&provider);
+
+    sc_setup.set_whitelist_bond_check_error(0u64, "Parameter value is out of bounds");
+    sc_setup.set_whitelist_bond_check_error(101u64, "Parameter value is out of bounds");
+
+    // the default whitelisting fee is 1 EGLD and the default bond is 5 EGLD
+    sc_setup.apply_for_whitelist_check_error(
+        &provider,
+        &delegation_contract,
+        1u64,
+        "Invalid whitelisting fee",
+    );
+
+    // only the owner of the delegation contract can apply, other applicants are refunded
+    sc_setup.apply_for_whitelist(&other_user, &delegation_contract, 6u64);
+    sc_setup.check_user_egld_balance(&other_user, 20u64);
+    sc_setup.check_pending_applications(0);
+    sc_setup.approve_whitelist_application_check_error(
+        &delegation_contract,
+        "Whitelist application is not verified yet",
+    );
+
+    sc_setup.apply_for_whitelist(&provider, &delegation_contract, 6u64);
+    sc_setup.check_user_egld_balance(&provider, 14u64);
+    sc_setup.check_pending_applications(1);
+    sc_setup.apply_for_whitelist_check_error(
+        &provider,
+        &delegation_contract,
+        6u64,
+        "Whitelist application already exists",
+    );
+
+    sc_setup.reject_whitelist_application(&delegation_contract);
+    sc_setup.check_user_egld_balance(&provider, 20u64);
+    sc_setup.check_pending_applications(0);
+
+    // a late ownership check does not touch a rejected application
+    sc_setup.whitelist_application_callback(&provider, &delegation_contract, &provider, true);
+    sc_setup.check_pending_applications(0);
+
+    sc_setup.slash_provider_bond(&delegation_contract, 10u64);
+    sc_setup.check_contract_rewards_storage_denominated(10_000_000_000_000_000_000u128);
+
+    // the bond of a reliable provider is returned to the admin of its delegation contract
+    sc_setup.whitelist_delegation_contract_direct(&delegation_contract, 0u64, 1000u64, 1000u64);
+    sc_setup.release_provider_bond(&delegation_contract, 5u64);
+    sc_setup.check_user_egld_balance(&delegation_contract, 5u64);
+}
+
+#[test]
+fn liquid_staking_parallel_whitelisting_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let provider = sc_setup.setup_new_user(0u64);
+    let first_delegation_contract = sc_setup.setup_new_user(0u64);
+    let second_delegation_contract = sc_setup.setup_new_user(0u64);
+
+    sc_setup.set_pending_whitelist(&provider, &second_delegation_contract, 0u64);
+    sc_setup.set_pending_whitelist(&provider, &first_delegation_contract, 5u64);
+    sc_setup.check_pending_whitelists(2);
+    sc_setup.set_pending_whitelist_check_error(
+        &first_delegation_contract,
+        "Whitelisting is already ongoing for this delegation contract",
+    );
+
+    sc_setup.clear_pending_whitelist_check_error(
+        &provider,
+        &first_delegation_contract,
+        "Whitelist operation cannot be cleared now",
+    );
+    sc_setup.b_mock.set_block_nonce(11u64);
+    sc_setup.clear_pending_whitelist(&provider, &first_delegation_contract);
+    sc_setup.check_user_egld_balance(&provider, 5u64);
+    sc_setup.check_pending_whitelists(1);
+
+    // the late callback of the cleared whitelisting leaves the newer whitelisting untouched
+    sc_setup.set_pending_whitelist(&provider, &first_delegation_contract, 5u64);
+    sc_setup.whitelist_contract_callback(&provider, &first_delegation_contract, 5u64, 0u64, false);
+    sc_setup.check_user_egld_balance(&provider, 5u64);
+    sc_setup.whitelist_contract_callback(&provider, &first_delegation_contract, 5u64, 0u64, true);
+    sc_setup.check_delegation_addresses_count(0);
+    sc_setup.check_pending_whitelists(2);
+
+    sc_setup.whitelist_contract_callback(&provider, &first_delegation_contract, 5u64, 11u64, true);
+    sc_setup.check_delegation_addresses_count(1);
+    sc_setup.check_pending_whitelists(1);
+    sc_setup.whitelist_contract_callback(&provider, &first_delegation_contract, 5u64, 11u64, true);
+    sc_setup.check_delegation_addresses_count(1);
+}
+
+#[test]
+fn liquid_staking_max_delegation_addresses_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let provider = sc_setup.setup_new_user(0u64);
+    let first_delegation_contract = sc_setup.setup_new_user(0u64);
+    let second_delegation_contract = sc_setup.setup_new_user(0u64);
+    let third_delegation_contract = sc_setup.setup_new_user(0u64);
+
+    // the pending whitelistings count towards the limit, which is reached at exactly max
+    sc_setup.set_max_delegation_addresses(2);
+    sc_setup.whitelist_delegation_contract_direct(
+        &first_delegation_contract,
+        0u64,
+        1000u64,
+        1000u64,
+    );
+    sc_setup.set_pending_whitelist(&provider, &second_delegation_contract, 0u64);
+    sc_setup.set_pending_whitelist_check_error(
+        &third_delegation_contract,
+        "Maximum number of delegation addresses reached",
+    );
+}
+
+#[test]
+fn liquid_staking_delegation_priority_index_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let low_apy_provider = sc_setup.setup_new_user(0u64);
+    let high_apy_provider = sc_setup.setup_new_user(0u64);
+    let full_provider = sc_setup.setup_new_user(0u64);
+
+    sc_setup.whitelist_delegation_contract_direct(&low_apy_provider, 0u64, 1000u64, 500u64);
+    sc_setup.whitelist_delegation_contract_direct(&high_apy_provider, 0u64, 1000u64, 900u64);
+    sc_setup.whitelist_delegation_contract_direct(&full_provider, 1000u64, 1000u64, 1000u64);
+
+    // the full delegation contract is only used for undelegation
+    sc_setup.check_delegation_contract_selection(5u64, &high_apy_provider, &full_provider);
+
+    sc_setup.move_delegation_contract_to_back(&high_apy_provider);
+    sc_setup.check_delegation_contract_selection(5u64, &low_apy_provider, &high_apy_provider);
+
+    let next_node_id =
+        sc_setup.check_delegation_addresses_page(0, 2, &[&low_apy_provider, &high_apy_provider]);
+    let next_node_id = sc_setup.check_delegation_addresses_page(next_node_id, 2, &[&full_provider]);
+    assert_eq!(next_node_id, 0);
+
+    sc_setup.prepare_claim_operation(3);
+}
+
+#[test]
+fn liquid_staking_vote_lock_snapshot_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let first_user = sc_setup.setup_new_user(0u64);
+    let second_user = sc_setup.setup_new_user(0u64);
+    sc_setup
+        .b_mock
+        .set_esdt_balance(&first_user, LS_TOKEN_ID, &exp18(50u64));
+    sc_setup
+        .b_mock
+        .set_esdt_balance(&second_user, LS_TOKEN_ID, &exp18(50u64));
+
+    sc_setup.b_mock.set_block_epoch(10u64);
+    sc_setup.lock_for_vote(&first_user, 30u64);
+    sc_setup.lock_for_vote(&first_user, 10u64);
+
+    sc_setup.b_mock.set_block_epoch(12u64);
+    sc_setup.unlock_from_vote(&first_user, 15u64);
+    sc_setup.lock_for_vote(&second_user, 20u64);
+    sc_setup.check_user_balance(&first_user, LS_TOKEN_ID, 25u64);
+
+    // balances locked during the start epoch do not count
+    sc_setup.check_vote_power_at(&first_user, 10u64, 0u64);
+    sc_setup.check_vote_power_at(&first_user, 11u64, 40u64);
+    sc_setup.check_vote_power_at(&first_user, 12u64, 40u64);
+    sc_setup.check_vote_power_at(&first_user, 13u64, 25u64);
+    sc_setup.check_vote_power_at(&second_user, 12u64, 0u64);
+    sc_setup.check_vote_power_at(&second_user, 13u64, 20u64);
+    sc_setup.check_total_vote_power_at(11u64, 40u64);
+    sc_setup.check_total_vote_power_at(13u64, 45u64);
+}
+
+#[test]
+fn liquid_staking_cast_aggregated_vote_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let vote_contract = sc_setup.setup_new_user(0u64);
+    let user = sc_setup.setup_new_user(0u64);
+
+    sc_setup.cast_aggregated_vote_check_error(&vote_contract, b"yes", "Vote contract is not set");
+
+    sc_setup.set_vote_contract_direct(&vote_contract);
+    sc_setup.cast_aggregated_vote_check_error(&user, b"yes", "Invalid caller");
+    sc_setup.cast_aggregated_vote_check_error(&vote_contract, b"maybe", "Invalid vote type");
+    sc_setup.cast_aggregated_vote_check_error(&vote_contract, b"yes", "No voting power");
+}
+
+#[test]
+fn liquid_staking_protocol_vote_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let vote_contract = sc_setup.setup_new_user(0u64);
+    let first_user = sc_setup.setup_new_user(0u64);
+    let second_user = sc_setup.setup_new_user(0u64);
+    sc_setup
+        .b_mock
+        .set_esdt_balance(&first_user, LS_TOKEN_ID, &exp18(50u64));
+    sc_setup
+        .b_mock
+        .set_esdt_balance(&second_user, LS_TOKEN_ID, &exp18(50u64));
+    sc_setup.b_mock.set_block_epoch(10u64);
+    sc_setup.lock_for_vote(&first_user, 30u64);
+    sc_setup.lock_for_vote(&second_user, 20u64);
+
+    // owner mode, the owner decides the direction
+    let proposal = 1u32;
+    sc_setup.register_protocol_proposal(proposal, 11u64, 13u64);
+    sc_setup.cast_protocol_vote_check_error(&first_user, proposal, "Protocol voting is not open");
+    sc_setup.decide_protocol_vote_check_error(proposal, b"maybe", "Invalid vote type");
+    sc_setup.decide_protocol_vote_check_error(proposal, b"yes", "Protocol voting is not open");
+    sc_setup.vote_protocol_proposal_check_error(
+        &first_user,
+        proposal,
+        b"yes",
+        "Not allowed in the current protocol vote mode",
+    );
+
+    // the proposal can no longer be delegated through the vote contract
+    sc_setup.set_vote_contract_direct(&vote_contract);
+    sc_setup.cast_aggregated_vote_check_error(
+        &vote_contract,
+        b"yes",
+        "Proposal is voted directly by the protocol",
+    );
+
+    // DAO mode, lsEGLD locked before the start epoch decides the direction
+    sc_setup.set_protocol_vote_mode(ProtocolVoteMode::Dao);
+    sc_setup.vote_protocol_proposal_check_error(
+        &first_user,
+        proposal,
+        b"no",
+        "Protocol voting is not open",
+    );
+    sc_setup.b_mock.set_block_epoch(11u64);
+    sc_setup.vote_protocol_proposal(&first_user, proposal, b"no");
+    sc_setup.vote_protocol_proposal_check_error(
+        &first_user,
+        proposal,
+        b"yes",
+        "Already voted on this proposal",
+    );
+    sc_setup.vote_protocol_proposal(&second_user, proposal, b"yes");
+    sc_setup.vote_protocol_proposal_check_error(
+        &vote_contract,
+        proposal,
+        b"yes",
+        "No voting power",
+    );
+    sc_setup.check_protocol_proposal_tally(proposal, b"no", 30u64);
+    sc_setup.check_protocol_proposal_tally(proposal, b"yes", 20u64);
+    sc_setup.cast_protocol_vote_check_error(
+        &first_user,
+        proposal,
+        "Protocol voting has not ended yet",
+    );
+
+    sc_setup.b_mock.set_block_epoch(13u64);
+    sc_setup.cast_protocol_vote(&first_user, proposal);
+    sc_setup.check_protocol_vote(proposal, Some(b"no"));
+    sc_setup.cast_protocol_vote_check_error(
+        &first_user,
+        proposal,
+        "Protocol vote was already cast",
+    );
+
+    // a vote rejected by the governance contract can be cast again
+    sc_setup.protocol_vote_callback_error(proposal);
+    sc_setup.check_protocol_vote(proposal, None);
+}
+
+#[test]
+fn liquid_staking_delegation_contract_verification_test() {
+    let mut sc_setup = LiquidStakingContractSetup::new(liquid_staking::contract_obj);
+    let provider = sc_setup.setup_new_user(0u64);
+    sc_setup.set_unbonded_delegation_contract(&provider, 0u64);
+
+    // nothing is flagged before the first verification
+    sc_setup.check_delegation_contract_mismatches(&provider, false, false, false);
+
+    // the provider reported no stake, no cap and no nodes
+    sc_setup.apply_verification_results(&provider, 0u64, 0u64, 0);
+    sc_setup.check_delegation_contract_mismatches(&provider, false, false, false);
+
+    sc_setup.apply_verification_results(&provider, 1000u64, 500u64, 2);
+    sc_setup.check_delegation_contract_mismatches(&provider, true, true, true);
+}
+
 #[ignore = "delegation setup impossible on blackbox currently"]
 #[test]
 fn liquid_staking_add_liquidity_test() {
@@ -0,0 +1,51 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::contract]
+pub trait WegldSwapMock {
+    #[init]
+    fn init(&self, wrapped_egld_token_id: TokenIdentifier) {
+        self.wrapped_egld_token_id().set(wrapped_egld_token_id);
+    }
+
+    #[payable("EGLD")]
+    #[endpoint(wrapEgld)]
+    fn wrap_egld(&self) -> EsdtTokenPayment {
+        require!(!self.wrap_failure().get(), "Wrapping is paused");
+        let payment_amount = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
+        let payment = EsdtTokenPayment::new(self.wrapped_egld_token_id().get(), 0, payment_amount);
+        self.send().direct_esdt(
+            &caller,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &payment.amount,
+        );
+
+        payment
+    }
+
+    #[payable("*")]
+    #[endpoint(unwrapEgld)]
+    fn unwrap_egld(&self) {
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.wrapped_egld_token_id().get(),
+            "Wrong esdt token"
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.send().direct_egld(&caller, &payment.amount);
+    }
+
+    #[endpoint(setWrapFailure)]
+    fn set_wrap_failure(&self, fail: bool) {
+        self.wrap_failure().set(fail);
+    }
+
+    #[storage_mapper("wrapFailure")]
+    fn wrap_failure(&self) -> SingleValueMapper<bool>;
+
+    #[view(getWrappedEgldTokenId)]
+    #[storage_mapper("wrappedEgldTokenId")]
+    fn wrapped_egld_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+}
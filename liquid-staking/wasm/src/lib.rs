@@ -6,10 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           33
+// Endpoints:                          137
 // Async Callback:                       1
-// Promise callbacks:                    5
-// Total number of exported functions:  41
+// Promise callbacks:                   12
+// Total number of exported functions: 152
 
 #![no_std]
 
@@ -24,16 +24,22 @@ multiversx_sc_wasm_adapter::endpoints! {
         getLsValueForPosition => get_ls_value_for_position
         registerLsToken => register_ls_token
         registerUnstakeToken => register_unstake_token
+        setUnstakeTokenMetadata => set_unstake_token_metadata
         getState => state
         getLsTokenId => ls_token
         getLsSupply => ls_token_supply
         getVirtualEgldReserve => virtual_egld_reserve
         getRewardsReserve => rewards_reserve
         getUnstakeTokenId => unstake_token
-        clearOngoingWhitelistOp => clear_ongoing_whitelist_op
+        getUnstakeTokenUriTemplate => unstake_token_uri_template
+        getUnstakeTokenRoyalties => unstake_token_royalties
+        getDelegatedVotingPower => delegated_voting_power
+        isAggregatedVoteCast => aggregated_vote_cast
+        clearPendingWhitelist => clear_pending_whitelist
         whitelistDelegationContract => whitelist_delegation_contract
         changeDelegationContractAdmin => change_delegation_contract_admin
         changeDelegationContractParams => change_delegation_contract_params
+        getDelegationAddressesPage => get_delegation_addresses_page
         getDelegationStatus => get_delegation_status
         getDelegationContractStakedAmount => get_delegation_contract_staked_amount
         getDelegationContractUnstakedAmount => get_delegation_contract_unstaked_amount
@@ -41,19 +47,124 @@ multiversx_sc_wasm_adapter::endpoints! {
         setStateActive => set_state_active
         setStateInactive => set_state_inactive
         getDelegationAddressesList => delegation_addresses_list
-        getAddressesToClaim => addresses_to_claim
+        getClaimCursor => claim_cursor
+        getRemainingClaimCount => remaining_claim_count
+        getDelegationApys => delegation_apys
+        getApyBucket => apy_bucket
+        getLowPriorityDelegationAddresses => low_priority_delegation_addresses
         getDelegationClaimStatus => delegation_claim_status
         getDelegationContractData => delegation_contract_data
+        getProviderBond => provider_bond
+        getPendingWhitelist => pending_whitelist
+        getPendingWhitelistAddresses => pending_whitelist_addresses
+        setIntegratorWhitelistEnabled => set_integrator_whitelist_enabled
+        addIntegrators => add_integrators
+        removeIntegrators => remove_integrators
+        isIntegratorWhitelistEnabled => is_integrator_whitelist_enabled
+        getIntegratorWhitelist => integrator_whitelist
+        applyForWhitelist => apply_for_whitelist
+        approveWhitelistApplication => approve_whitelist_application
+        rejectWhitelistApplication => reject_whitelist_application
+        withdrawWhitelistApplication => withdraw_whitelist_application
+        slashProviderBond => slash_provider_bond
+        releaseProviderBond => release_provider_bond
+        getWhitelistApplication => whitelist_application
+        getPendingApplications => pending_applications
+        setMinEgldToDelegate => set_min_egld_to_delegate
+        setEgldToWhitelist => set_egld_to_whitelist
+        setWhitelistBond => set_whitelist_bond
+        setUnbondPeriod => set_unbond_period
+        setMaxDelegationAddresses => set_max_delegation_addresses
+        setRecomputeBlockOffset => set_recompute_block_offset
+        setClaimRewardsGas => set_claim_rewards_gas
+        setAsyncCallGas => set_async_call_gas
+        getParameters => get_parameters
+        setProtocolVoteMode => set_protocol_vote_mode
+        registerProtocolProposal => register_protocol_proposal
+        decideProtocolVote => decide_protocol_vote
+        voteProtocolProposal => vote_protocol_proposal
+        castProtocolVote => cast_protocol_vote
+        getProtocolVoteMode => protocol_vote_mode
+        getProtocolProposal => protocol_proposal
+        getProtocolProposalTally => protocol_proposal_tally
+        hasVotedProtocolProposal => protocol_proposal_voted
+        getProtocolVote => protocol_vote
+        getProtocolVotedProposals => protocol_voted_proposals
+        verifyDelegationContract => verify_delegation_contract
+        getDelegationContractVerification => get_delegation_contract_verification
+        getMismatchedDelegationContracts => get_mismatched_delegation_contracts
         set_vote_contract => set_vote_contract
         delegateVote => delegate_vote
+        castAggregatedVote => cast_aggregated_vote
         getVoteContract => vote_contract
+        lockForVote => lock_for_vote
+        unlockFromVote => unlock_from_vote
+        getVotePowerAt => get_vote_power_at
+        getTotalVotePowerAt => get_total_vote_power_at
+        getVoteLockedBalance => vote_locked_balance
+        getTotalVoteLocked => total_vote_locked
+        setWegldSwap => set_wegld_swap
+        claimWegldRefund => claim_wegld_refund
+        getWegldSwapAddress => wegld_swap_address
+        getWegldTokenId => wegld_token_id
+        getWegldRefund => wegld_refund
+        isWegldPayout => wegld_payout
+        setProviderClaimGas => set_provider_claim_gas
         claimRewards => claim_rewards
+        getProviderClaimGas => get_provider_claim_gas
+        getFailedClaimRetries => failed_claim_retries
+        getFailedClaims => failed_claims
         delegateRewards => delegate_rewards
         recomputeTokenReserve => recompute_token_reserve
         unbondTokens => unbond_tokens
+        unbondTokensFor => unbond_tokens_for
+        getUnstakeTokenMaturity => get_unstake_token_maturity
+        getPendingUndelegations => get_pending_undelegations
+        getUndelegationBatch => undelegation_batch
+        getUntrackedUndelegation => untracked_undelegation
         withdrawAll => withdraw_all
         addLiquidity => add_liquidity
+        addLiquidityWegld => add_liquidity_wegld
+        addLiquidityFor => add_liquidity_for
+        wrapLsToken => wrap_ls_token
+        unwrapLsToken => unwrap_ls_token
+        getRebasingBalance => get_rebasing_balance
+        getRebasingTotalSupply => get_rebasing_total_supply
+        getWrappedShares => wrapped_shares
+        getTotalWrappedShares => total_wrapped_shares
         removeLiquidity => remove_liquidity
+        removeLiquidityWegld => remove_liquidity_wegld
+        removeLiquidityFor => remove_liquidity_for
+        setMarketFee => set_market_fee
+        listUnstakeToken => list_unstake_token
+        cancelUnstakeListing => cancel_unstake_listing
+        buyUnstakeToken => buy_unstake_token
+        getUnstakeListings => get_unstake_listings
+        getUnstakeListing => unstake_listing
+        getMarketFeePercentage => market_fee_percentage
+        getMarketFeesReserve => market_fees_reserve
+        getTotalMarketFees => total_market_fees
+        setPartnerSharePercentage => set_partner_share_percentage
+        claimMarketFees => claim_market_fees
+        distributePartnerShare => distribute_partner_share
+        getPendingPartnerShare => get_pending_partner_share
+        claimPartnerRewards => claim_partner_rewards
+        getPartnerSharePercentage => partner_share_percentage
+        getPartnerShareWatermark => partner_share_watermark
+        getPartnerRewards => partner_rewards
+        registerPartner => register_partner
+        unregisterPartner => unregister_partner
+        getPartnerTvl => get_partner_tvl
+        getPartnerTvlHistory => get_partner_tvl_history
+        getPartners => partners
+        getUserReferral => user_referral
+        whitelist_application_callback => whitelist_application_callback
+        protocol_vote_callback => protocol_vote_callback
+        contract_config_callback => contract_config_callback
+        total_active_stake_callback => total_active_stake_callback
+        node_states_callback => node_states_callback
+        delegate_vote_callback => delegate_vote_callback
+        aggregated_vote_callback => aggregated_vote_callback
         claim_rewards_callback => claim_rewards_callback
         delegate_rewards_callback => delegate_rewards_callback
         withdraw_tokens_callback => withdraw_tokens_callback
@@ -0,0 +1,37 @@
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum MerkleError {
+    InvalidAddress(String),
+    InvalidPower(String),
+    InvalidHash(String),
+    InvalidSnapshotLine(usize),
+    DuplicateAddress(String),
+    EmptySnapshot,
+    AddressNotFound(String),
+}
+
+impl fmt::Display for MerkleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MerkleError::InvalidAddress(address) => write!(f, "Invalid address: {address}"),
+            MerkleError::InvalidPower(power) => write!(f, "Invalid voting power: {power}"),
+            MerkleError::InvalidHash(hash) => write!(f, "Invalid hash: {hash}"),
+            MerkleError::InvalidSnapshotLine(line) => {
+                write!(f, "Invalid snapshot line {line}, expected address,power")
+            }
+            MerkleError::DuplicateAddress(address) => {
+                write!(
+                    f,
+                    "Address appears more than once in the snapshot: {address}"
+                )
+            }
+            MerkleError::EmptySnapshot => write!(f, "The snapshot has no entries"),
+            MerkleError::AddressNotFound(address) => {
+                write!(f, "Address is not part of the snapshot: {address}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MerkleError {}
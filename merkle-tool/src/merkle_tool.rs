@@ -0,0 +1,96 @@
+mod errors;
+mod merkle_tool_cli;
+mod proof;
+mod snapshot;
+mod tree;
+
+use std::{fs, path::Path};
+
+use clap::Parser;
+pub use errors::MerkleError;
+use merkle_tool_cli::{MerkleToolCli, MerkleToolCliCommand};
+pub use proof::{decode_hash, MultiProof, MultiProofLeaf, VoterProof};
+pub use snapshot::{
+    decode_address, encode_address, parse_power, parse_snapshot_csv, AddressBytes, SnapshotEntry,
+    ADDRESS_HRP, ADDRESS_LENGTH,
+};
+pub use tree::{
+    compute_root, hash_pair, leaf_hash, verify_multi_proof, verify_proof, Hash, HashFunction,
+    MerkleTree, HASH_LENGTH,
+};
+
+pub fn cli() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = MerkleToolCli::parse();
+    match cli.command {
+        MerkleToolCliCommand::Root(args) => {
+            let tree = load_snapshot(&args.snapshot, args.hash_function)?;
+            println!("{}", hex::encode(tree.root()));
+        }
+        MerkleToolCliCommand::Proofs(args) => {
+            let tree = load_snapshot(&args.snapshot, args.hash_function)?;
+            fs::create_dir_all(&args.out_dir)?;
+            for index in 0..tree.entries().len() {
+                let voter_proof = VoterProof::from_tree(&tree, index);
+                let path = Path::new(&args.out_dir).join(format!("{}.json", voter_proof.address));
+                fs::write(path, serde_json::to_string_pretty(&voter_proof)?)?;
+            }
+            println!("{}", hex::encode(tree.root()));
+        }
+        MerkleToolCliCommand::Proof(args) => {
+            let tree = load_snapshot(&args.snapshot, args.hash_function)?;
+            let address = decode_address(&args.address)?;
+            let index = tree
+                .position(&address)
+                .ok_or(MerkleError::AddressNotFound(args.address))?;
+            let voter_proof = VoterProof::from_tree(&tree, index);
+            println!("{}", serde_json::to_string_pretty(&voter_proof)?);
+        }
+        MerkleToolCliCommand::MultiProof(args) => {
+            let tree = load_snapshot(&args.snapshot, args.hash_function)?;
+            let mut indices = Vec::new();
+            for address in args.addresses {
+                let index = tree
+                    .position(&decode_address(&address)?)
+                    .ok_or(MerkleError::AddressNotFound(address))?;
+                indices.push(index);
+            }
+            let multi_proof = MultiProof::from_tree(&tree, &indices);
+            println!("{}", serde_json::to_string_pretty(&multi_proof)?);
+        }
+        MerkleToolCliCommand::Verify(args) => {
+            let voter_proof: VoterProof = serde_json::from_str(&fs::read_to_string(&args.proof)?)?;
+            let root = match args.root {
+                Some(root) => decode_hash(&root)?,
+                None => voter_proof.root_hash()?,
+            };
+            if !voter_proof.verify(&root)? {
+                return Err("Invalid merkle proof provided".into());
+            }
+            println!(
+                "Valid proof for {} with power {}",
+                voter_proof.address, voter_proof.power
+            );
+        }
+        MerkleToolCliCommand::VerifyMulti(args) => {
+            let multi_proof: MultiProof = serde_json::from_str(&fs::read_to_string(&args.proof)?)?;
+            let root = match args.root {
+                Some(root) => decode_hash(&root)?,
+                None => multi_proof.root_hash()?,
+            };
+            if !multi_proof.verify(&root)? {
+                return Err("Invalid merkle proof provided".into());
+            }
+            println!("Valid multi-proof for {} voters", multi_proof.leaves.len());
+        }
+    }
+
+    Ok(())
+}
+
+pub fn load_snapshot(
+    path: &str,
+    hash_function: HashFunction,
+) -> Result<MerkleTree, Box<dyn std::error::Error>> {
+    let entries = parse_snapshot_csv(&fs::read_to_string(path)?)?;
+    Ok(MerkleTree::new(hash_function, entries)?)
+}
@@ -0,0 +1,101 @@
+use clap::{Args, Parser, Subcommand};
+
+use crate::tree::HashFunction;
+
+/// Vote snapshot Merkle tree CLI
+#[derive(PartialEq, Eq, Debug, Parser)]
+#[command(version, about)]
+#[command(propagate_version = true)]
+pub struct MerkleToolCli {
+    #[command(subcommand)]
+    pub command: MerkleToolCliCommand,
+}
+
+/// Vote snapshot Merkle tree CLI Commands
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum MerkleToolCliCommand {
+    #[command(name = "root", about = "Print the root hash of a snapshot")]
+    Root(SnapshotArg),
+
+    #[command(
+        name = "proofs",
+        about = "Write the JSON proof of every snapshot address to a directory"
+    )]
+    Proofs(ProofsArgs),
+
+    #[command(name = "proof", about = "Print the JSON proof of a snapshot address")]
+    Proof(ProofArgs),
+
+    #[command(
+        name = "multi-proof",
+        about = "Print the JSON multi-proof of several snapshot addresses"
+    )]
+    MultiProof(MultiProofArgs),
+
+    #[command(name = "verify", about = "Verify a JSON proof against its root hash")]
+    Verify(VerifyArgs),
+
+    #[command(
+        name = "verify-multi",
+        about = "Verify a JSON multi-proof against its root hash"
+    )]
+    VerifyMulti(VerifyArgs),
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SnapshotArg {
+    /// CSV file with one address,power pair per line
+    #[arg(short = 's', long = "snapshot")]
+    pub snapshot: String,
+    /// Hash function set for the proposal in the vote contract
+    #[arg(long = "hash-function", value_enum, default_value_t = HashFunction::Sha256)]
+    pub hash_function: HashFunction,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ProofsArgs {
+    /// CSV file with one address,power pair per line
+    #[arg(short = 's', long = "snapshot")]
+    pub snapshot: String,
+    /// Hash function set for the proposal in the vote contract
+    #[arg(long = "hash-function", value_enum, default_value_t = HashFunction::Sha256)]
+    pub hash_function: HashFunction,
+    /// Directory receiving one <address>.json file per voter
+    #[arg(short = 'o', long = "out-dir")]
+    pub out_dir: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ProofArgs {
+    /// CSV file with one address,power pair per line
+    #[arg(short = 's', long = "snapshot")]
+    pub snapshot: String,
+    /// Hash function set for the proposal in the vote contract
+    #[arg(long = "hash-function", value_enum, default_value_t = HashFunction::Sha256)]
+    pub hash_function: HashFunction,
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct MultiProofArgs {
+    /// CSV file with one address,power pair per line
+    #[arg(short = 's', long = "snapshot")]
+    pub snapshot: String,
+    /// Hash function set for the proposal in the vote contract
+    #[arg(long = "hash-function", value_enum, default_value_t = HashFunction::Sha256)]
+    pub hash_function: HashFunction,
+    /// Comma separated addresses to prove together
+    #[arg(short = 'a', long = "addresses", value_delimiter = ',')]
+    pub addresses: Vec<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct VerifyArgs {
+    /// JSON proof, as written by the proof, proofs and multi-proof commands
+    #[arg(short = 'p', long = "proof")]
+    pub proof: String,
+    /// Root hash to verify against, instead of the one stored in the proof
+    #[arg(short = 'r', long = "root")]
+    pub root: Option<String>,
+}
@@ -0,0 +1,6 @@
+fn main() {
+    if let Err(error) = merkle_tool::cli() {
+        eprintln!("{error}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::MerkleError,
+    snapshot::SnapshotEntry,
+    tree::{verify_multi_proof, verify_proof, Hash, HashFunction, MerkleTree},
+};
+
+// the JSON handed to a voter, holding everything `delegateVote` needs
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct VoterProof {
+    pub root: String,
+    #[serde(default)]
+    pub hash_function: HashFunction,
+    pub address: String,
+    pub power: String,
+    pub proof: Vec<String>,
+}
+
+impl VoterProof {
+    pub fn from_tree(tree: &MerkleTree, index: usize) -> Self {
+        let entry = &tree.entries()[index];
+        VoterProof {
+            root: hex::encode(tree.root()),
+            hash_function: tree.hash_function(),
+            address: entry.bech32_address(),
+            power: entry.power.to_string(),
+            proof: tree.proof(index).iter().map(hex::encode).collect(),
+        }
+    }
+
+    pub fn entry(&self) -> Result<SnapshotEntry, MerkleError> {
+        SnapshotEntry::new(&self.address, &self.power)
+    }
+
+    pub fn root_hash(&self) -> Result<Hash, MerkleError> {
+        decode_hash(&self.root)
+    }
+
+    pub fn proof_hashes(&self) -> Result<Vec<Hash>, MerkleError> {
+        decode_hashes(&self.proof)
+    }
+
+    pub fn verify(&self, root: &Hash) -> Result<bool, MerkleError> {
+        Ok(verify_proof(
+            self.hash_function,
+            &self.entry()?,
+            &self.proof_hashes()?,
+            root,
+        ))
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct MultiProofLeaf {
+    pub index: usize,
+    pub address: String,
+    pub power: String,
+}
+
+// the JSON a relayer passes to `proveVotingPowers`, proving many voters at once
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct MultiProof {
+    pub root: String,
+    #[serde(default)]
+    pub hash_function: HashFunction,
+    pub leaf_count: usize,
+    pub leaves: Vec<MultiProofLeaf>,
+    pub proof: Vec<String>,
+}
+
+impl MultiProof {
+    pub fn from_tree(tree: &MerkleTree, indices: &[usize]) -> Self {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        let leaves = indices
+            .iter()
+            .map(|index| {
+                let entry = &tree.entries()[*index];
+                MultiProofLeaf {
+                    index: *index,
+                    address: entry.bech32_address(),
+                    power: entry.power.to_string(),
+                }
+            })
+            .collect();
+
+        MultiProof {
+            root: hex::encode(tree.root()),
+            hash_function: tree.hash_function(),
+            leaf_count: tree.entries().len(),
+            leaves,
+            proof: tree.multi_proof(&indices).iter().map(hex::encode).collect(),
+        }
+    }
+
+    pub fn root_hash(&self) -> Result<Hash, MerkleError> {
+        decode_hash(&self.root)
+    }
+
+    pub fn verify(&self, root: &Hash) -> Result<bool, MerkleError> {
+        let leaves = self
+            .leaves
+            .iter()
+            .map(|leaf| Ok((leaf.index, SnapshotEntry::new(&leaf.address, &leaf.power)?)))
+            .collect::<Result<Vec<_>, MerkleError>>()?;
+
+        Ok(verify_multi_proof(
+            self.hash_function,
+            &leaves,
+            self.leaf_count,
+            &decode_hashes(&self.proof)?,
+            root,
+        ))
+    }
+}
+
+pub fn decode_hash(hex_hash: &str) -> Result<Hash, MerkleError> {
+    let invalid_hash = || MerkleError::InvalidHash(hex_hash.to_string());
+    hex::decode(hex_hash.trim_start_matches("0x"))
+        .map_err(|_| invalid_hash())?
+        .try_into()
+        .map_err(|_| invalid_hash())
+}
+
+fn decode_hashes(hex_hashes: &[String]) -> Result<Vec<Hash>, MerkleError> {
+    hex_hashes.iter().map(|item| decode_hash(item)).collect()
+}
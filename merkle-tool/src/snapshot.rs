@@ -0,0 +1,83 @@
+use bech32::{Bech32, Hrp};
+use num_bigint::BigUint;
+
+use crate::errors::MerkleError;
+
+pub const ADDRESS_HRP: &str = "erd";
+pub const ADDRESS_LENGTH: usize = 32;
+
+pub type AddressBytes = [u8; ADDRESS_LENGTH];
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SnapshotEntry {
+    pub address: AddressBytes,
+    pub power: BigUint,
+}
+
+impl SnapshotEntry {
+    pub fn new(bech32_address: &str, power: &str) -> Result<Self, MerkleError> {
+        Ok(SnapshotEntry {
+            address: decode_address(bech32_address)?,
+            power: parse_power(power)?,
+        })
+    }
+
+    pub fn bech32_address(&self) -> String {
+        encode_address(&self.address)
+    }
+
+    // address bytes followed by the minimal big-endian power bytes,
+    // as `to_bytes_be_buffer` produces them in the vote contract (empty for 0)
+    pub fn leaf_bytes(&self) -> Vec<u8> {
+        let mut leaf_bytes = self.address.to_vec();
+        if self.power != BigUint::ZERO {
+            leaf_bytes.extend(self.power.to_bytes_be());
+        }
+
+        leaf_bytes
+    }
+}
+
+pub fn decode_address(bech32_address: &str) -> Result<AddressBytes, MerkleError> {
+    let invalid_address = || MerkleError::InvalidAddress(bech32_address.to_string());
+    let (hrp, data) = bech32::decode(bech32_address).map_err(|_| invalid_address())?;
+    if hrp.as_str() != ADDRESS_HRP {
+        return Err(invalid_address());
+    }
+
+    data.try_into().map_err(|_| invalid_address())
+}
+
+pub fn encode_address(address: &AddressBytes) -> String {
+    let hrp = Hrp::parse(ADDRESS_HRP).unwrap();
+    bech32::encode::<Bech32>(hrp, address).unwrap()
+}
+
+pub fn parse_power(power: &str) -> Result<BigUint, MerkleError> {
+    BigUint::parse_bytes(power.as_bytes(), 10)
+        .ok_or_else(|| MerkleError::InvalidPower(power.to_string()))
+}
+
+// one `address,power` pair per line, the power being a decimal amount in the
+// smallest denomination; blank lines, `#` comments and an optional header are skipped
+pub fn parse_snapshot_csv(contents: &str) -> Result<Vec<SnapshotEntry>, MerkleError> {
+    let mut entries = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (address, power) = line
+            .split_once(',')
+            .ok_or(MerkleError::InvalidSnapshotLine(index + 1))?;
+        let (address, power) = (address.trim(), power.trim());
+        if entries.is_empty() && address.eq_ignore_ascii_case("address") {
+            continue;
+        }
+
+        entries.push(SnapshotEntry::new(address, power)?);
+    }
+
+    Ok(entries)
+}
@@ -0,0 +1,225 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+use crate::{
+    errors::MerkleError,
+    snapshot::{AddressBytes, SnapshotEntry},
+};
+
+pub const HASH_LENGTH: usize = 32;
+
+pub type Hash = [u8; HASH_LENGTH];
+
+// the hash functions the vote contract can be configured with, per proposal
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum HashFunction {
+    #[default]
+    Sha256,
+    Keccak256,
+}
+
+impl HashFunction {
+    pub fn hash(&self, bytes: &[u8]) -> Hash {
+        match self {
+            HashFunction::Sha256 => Sha256::digest(bytes).into(),
+            HashFunction::Keccak256 => Keccak256::digest(bytes).into(),
+        }
+    }
+}
+
+pub fn leaf_hash(hash_function: HashFunction, entry: &SnapshotEntry) -> Hash {
+    hash_function.hash(&entry.leaf_bytes())
+}
+
+// the smaller hash always goes first, so a proof does not need to record
+// on which side each sibling sits; both hashes have the same length, so the
+// byte order matches the `BigUint` comparison done by the vote contract
+pub fn hash_pair(hash_function: HashFunction, hash: &Hash, proof_item: &Hash) -> Hash {
+    let mut pair = Vec::with_capacity(2 * HASH_LENGTH);
+    if hash < proof_item {
+        pair.extend_from_slice(hash);
+        pair.extend_from_slice(proof_item);
+    } else {
+        pair.extend_from_slice(proof_item);
+        pair.extend_from_slice(hash);
+    }
+
+    hash_function.hash(&pair)
+}
+
+pub fn compute_root(hash_function: HashFunction, entry: &SnapshotEntry, proof: &[Hash]) -> Hash {
+    proof
+        .iter()
+        .fold(leaf_hash(hash_function, entry), |hash, proof_item| {
+            hash_pair(hash_function, &hash, proof_item)
+        })
+}
+
+pub fn verify_proof(
+    hash_function: HashFunction,
+    entry: &SnapshotEntry,
+    proof: &[Hash],
+    root: &Hash,
+) -> bool {
+    compute_root(hash_function, entry, proof) == *root
+}
+
+// mirrors `verify_merkle_multi_proof` of the vote contract: the leaves are given
+// as (index, entry) pairs sorted by index, and the tree is rebuilt level by level
+pub fn verify_multi_proof(
+    hash_function: HashFunction,
+    leaves: &[(usize, SnapshotEntry)],
+    leaf_count: usize,
+    proof: &[Hash],
+    root: &Hash,
+) -> bool {
+    if leaves.is_empty()
+        || leaves.last().unwrap().0 >= leaf_count
+        || leaves.windows(2).any(|pair| pair[0].0 >= pair[1].0)
+    {
+        return false;
+    }
+
+    let mut nodes = leaves
+        .iter()
+        .map(|(index, entry)| (*index, leaf_hash(hash_function, entry)))
+        .collect::<Vec<_>>();
+    let mut node_count = leaf_count;
+    let mut proof_items = proof.iter();
+    while node_count > 1 {
+        let mut parents = Vec::new();
+        let mut position = 0;
+        while position < nodes.len() {
+            let (index, hash) = nodes[position];
+            let parent_hash = if index.is_multiple_of(2) && index + 1 == node_count {
+                hash
+            } else if nodes.get(position + 1).map(|node| node.0) == Some(index ^ 1) {
+                position += 1;
+                hash_pair(hash_function, &hash, &nodes[position].1)
+            } else {
+                match proof_items.next() {
+                    Some(proof_item) => hash_pair(hash_function, &hash, proof_item),
+                    None => return false,
+                }
+            };
+            parents.push((index / 2, parent_hash));
+            position += 1;
+        }
+        nodes = parents;
+        node_count = node_count.div_ceil(2);
+    }
+
+    proof_items.next().is_none() && nodes[0].1 == *root
+}
+
+pub struct MerkleTree {
+    hash_function: HashFunction,
+    entries: Vec<SnapshotEntry>,
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    pub fn new(
+        hash_function: HashFunction,
+        entries: Vec<SnapshotEntry>,
+    ) -> Result<Self, MerkleError> {
+        if entries.is_empty() {
+            return Err(MerkleError::EmptySnapshot);
+        }
+
+        let mut addresses = HashSet::new();
+        for entry in &entries {
+            if !addresses.insert(entry.address) {
+                return Err(MerkleError::DuplicateAddress(entry.bech32_address()));
+            }
+        }
+
+        let mut levels = vec![entries
+            .iter()
+            .map(|entry| leaf_hash(hash_function, entry))
+            .collect::<Vec<_>>()];
+        while levels.last().unwrap().len() > 1 {
+            // a node without a sibling moves up unchanged, so it adds no proof item
+            let next_level = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|nodes| match nodes {
+                    [left, right] => hash_pair(hash_function, left, right),
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next_level);
+        }
+
+        Ok(MerkleTree {
+            hash_function,
+            entries,
+            levels,
+        })
+    }
+
+    pub fn hash_function(&self) -> HashFunction {
+        self.hash_function
+    }
+
+    pub fn root(&self) -> Hash {
+        self.levels.last().unwrap()[0]
+    }
+
+    pub fn entries(&self) -> &[SnapshotEntry] {
+        &self.entries
+    }
+
+    pub fn position(&self, address: &AddressBytes) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.address == *address)
+    }
+
+    pub fn proof(&self, index: usize) -> Vec<Hash> {
+        let mut proof = Vec::new();
+        let mut node_index = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(node_index ^ 1) {
+                proof.push(*sibling);
+            }
+            node_index /= 2;
+        }
+
+        proof
+    }
+
+    // the proof items of several leaves at once, in the order a level by level
+    // verification needs them; siblings that are both proven add no proof item
+    pub fn multi_proof(&self, indices: &[usize]) -> Vec<Hash> {
+        let mut proof = Vec::new();
+        let mut known = indices.to_vec();
+        known.sort_unstable();
+        known.dedup();
+        for level in &self.levels[..self.levels.len() - 1] {
+            let mut parents = Vec::new();
+            let mut position = 0;
+            while position < known.len() {
+                let index = known[position];
+                if index.is_multiple_of(2) && index + 1 == level.len() {
+                    // moves up unchanged
+                } else if known.get(position + 1) == Some(&(index ^ 1)) {
+                    position += 1;
+                } else {
+                    proof.push(level[index ^ 1]);
+                }
+                parents.push(index / 2);
+                position += 1;
+            }
+            known = parents;
+        }
+
+        proof
+    }
+}
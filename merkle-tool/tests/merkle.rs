@@ -0,0 +1,178 @@
+use merkle_tool::{
+    decode_hash, encode_address, parse_snapshot_csv, verify_multi_proof, verify_proof,
+    HashFunction, MerkleError, MerkleTree, MultiProof, SnapshotEntry, VoterProof,
+};
+use num_bigint::BigUint;
+
+const ALICE: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+const ROOT_HASH: &str = "078bc8a05f5e62733ca27a4e0df5f5ff2d7327c9ab6c7f4766b6af12b5cc9183";
+
+fn snapshot_entry(seed: u8, power: u64) -> SnapshotEntry {
+    SnapshotEntry {
+        address: [seed; 32],
+        power: BigUint::from(power),
+    }
+}
+
+#[test]
+fn merkle_tool_interactor_proof_test() {
+    // the proof hard-coded in the interactor delegate_vote test
+    let proof = [
+        "330f8db028b7b5a9435a0ddfd012bd29996fa9e38bfbf65ea32872c3468a06cb",
+        "972e54453b055faafc5d24d7486e7377cfce3d82a94f2d1dd6143ae7f9ddd06d",
+        "9b3c15e802052c3b7687dc35da074dffc5675501c8f924478cb98c97b92a0db2",
+    ]
+    .iter()
+    .map(|item| decode_hash(item).unwrap())
+    .collect::<Vec<_>>();
+    let root = decode_hash(ROOT_HASH).unwrap();
+
+    let entry = SnapshotEntry::new(ALICE, "1000000000000000000").unwrap();
+    assert!(verify_proof(HashFunction::Sha256, &entry, &proof, &root));
+
+    let entry = SnapshotEntry::new(ALICE, "2000000000000000000").unwrap();
+    assert!(!verify_proof(HashFunction::Sha256, &entry, &proof, &root));
+}
+
+#[test]
+fn merkle_tool_tree_proofs_test() {
+    for hash_function in [HashFunction::Sha256, HashFunction::Keccak256] {
+        for entries_count in 1..=9u8 {
+            let entries = (0..entries_count)
+                .map(|seed| snapshot_entry(seed, seed as u64 * 1_000))
+                .collect::<Vec<_>>();
+            let tree = MerkleTree::new(hash_function, entries.clone()).unwrap();
+            let root = tree.root();
+
+            for (index, entry) in entries.iter().enumerate() {
+                let proof = tree.proof(index);
+                assert!(verify_proof(hash_function, entry, &proof, &root));
+
+                let voter_proof = VoterProof::from_tree(&tree, index);
+                let json = serde_json::to_string(&voter_proof).unwrap();
+                let voter_proof: VoterProof = serde_json::from_str(&json).unwrap();
+                assert!(voter_proof.verify(&root).unwrap());
+
+                let wrong_entry = snapshot_entry(entry.address[0], index as u64 * 1_000 + 1);
+                assert!(!verify_proof(hash_function, &wrong_entry, &proof, &root));
+            }
+        }
+    }
+
+    // the hash function is part of the tree, a keccak proof does not verify as sha256
+    let entries = vec![snapshot_entry(1, 1), snapshot_entry(2, 2)];
+    let tree = MerkleTree::new(HashFunction::Keccak256, entries.clone()).unwrap();
+    assert!(!verify_proof(
+        HashFunction::Sha256,
+        &entries[0],
+        &tree.proof(0),
+        &tree.root()
+    ));
+}
+
+#[test]
+fn merkle_tool_multi_proof_test() {
+    for entries_count in 1..=11u8 {
+        let entries = (0..entries_count)
+            .map(|seed| snapshot_entry(seed, seed as u64 + 1))
+            .collect::<Vec<_>>();
+        let tree = MerkleTree::new(HashFunction::Sha256, entries.clone()).unwrap();
+        let root = tree.root();
+
+        // every non empty subset of the leaves
+        for subset in 1..(1u32 << entries_count) {
+            let indices = (0..entries_count as usize)
+                .filter(|index| subset & (1 << index) != 0)
+                .collect::<Vec<_>>();
+            let leaves = indices
+                .iter()
+                .map(|index| (*index, entries[*index].clone()))
+                .collect::<Vec<_>>();
+            let proof = tree.multi_proof(&indices);
+            assert!(verify_multi_proof(
+                HashFunction::Sha256,
+                &leaves,
+                entries.len(),
+                &proof,
+                &root
+            ));
+
+            let mut wrong_leaves = leaves.clone();
+            wrong_leaves[0].1.power += 1u32;
+            assert!(!verify_multi_proof(
+                HashFunction::Sha256,
+                &wrong_leaves,
+                entries.len(),
+                &proof,
+                &root
+            ));
+        }
+
+        let indices = (0..entries_count as usize).step_by(3).collect::<Vec<_>>();
+        let multi_proof = MultiProof::from_tree(&tree, &indices);
+        let json = serde_json::to_string(&multi_proof).unwrap();
+        let multi_proof: MultiProof = serde_json::from_str(&json).unwrap();
+        assert!(multi_proof.verify(&root).unwrap());
+    }
+
+    // leaves must be sorted and unique, and all proof items must be used
+    let entries = (0..4u8)
+        .map(|seed| snapshot_entry(seed, 1))
+        .collect::<Vec<_>>();
+    let tree = MerkleTree::new(HashFunction::Sha256, entries.clone()).unwrap();
+    let proof = tree.multi_proof(&[0, 2]);
+    let leaves = vec![(2, entries[2].clone()), (0, entries[0].clone())];
+    assert!(!verify_multi_proof(
+        HashFunction::Sha256,
+        &leaves,
+        4,
+        &proof,
+        &tree.root()
+    ));
+    let leaves = vec![(0, entries[0].clone()), (2, entries[2].clone())];
+    let mut longer_proof = proof.clone();
+    longer_proof.push(tree.root());
+    assert!(!verify_multi_proof(
+        HashFunction::Sha256,
+        &leaves,
+        4,
+        &longer_proof,
+        &tree.root()
+    ));
+}
+
+#[test]
+fn merkle_tool_snapshot_csv_test() {
+    let bob = encode_address(&[2; 32]);
+    let csv = format!("address,power\n{ALICE},1000000000000000000\n\n# comment\n{bob}, 0\n");
+    let entries = parse_snapshot_csv(&csv).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].bech32_address(), ALICE);
+    assert_eq!(entries[1].power, BigUint::ZERO);
+    // a zero power adds no bytes to the leaf, like `to_bytes_be_buffer` in the contract
+    assert_eq!(entries[1].leaf_bytes(), vec![2; 32]);
+
+    let duplicate_csv = format!("{ALICE},1\n{ALICE},2\n");
+    let entries = parse_snapshot_csv(&duplicate_csv).unwrap();
+    assert_eq!(
+        MerkleTree::new(HashFunction::Sha256, entries).err(),
+        Some(MerkleError::DuplicateAddress(ALICE.to_string()))
+    );
+
+    assert_eq!(
+        parse_snapshot_csv("erd1invalid,1").err(),
+        Some(MerkleError::InvalidAddress("erd1invalid".to_string()))
+    );
+    assert_eq!(
+        parse_snapshot_csv(&format!("{ALICE},-1")).err(),
+        Some(MerkleError::InvalidPower("-1".to_string()))
+    );
+    assert_eq!(
+        parse_snapshot_csv(ALICE).err(),
+        Some(MerkleError::InvalidSnapshotLine(1))
+    );
+    assert_eq!(
+        MerkleTree::new(HashFunction::Sha256, Vec::new()).err(),
+        Some(MerkleError::EmptySnapshot)
+    );
+}
@@ -0,0 +1,51 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{constants::ProposalId, errors::PROPOSAL_ALREADY_STARTED, views::VoteTally};
+
+// holder votes of an aggregated proposal are tallied here until its end epoch,
+// then cast once by the liquid staking contract with the whole protocol stake
+#[multiversx_sc::module]
+pub trait AggregationModule:
+    crate::events::EventsModule
+    + crate::merkle::MerkleModule
+    + crate::proposals::ProposalsModule
+    + crate::views::ViewsModule
+{
+    #[only_owner]
+    #[endpoint]
+    fn set_proposal_aggregation(&self, proposal_id: ProposalId) {
+        self.get_registered_proposal(proposal_id);
+        require!(
+            !self.is_voting_started(proposal_id),
+            PROPOSAL_ALREADY_STARTED
+        );
+        self.aggregated_proposal(proposal_id).set(true);
+    }
+
+    // the governance contract keeps a single vote per voter, so the aggregated vote goes
+    // entirely to the vote type with the highest tally, ties being won by the earlier
+    // vote type in VOTE_TYPES, and carries the summed tallies
+    fn get_aggregated_vote(&self, proposal_id: ProposalId) -> VoteTally<Self::Api> {
+        let tallies = self.get_proposal_vote_totals(proposal_id);
+        let mut total_power = BigUint::zero();
+        let mut winning_vote = tallies.get(0).clone();
+        for tally in tallies.iter() {
+            total_power += &tally.voting_power;
+            if tally.voting_power > winning_vote.voting_power {
+                winning_vote = tally.clone();
+            }
+        }
+        winning_vote.voting_power = total_power;
+
+        winning_vote
+    }
+
+    #[view(isAggregatedProposal)]
+    #[storage_mapper("aggregatedProposal")]
+    fn aggregated_proposal(&self, proposal_id: ProposalId) -> SingleValueMapper<bool>;
+
+    #[view(isAggregatedVoteCast)]
+    #[storage_mapper("aggregatedVoteCast")]
+    fn aggregated_vote_cast(&self, proposal_id: ProposalId) -> SingleValueMapper<bool>;
+}
@@ -5,4 +5,11 @@ pub const HASH_LENGTH: usize = 32;
 pub const MIN_GAS_FOR_SYNC_CALL: GasLimit = 15_000_000;
 pub const MIN_GAS_FINISH_EXEC: GasLimit = 1_000_000;
 pub type ProposalId = u32;
+// its root hash is the fallback for proposals without their own root
+pub const DEFAULT_ROOT_PROPOSAL_ID: ProposalId = 0;
 pub type Hash<M> = ManagedByteArray<M, HASH_LENGTH>;
+
+pub const VOTE_TYPES: [&[u8]; 4] = [b"yes", b"no", b"abstain", b"veto"];
+
+// prepended by wallets to every message they sign
+pub const SIGNED_MESSAGE_PREFIX: &[u8] = b"\x17Elrond Signed Message:\n";
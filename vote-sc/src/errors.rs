@@ -3,6 +3,21 @@ pub const NO_PROPOSAL: &[u8] = b"Proposal does not exist";
 pub const LS_SC_NOT_SET: &[u8] = b"Liquid Staking SC is not set";
 pub const INVALID_MERKLE_PROOF: &[u8] = b"Invalid merkle proof provided";
 pub const INVALID_SC_ADDRESS: &[u8] = b"Invalid SC address provided";
+pub const INVALID_VOTING_POWER: &[u8] = b"Voting power does not match the locked balance";
+pub const INVALID_VOTE_TYPE: &[u8] = b"Invalid vote type";
+pub const VOTING_NOT_ENDED: &[u8] = b"Voting has not ended yet";
+pub const AGGREGATION_ALREADY_CAST: &[u8] = b"Aggregated vote was already cast";
+pub const NO_AGGREGATION: &[u8] = b"Proposal is not aggregated";
+pub const NO_VOTES_TO_CAST: &[u8] = b"There are no votes to cast";
+pub const INSUFFICIENT_VOTING_POWER: &[u8] = b"Insufficient remaining voting power";
+pub const VOTE_CHANGE_NOT_ALLOWED: &[u8] = b"Vote type cannot be changed for this proposal";
+pub const INVALID_VOTING_WINDOW: &[u8] = b"Invalid voting window";
+pub const PROPOSAL_ALREADY_STARTED: &[u8] = b"Proposal voting has already started";
+pub const VOTING_NOT_OPEN: &[u8] = b"Voting is not open for this proposal";
+pub const ROOT_HASH_LOCKED: &[u8] = b"Root hash cannot change after voting started";
+pub const HASH_FUNCTION_LOCKED: &[u8] = b"Hash function cannot change after voting started";
+pub const NO_MERKLE_VOTING: &[u8] = b"Proposal does not use merkle proofs";
+pub const INVALID_VOTE_NONCE: &[u8] = b"Invalid vote nonce";
 pub const SELF_CALL_ERROR: &[u8] = b"Cannot call this endpoint through proposed action";
 
 pub static ERROR_INSUFFICIENT_GAS_FOR_SYNC: &[u8] = b"Insufficient gas for sync call";
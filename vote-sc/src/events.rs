@@ -10,7 +10,16 @@ pub trait EventsModule {
         &self,
         #[indexed] voter: &ManagedAddress,
         #[indexed] proposal_id: ProposalId,
+        #[indexed] vote_type: &ManagedBuffer,
         #[indexed] voting_power: &BigUint,
         #[indexed] user_quorum: &BigUint,
     );
+
+    #[event("AggregatedVoteCast")]
+    fn aggregated_vote_cast_event(
+        &self,
+        #[indexed] proposal_id: ProposalId,
+        #[indexed] vote_type: &ManagedBuffer,
+        voting_power: &BigUint,
+    );
 }
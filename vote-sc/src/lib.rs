@@ -2,18 +2,38 @@
 
 multiversx_sc::imports!();
 
+pub mod aggregation;
 mod caller_check;
 pub mod constants;
 mod errors;
 pub mod events;
-pub mod liquid_staking_proxy;
+pub mod merkle;
+pub mod proposals;
+pub mod signed_votes;
 pub mod views;
+#[rustfmt::skip]
+pub mod vote_sc_proxy;
+pub mod vote_usage;
 
-use crate::{constants::*, errors::*};
+use liquid_staking::liquid_staking_proxy;
+
+use crate::{
+    constants::*,
+    errors::*,
+    merkle::{MerkleNode, ProvenVotingPower},
+    vote_usage::VoteUsage,
+};
 
 #[multiversx_sc::contract]
 pub trait VoteSC:
-    caller_check::CallerCheckModule + events::EventsModule + views::ViewsModule
+    aggregation::AggregationModule
+    + caller_check::CallerCheckModule
+    + events::EventsModule
+    + merkle::MerkleModule
+    + proposals::ProposalsModule
+    + signed_votes::SignedVotesModule
+    + views::ViewsModule
+    + vote_usage::VoteUsageModule
 {
     #[init]
     fn init(&self) {}
@@ -25,9 +45,34 @@ pub trait VoteSC:
     #[endpoint]
     fn set_root_hash(&self, root_hash: Hash<Self::Api>, proposal_id: ProposalId) {
         require!(!root_hash.is_empty(), INVALID_ROOT_HASH);
+        self.require_root_hash_unlocked(proposal_id);
         self.proposal_root_hash(proposal_id).set(root_hash)
     }
 
+    #[only_owner]
+    #[endpoint]
+    fn set_root_hash_fallback(&self, enabled: bool) {
+        self.root_hash_fallback_disabled().set(!enabled);
+    }
+
+    // the root of a registered proposal can be replaced until its voting starts; a proposal
+    // registered after its start epoch can still get a first root, as no vote could use
+    // any root before; the default root of proposal 0 has no window of its own
+    fn require_root_hash_unlocked(&self, proposal_id: ProposalId) {
+        let proposal_mapper = self.proposal(proposal_id);
+        if proposal_mapper.is_empty() {
+            require!(proposal_id == DEFAULT_ROOT_PROPOSAL_ID, NO_PROPOSAL);
+            return;
+        }
+
+        let voting_started =
+            self.blockchain().get_block_epoch() >= proposal_mapper.get().start_epoch;
+        require!(
+            !voting_started || self.get_root_hash(proposal_id).is_none(),
+            ROOT_HASH_LOCKED
+        );
+    }
+
     #[only_owner]
     #[endpoint]
     fn set_liquid_staking_address(&self, address: ManagedAddress) {
@@ -45,22 +90,188 @@ pub trait VoteSC:
         vote: ManagedBuffer,
         voting_power: BigUint<Self::Api>,
         proof: ManagedVec<Hash<Self::Api>>,
+        opt_amount: OptionalValue<BigUint<Self::Api>>,
     ) {
         let caller = self.blockchain().get_caller();
+        self.process_vote(caller, proposal_id, vote, voting_power, proof, opt_amount);
+    }
+
+    // a relayer pays the fees of a vote signed by the voter, which uses all of its remaining power
+    #[endpoint]
+    fn delegate_signed_vote(
+        &self,
+        voter: ManagedAddress,
+        proposal_id: ProposalId,
+        vote: ManagedBuffer,
+        voting_power: BigUint<Self::Api>,
+        nonce: u64,
+        proof: ManagedVec<Hash<Self::Api>>,
+        signature: ManagedBuffer,
+    ) {
+        let message = self.get_vote_message(proposal_id, vote.clone(), voting_power.clone(), nonce);
+        self.verify_vote_signature(&voter, &message, &signature);
+        self.use_voter_nonce(&voter, nonce);
+
+        self.process_vote(
+            voter,
+            proposal_id,
+            vote,
+            voting_power,
+            proof,
+            OptionalValue::None,
+        );
+    }
+
+    fn process_vote(
+        &self,
+        voter: ManagedAddress,
+        proposal_id: ProposalId,
+        vote: ManagedBuffer,
+        voting_power: BigUint<Self::Api>,
+        proof: ManagedVec<Hash<Self::Api>>,
+        opt_amount: OptionalValue<BigUint<Self::Api>>,
+    ) {
         require!(!self.liquid_staking_sc().is_empty(), LS_SC_NOT_SET);
-        self.require_caller_not_self(&caller);
-        self.check_caller_has_power(&caller, proposal_id, &voting_power, proof);
+        self.require_caller_not_self(&voter);
+        self.require_voting_open(proposal_id);
+        self.check_caller_has_power(&voter, proposal_id, &voting_power, proof);
+
+        let amount = self.record_vote(&voter, proposal_id, &vote, &voting_power, opt_amount);
+        if self.aggregated_proposal(proposal_id).get() {
+            return;
+        }
 
         let ls_sc_address = self.liquid_staking_sc().get();
         let gas_for_async_call = self.get_gas_for_sync_call();
         self.tx()
             .to(ls_sc_address)
             .typed(liquid_staking_proxy::LiquidStakingProxy)
-            .delegate_vote(proposal_id, vote, caller, voting_power)
+            .delegate_vote(proposal_id, vote, voter, amount)
             .gas(gas_for_async_call)
             .sync_call();
     }
 
+    // a relayer proves the voting power of many voters with a single multi-proof;
+    // they can then vote without a proof of their own, while the root hash stays the same
+    #[endpoint]
+    fn prove_voting_powers(
+        &self,
+        proposal_id: ProposalId,
+        leaf_count: u32,
+        proof: ManagedVec<Hash<Self::Api>>,
+        voters: MultiValueEncoded<MultiValue3<ManagedAddress, BigUint, u32>>,
+    ) {
+        self.require_voting_open(proposal_id);
+        let proposal = self.get_registered_proposal(proposal_id);
+        require!(!proposal.snapshot_voting, NO_MERKLE_VOTING);
+        let root_hash = match self.get_root_hash(proposal_id) {
+            OptionalValue::Some(root_hash) => root_hash,
+            OptionalValue::None => sc_panic!(INVALID_ROOT_HASH),
+        };
+
+        let hash_function = self.proposal_hash_function(proposal_id).get();
+        let mut leaves = ManagedVec::new();
+        for voter in voters.clone().into_iter() {
+            let (address, voting_power, index) = voter.into_tuple();
+            let hash = self.hash_leaf(hash_function, &address, &voting_power);
+            leaves.push(MerkleNode { index, hash });
+        }
+        require!(
+            self.verify_merkle_multi_proof(
+                proposal_id,
+                leaves,
+                leaf_count,
+                proof,
+                root_hash.clone()
+            ),
+            INVALID_MERKLE_PROOF
+        );
+
+        for voter in voters.into_iter() {
+            let (address, voting_power, _) = voter.into_tuple();
+            self.proven_voting_power(proposal_id, &address)
+                .set(ProvenVotingPower {
+                    root_hash: root_hash.clone(),
+                    voting_power,
+                });
+        }
+    }
+
+    fn record_vote(
+        &self,
+        caller: &ManagedAddress,
+        proposal_id: ProposalId,
+        vote: &ManagedBuffer,
+        voting_power: &BigUint,
+        opt_amount: OptionalValue<BigUint>,
+    ) -> BigUint {
+        require!(
+            VOTE_TYPES.iter().any(|vote_type| vote == *vote_type),
+            INVALID_VOTE_TYPE
+        );
+
+        let previous_usage = self.get_vote_usage(proposal_id, caller);
+        let amount = self.get_amount_to_use(&previous_usage, voting_power, opt_amount);
+        let vote_changed = previous_usage.used_power > 0 && &previous_usage.vote_type != vote;
+        let used_power = &previous_usage.used_power + &amount;
+        if previous_usage.used_power == 0 {
+            self.proposal_voters_count(proposal_id)
+                .update(|count| *count += 1);
+        }
+        self.vote_usage(proposal_id, caller).set(VoteUsage {
+            vote_type: vote.clone(),
+            used_power: used_power.clone(),
+        });
+
+        // only aggregated votes can change, the governance contract keeps the forwarded ones
+        if self.aggregated_proposal(proposal_id).get() {
+            if vote_changed {
+                self.move_vote_total(
+                    proposal_id,
+                    &previous_usage.vote_type,
+                    vote,
+                    &previous_usage.used_power,
+                );
+            }
+        } else {
+            require!(!vote_changed, VOTE_CHANGE_NOT_ALLOWED);
+        }
+        self.add_to_vote_total(proposal_id, vote, &amount);
+        self.vote_cast_event(caller, proposal_id, vote, &amount, &used_power);
+
+        amount
+    }
+
+    #[endpoint]
+    fn cast_aggregated_vote(&self, proposal_id: ProposalId) {
+        require!(self.aggregated_proposal(proposal_id).get(), NO_AGGREGATION);
+        require!(self.is_voting_ended(proposal_id), VOTING_NOT_ENDED);
+        require!(
+            !self.aggregated_vote_cast(proposal_id).get(),
+            AGGREGATION_ALREADY_CAST
+        );
+        require!(!self.liquid_staking_sc().is_empty(), LS_SC_NOT_SET);
+
+        let aggregated_vote = self.get_aggregated_vote(proposal_id);
+        require!(aggregated_vote.voting_power > 0, NO_VOTES_TO_CAST);
+
+        self.aggregated_vote_cast(proposal_id).set(true);
+        self.aggregated_vote_cast_event(
+            proposal_id,
+            &aggregated_vote.vote_type,
+            &aggregated_vote.voting_power,
+        );
+
+        let ls_sc_address = self.liquid_staking_sc().get();
+        let gas_for_sync_call = self.get_gas_for_sync_call();
+        self.tx()
+            .to(ls_sc_address)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .cast_aggregated_vote(proposal_id, aggregated_vote.vote_type)
+            .gas(gas_for_sync_call)
+            .sync_call();
+    }
+
     fn check_caller_has_power(
         &self,
         caller: &ManagedAddress,
@@ -68,11 +279,29 @@ pub trait VoteSC:
         voting_power: &BigUint<Self::Api>,
         proof: ManagedVec<ManagedByteArray<HASH_LENGTH>>,
     ) {
+        let proposal = self.get_registered_proposal(proposal_id);
+        if proposal.snapshot_voting {
+            let locked_power = self.get_locked_voting_power(caller, proposal.start_epoch);
+            require!(
+                locked_power > 0 && voting_power == &locked_power,
+                INVALID_VOTING_POWER
+            );
+            return;
+        }
+
         let wrapped_root_hash = self.get_root_hash(proposal_id);
 
         if let OptionalValue::Some(root_hash) = wrapped_root_hash {
+            let proven_mapper = self.proven_voting_power(proposal_id, caller);
+            if !proven_mapper.is_empty() {
+                let proven = proven_mapper.get();
+                if proven.root_hash == root_hash && &proven.voting_power == voting_power {
+                    return;
+                }
+            }
+
             require!(
-                self.verify_merkle_proof(caller, voting_power, proof, root_hash),
+                self.verify_merkle_proof(proposal_id, caller, voting_power, proof, root_hash),
                 INVALID_MERKLE_PROOF
             );
         } else {
@@ -80,6 +309,18 @@ pub trait VoteSC:
         }
     }
 
+    fn get_locked_voting_power(&self, caller: &ManagedAddress, start_epoch: u64) -> BigUint {
+        let ls_sc_address = self.liquid_staking_sc().get();
+        let gas_for_sync_call = self.get_gas_for_sync_call();
+        self.tx()
+            .to(ls_sc_address)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .get_vote_power_at(caller, start_epoch)
+            .gas(gas_for_sync_call)
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
     fn get_gas_for_sync_call(&self) -> u64 {
         let gas_left = self.blockchain().get_gas_left();
         require!(
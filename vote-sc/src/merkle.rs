@@ -0,0 +1,168 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    constants::{Hash, ProposalId},
+    errors::HASH_FUNCTION_LOCKED,
+};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HashFunction {
+    Sha256,
+    Keccak256,
+}
+
+// a leaf of a multi-proof, identified by its position among the tree leaves
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq, Debug,
+)]
+pub struct MerkleNode<M: ManagedTypeApi> {
+    pub index: u32,
+    pub hash: Hash<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProvenVotingPower<M: ManagedTypeApi> {
+    pub root_hash: Hash<M>,
+    pub voting_power: BigUint<M>,
+}
+
+// snapshot trees are built from address ‖ big-endian power leaves, every pair
+// being hashed with the smaller hash first, using the hash function of the proposal
+#[multiversx_sc::module]
+pub trait MerkleModule: crate::proposals::ProposalsModule {
+    #[only_owner]
+    #[endpoint]
+    fn set_proposal_hash_function(&self, proposal_id: ProposalId, hash_function: HashFunction) {
+        require!(!self.is_voting_started(proposal_id), HASH_FUNCTION_LOCKED);
+        self.proposal_hash_function(proposal_id).set(hash_function);
+    }
+
+    fn hash_bytes(&self, hash_function: HashFunction, bytes: &ManagedBuffer) -> Hash<Self::Api> {
+        match hash_function {
+            HashFunction::Sha256 => self.crypto().sha256(bytes),
+            HashFunction::Keccak256 => self.crypto().keccak256(bytes),
+        }
+    }
+
+    fn hash_leaf(
+        &self,
+        hash_function: HashFunction,
+        address: &ManagedAddress,
+        power: &BigUint,
+    ) -> Hash<Self::Api> {
+        let mut leaf_bytes = address.as_managed_buffer().clone();
+        leaf_bytes.append(&power.to_bytes_be_buffer());
+
+        self.hash_bytes(hash_function, &leaf_bytes)
+    }
+
+    fn hash_pair(
+        &self,
+        hash_function: HashFunction,
+        hash: &Hash<Self::Api>,
+        proof_item: &Hash<Self::Api>,
+    ) -> Hash<Self::Api> {
+        let mut pair = ManagedBuffer::new();
+        if BigUint::from(hash.as_managed_buffer()) < BigUint::from(proof_item.as_managed_buffer()) {
+            pair.append(hash.as_managed_buffer());
+            pair.append(proof_item.as_managed_buffer());
+        } else {
+            pair.append(proof_item.as_managed_buffer());
+            pair.append(hash.as_managed_buffer());
+        }
+
+        self.hash_bytes(hash_function, &pair)
+    }
+
+    fn verify_merkle_proof(
+        &self,
+        proposal_id: ProposalId,
+        caller: &ManagedAddress,
+        power: &BigUint,
+        proof: ManagedVec<Hash<Self::Api>>,
+        root_hash: Hash<Self::Api>,
+    ) -> bool {
+        let hash_function = self.proposal_hash_function(proposal_id).get();
+        let mut hash = self.hash_leaf(hash_function, caller, power);
+        for proof_item in proof.iter() {
+            hash = self.hash_pair(hash_function, &hash, &proof_item);
+        }
+
+        hash == root_hash
+    }
+
+    // the leaves must be sorted by index; the tree is rebuilt level by level, a node
+    // without a sibling moving up unchanged, and the missing siblings are taken from
+    // the proof in the order they are needed
+    fn verify_merkle_multi_proof(
+        &self,
+        proposal_id: ProposalId,
+        leaves: ManagedVec<MerkleNode<Self::Api>>,
+        leaf_count: u32,
+        proof: ManagedVec<Hash<Self::Api>>,
+        root_hash: Hash<Self::Api>,
+    ) -> bool {
+        if leaves.is_empty() {
+            return false;
+        }
+        let mut previous_index = None;
+        for leaf in leaves.iter() {
+            if leaf.index >= leaf_count || previous_index.is_some_and(|index| index >= leaf.index) {
+                return false;
+            }
+            previous_index = Some(leaf.index);
+        }
+
+        let hash_function = self.proposal_hash_function(proposal_id).get();
+        let mut nodes = leaves;
+        let mut node_count = leaf_count;
+        let mut proof_index = 0;
+        while node_count > 1 {
+            let mut parents = ManagedVec::<Self::Api, MerkleNode<Self::Api>>::new();
+            let mut node_position = 0;
+            while node_position < nodes.len() {
+                let node = nodes.get(node_position).clone();
+                let hash = if node.index.is_multiple_of(2) && node.index + 1 == node_count {
+                    node.hash
+                } else if node_position + 1 < nodes.len()
+                    && nodes.get(node_position + 1).index == node.index ^ 1
+                {
+                    node_position += 1;
+                    self.hash_pair(hash_function, &node.hash, &nodes.get(node_position).hash)
+                } else {
+                    if proof_index == proof.len() {
+                        return false;
+                    }
+                    proof_index += 1;
+                    self.hash_pair(hash_function, &node.hash, &proof.get(proof_index - 1))
+                };
+                parents.push(MerkleNode {
+                    index: node.index / 2,
+                    hash,
+                });
+                node_position += 1;
+            }
+            nodes = parents;
+            node_count = node_count.div_ceil(2);
+        }
+
+        proof_index == proof.len() && nodes.get(0).hash == root_hash
+    }
+
+    // unset for a proposal means sha256, the first variant
+    #[view(getProposalHashFunction)]
+    #[storage_mapper("proposalHashFunction")]
+    fn proposal_hash_function(&self, proposal_id: ProposalId) -> SingleValueMapper<HashFunction>;
+
+    #[view(getProvenVotingPower)]
+    #[storage_mapper("provenVotingPower")]
+    fn proven_voting_power(
+        &self,
+        proposal_id: ProposalId,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<ProvenVotingPower<Self::Api>>;
+}
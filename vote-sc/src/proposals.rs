@@ -0,0 +1,97 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    constants::ProposalId,
+    errors::{INVALID_VOTING_WINDOW, NO_PROPOSAL, PROPOSAL_ALREADY_STARTED, VOTING_NOT_OPEN},
+};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Proposal {
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+    pub snapshot_voting: bool,
+}
+
+// votes are only accepted in the [start_epoch, end_epoch) window of a registered proposal
+#[multiversx_sc::module]
+pub trait ProposalsModule {
+    // proposals with snapshot voting are voted with the lsEGLD locked in the liquid staking
+    // contract before the start epoch, instead of a merkle proof
+    #[only_owner]
+    #[endpoint]
+    fn register_proposal(
+        &self,
+        proposal_id: ProposalId,
+        start_epoch: u64,
+        end_epoch: u64,
+        snapshot_voting: bool,
+    ) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            start_epoch < end_epoch && current_epoch < end_epoch,
+            INVALID_VOTING_WINDOW
+        );
+        require!(
+            !self.is_voting_started(proposal_id),
+            PROPOSAL_ALREADY_STARTED
+        );
+
+        self.proposal(proposal_id).set(Proposal {
+            start_epoch,
+            end_epoch,
+            snapshot_voting,
+        });
+        self.proposals().insert(proposal_id);
+    }
+
+    fn get_registered_proposal(&self, proposal_id: ProposalId) -> Proposal {
+        let proposal_mapper = self.proposal(proposal_id);
+        require!(!proposal_mapper.is_empty(), NO_PROPOSAL);
+
+        proposal_mapper.get()
+    }
+
+    fn is_voting_started(&self, proposal_id: ProposalId) -> bool {
+        let proposal_mapper = self.proposal(proposal_id);
+        !proposal_mapper.is_empty()
+            && self.blockchain().get_block_epoch() >= proposal_mapper.get().start_epoch
+    }
+
+    fn is_voting_ended(&self, proposal_id: ProposalId) -> bool {
+        let proposal = self.get_registered_proposal(proposal_id);
+        self.blockchain().get_block_epoch() >= proposal.end_epoch
+    }
+
+    fn require_voting_open(&self, proposal_id: ProposalId) {
+        let proposal = self.get_registered_proposal(proposal_id);
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            current_epoch >= proposal.start_epoch && current_epoch < proposal.end_epoch,
+            VOTING_NOT_OPEN
+        );
+    }
+
+    #[view(getActiveProposals)]
+    fn get_active_proposals(&self) -> MultiValueEncoded<ProposalId> {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut active_proposals = MultiValueEncoded::new();
+        for proposal_id in self.proposals().iter() {
+            let proposal = self.proposal(proposal_id).get();
+            if current_epoch >= proposal.start_epoch && current_epoch < proposal.end_epoch {
+                active_proposals.push(proposal_id);
+            }
+        }
+
+        active_proposals
+    }
+
+    #[view(getProposal)]
+    #[storage_mapper("proposal")]
+    fn proposal(&self, proposal_id: ProposalId) -> SingleValueMapper<Proposal>;
+
+    #[view(getProposals)]
+    #[storage_mapper("proposals")]
+    fn proposals(&self) -> UnorderedSetMapper<ProposalId>;
+}
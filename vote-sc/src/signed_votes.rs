@@ -0,0 +1,71 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    constants::{ProposalId, SIGNED_MESSAGE_PREFIX},
+    errors::INVALID_VOTE_NONCE,
+};
+
+// what a voter signs so that a relayer can delegate the vote in its place;
+// the contract address keeps the signature from being replayed on another vote contract
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct VoteMessage<M: ManagedTypeApi> {
+    pub contract: ManagedAddress<M>,
+    pub proposal_id: ProposalId,
+    pub vote: ManagedBuffer<M>,
+    pub voting_power: BigUint<M>,
+    pub nonce: u64,
+}
+
+#[multiversx_sc::module]
+pub trait SignedVotesModule {
+    #[view(getVoteMessage)]
+    fn get_vote_message(
+        &self,
+        proposal_id: ProposalId,
+        vote: ManagedBuffer,
+        voting_power: BigUint,
+        nonce: u64,
+    ) -> ManagedBuffer {
+        self.serializer()
+            .top_encode_to_managed_buffer(&VoteMessage {
+                contract: self.blockchain().get_sc_address(),
+                proposal_id,
+                vote,
+                voting_power,
+                nonce,
+            })
+    }
+
+    // signatures follow the wallet signed message format: the voter key signs
+    // keccak256(prefix ‖ message length in decimal ‖ message); an invalid signature fails the call
+    fn verify_vote_signature(
+        &self,
+        voter: &ManagedAddress,
+        message: &ManagedBuffer,
+        signature: &ManagedBuffer,
+    ) {
+        let mut signable_message = ManagedBuffer::from(SIGNED_MESSAGE_PREFIX);
+        signable_message.append(&BigUint::from(message.len()).to_display());
+        signable_message.append(message);
+        let message_hash = self.crypto().keccak256(&signable_message);
+
+        self.crypto().verify_ed25519(
+            voter.as_managed_buffer(),
+            message_hash.as_managed_buffer(),
+            signature,
+        );
+    }
+
+    // each signed vote uses the current nonce of the voter, so it can only be relayed once
+    fn use_voter_nonce(&self, voter: &ManagedAddress, nonce: u64) {
+        let nonce_mapper = self.voter_nonce(voter);
+        require!(nonce == nonce_mapper.get(), INVALID_VOTE_NONCE);
+        nonce_mapper.set(nonce + 1);
+    }
+
+    #[view(getVoterNonce)]
+    #[storage_mapper("voterNonce")]
+    fn voter_nonce(&self, voter: &ManagedAddress) -> SingleValueMapper<u64>;
+}
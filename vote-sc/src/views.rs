@@ -1,12 +1,22 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
 use crate::{
-    constants::{Hash, ProposalId, HASH_LENGTH},
+    constants::{Hash, ProposalId, DEFAULT_ROOT_PROPOSAL_ID, HASH_LENGTH, VOTE_TYPES},
     errors::NO_PROPOSAL,
 };
 
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq, Debug,
+)]
+pub struct VoteTally<M: ManagedTypeApi> {
+    pub vote_type: ManagedBuffer<M>,
+    pub voting_power: BigUint<M>,
+}
+
 #[multiversx_sc::module]
-pub trait ViewsModule {
+pub trait ViewsModule: crate::merkle::MerkleModule + crate::proposals::ProposalsModule {
     #[view(getProposalRootHash)]
     fn get_root_hash(
         &self,
@@ -15,9 +25,9 @@ pub trait ViewsModule {
         let mut id_to_check = proposal_id;
         if self.proposal_root_hash(proposal_id).is_empty() {
             // check if proposal does not exist
-            if proposal_id != 0 {
+            if proposal_id != DEFAULT_ROOT_PROPOSAL_ID && self.is_root_hash_fallback_enabled() {
                 // check inexistent proposal is not default
-                id_to_check = 0;
+                id_to_check = DEFAULT_ROOT_PROPOSAL_ID;
 
                 if self.proposal_root_hash(id_to_check).is_empty() {
                     // check default proposal is set
@@ -44,43 +54,74 @@ pub trait ViewsModule {
             }
             OptionalValue::Some(root_hash) => {
                 let caller = self.blockchain().get_caller();
-                self.verify_merkle_proof(&caller, &voting_power, proof, root_hash)
+                self.verify_merkle_proof(proposal_id, &caller, &voting_power, proof, root_hash)
             }
         }
     }
 
-    fn verify_merkle_proof(
+    fn add_to_vote_total(
         &self,
-        caller: &ManagedAddress,
-        power: &BigUint<Self::Api>,
-        proof: ManagedVec<ManagedByteArray<HASH_LENGTH>>,
-        root_hash: ManagedByteArray<HASH_LENGTH>,
-    ) -> bool {
-        let mut leaf_bytes = caller.as_managed_buffer().clone();
+        proposal_id: ProposalId,
+        vote_type: &ManagedBuffer,
+        voting_power: &BigUint,
+    ) {
+        self.proposal_vote_total(proposal_id, vote_type)
+            .update(|total| *total += voting_power);
+    }
+
+    // a vote change moves the power the voter already used to the new vote type
+    fn move_vote_total(
+        &self,
+        proposal_id: ProposalId,
+        old_vote_type: &ManagedBuffer,
+        new_vote_type: &ManagedBuffer,
+        voting_power: &BigUint,
+    ) {
+        self.proposal_vote_total(proposal_id, old_vote_type)
+            .update(|total| *total -= voting_power);
+        self.add_to_vote_total(proposal_id, new_vote_type, voting_power);
+    }
 
-        let p = power.to_bytes_be_buffer();
-        leaf_bytes.append(&p);
+    #[view(getProposalVoteTotals)]
+    fn get_proposal_vote_totals(
+        &self,
+        proposal_id: ProposalId,
+    ) -> ManagedVec<VoteTally<Self::Api>> {
+        let mut tallies = ManagedVec::new();
+        for vote_type in VOTE_TYPES.iter() {
+            let vote_type = ManagedBuffer::from(*vote_type);
+            let voting_power = self.proposal_vote_total(proposal_id, &vote_type).get();
+            tallies.push(VoteTally {
+                vote_type,
+                voting_power,
+            });
+        }
 
-        let mut hash = self.crypto().sha256(&leaf_bytes);
-        for proof_item in proof {
-            if BigUint::from(hash.as_managed_buffer())
-                < BigUint::from(proof_item.as_managed_buffer())
-            {
-                let mut tst = hash.as_managed_buffer().clone();
-                tst.append(proof_item.as_managed_buffer());
+        tallies
+    }
 
-                hash = self.crypto().sha256(tst);
-            } else {
-                let mut tst = proof_item.as_managed_buffer().clone();
-                tst.append(hash.as_managed_buffer());
+    #[view(getProposalVoteTotal)]
+    #[storage_mapper("proposalVoteTotal")]
+    fn proposal_vote_total(
+        &self,
+        proposal_id: ProposalId,
+        vote_type: &ManagedBuffer,
+    ) -> SingleValueMapper<BigUint>;
 
-                hash = self.crypto().sha256(tst);
-            }
-        }
+    #[view(getProposalVotersCount)]
+    #[storage_mapper("proposalVotersCount")]
+    fn proposal_voters_count(&self, proposal_id: ProposalId) -> SingleValueMapper<usize>;
 
-        hash == root_hash
+    // the root hash of proposal 0 is used for other proposals unless the owner disabled it
+    #[view(isRootHashFallbackEnabled)]
+    fn is_root_hash_fallback_enabled(&self) -> bool {
+        !self.root_hash_fallback_disabled().get()
     }
 
+    // stored negated, so that the fallback stays enabled on contracts upgraded from before the flag
+    #[storage_mapper("rootHashFallbackDisabled")]
+    fn root_hash_fallback_disabled(&self) -> SingleValueMapper<bool>;
+
     #[storage_mapper("proposalRootHash")]
     fn proposal_root_hash(&self, proposal_id: ProposalId) -> SingleValueMapper<Hash<Self::Api>>;
 }
@@ -0,0 +1,560 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct VoteSCProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for VoteSCProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = VoteSCProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        VoteSCProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct VoteSCProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> VoteSCProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> VoteSCProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> VoteSCProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn set_root_hash<
+        Arg0: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        root_hash: Arg0,
+        proposal_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_root_hash")
+            .argument(&root_hash)
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn set_root_hash_fallback<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_root_hash_fallback")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    pub fn set_liquid_staking_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_liquid_staking_address")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn delegate_vote<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<ManagedVec<Env::Api, ManagedByteArray<Env::Api, 32usize>>>,
+        Arg4: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        vote: Arg1,
+        voting_power: Arg2,
+        proof: Arg3,
+        opt_amount: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("delegate_vote")
+            .argument(&proposal_id)
+            .argument(&vote)
+            .argument(&voting_power)
+            .argument(&proof)
+            .argument(&opt_amount)
+            .original_result()
+    }
+
+    pub fn delegate_signed_vote<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<ManagedVec<Env::Api, ManagedByteArray<Env::Api, 32usize>>>,
+        Arg6: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        voter: Arg0,
+        proposal_id: Arg1,
+        vote: Arg2,
+        voting_power: Arg3,
+        nonce: Arg4,
+        proof: Arg5,
+        signature: Arg6,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("delegate_signed_vote")
+            .argument(&voter)
+            .argument(&proposal_id)
+            .argument(&vote)
+            .argument(&voting_power)
+            .argument(&nonce)
+            .argument(&proof)
+            .argument(&signature)
+            .original_result()
+    }
+
+    pub fn prove_voting_powers<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<ManagedVec<Env::Api, ManagedByteArray<Env::Api, 32usize>>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<ManagedAddress<Env::Api>, BigUint<Env::Api>, u32>>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        leaf_count: Arg1,
+        proof: Arg2,
+        voters: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("prove_voting_powers")
+            .argument(&proposal_id)
+            .argument(&leaf_count)
+            .argument(&proof)
+            .argument(&voters)
+            .original_result()
+    }
+
+    pub fn cast_aggregated_vote<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cast_aggregated_vote")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn liquid_staking_sc(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidStakingAddress")
+            .original_result()
+    }
+
+    pub fn set_proposal_aggregation<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_proposal_aggregation")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn aggregated_proposal<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAggregatedProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn aggregated_vote_cast<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAggregatedVoteCast")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn set_proposal_hash_function<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<HashFunction>,
+    >(
+        self,
+        proposal_id: Arg0,
+        hash_function: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_proposal_hash_function")
+            .argument(&proposal_id)
+            .argument(&hash_function)
+            .original_result()
+    }
+
+    pub fn proposal_hash_function<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, HashFunction> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalHashFunction")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn proven_voting_power<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProvenVotingPower<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProvenVotingPower")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn register_proposal<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<bool>,
+    >(
+        self,
+        proposal_id: Arg0,
+        start_epoch: Arg1,
+        end_epoch: Arg2,
+        snapshot_voting: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("register_proposal")
+            .argument(&proposal_id)
+            .argument(&start_epoch)
+            .argument(&end_epoch)
+            .argument(&snapshot_voting)
+            .original_result()
+    }
+
+    pub fn get_active_proposals(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u32>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActiveProposals")
+            .original_result()
+    }
+
+    pub fn proposal<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Proposal> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn proposals(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u32>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposals")
+            .original_result()
+    }
+
+    pub fn get_vote_message<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+        vote: Arg1,
+        voting_power: Arg2,
+        nonce: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoteMessage")
+            .argument(&proposal_id)
+            .argument(&vote)
+            .argument(&voting_power)
+            .argument(&nonce)
+            .original_result()
+    }
+
+    pub fn voter_nonce<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        voter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoterNonce")
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn get_root_hash<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalRootHash")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn confirm_voting_power<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedVec<Env::Api, ManagedByteArray<Env::Api, 32usize>>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voting_power: Arg1,
+        proof: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("confirmVotingPower")
+            .argument(&proposal_id)
+            .argument(&voting_power)
+            .argument(&proof)
+            .original_result()
+    }
+
+    pub fn get_proposal_vote_totals<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, VoteTally<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalVoteTotals")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn proposal_vote_total<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        vote_type: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalVoteTotal")
+            .argument(&proposal_id)
+            .argument(&vote_type)
+            .original_result()
+    }
+
+    pub fn proposal_voters_count<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalVotersCount")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn is_root_hash_fallback_enabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRootHashFallbackEnabled")
+            .original_result()
+    }
+
+    pub fn get_vote_usage<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, VoteUsage<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoteUsage")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn get_remaining_voting_power<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+        voting_power: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRemainingVotingPower")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .argument(&voting_power)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HashFunction {
+    Sha256,
+    Keccak256,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct ProvenVotingPower<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub root_hash: ManagedByteArray<Api, 32usize>,
+    pub voting_power: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct Proposal {
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+    pub snapshot_voting: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq, Debug)]
+pub struct VoteTally<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub vote_type: ManagedBuffer<Api>,
+    pub voting_power: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct VoteUsage<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub vote_type: ManagedBuffer<Api>,
+    pub used_power: BigUint<Api>,
+}
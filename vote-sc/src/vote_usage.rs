@@ -0,0 +1,81 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{constants::ProposalId, errors::INSUFFICIENT_VOTING_POWER};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct VoteUsage<M: ManagedTypeApi> {
+    pub vote_type: ManagedBuffer<M>,
+    pub used_power: BigUint<M>,
+}
+
+// the power a voter already used on a proposal, so the same proof cannot be used twice
+#[multiversx_sc::module]
+pub trait VoteUsageModule {
+    fn get_amount_to_use(
+        &self,
+        vote_usage: &VoteUsage<Self::Api>,
+        voting_power: &BigUint,
+        opt_amount: OptionalValue<BigUint>,
+    ) -> BigUint {
+        let remaining_power = self.compute_remaining_power(vote_usage, voting_power);
+        let amount = match opt_amount {
+            OptionalValue::Some(amount) => amount,
+            OptionalValue::None => remaining_power.clone(),
+        };
+        require!(
+            amount > 0 && amount <= remaining_power,
+            INSUFFICIENT_VOTING_POWER
+        );
+
+        amount
+    }
+
+    fn compute_remaining_power(
+        &self,
+        vote_usage: &VoteUsage<Self::Api>,
+        voting_power: &BigUint,
+    ) -> BigUint {
+        if &vote_usage.used_power >= voting_power {
+            return BigUint::zero();
+        }
+
+        voting_power - &vote_usage.used_power
+    }
+
+    #[view(getVoteUsage)]
+    fn get_vote_usage(
+        &self,
+        proposal_id: ProposalId,
+        voter: &ManagedAddress,
+    ) -> VoteUsage<Self::Api> {
+        let vote_usage_mapper = self.vote_usage(proposal_id, voter);
+        if vote_usage_mapper.is_empty() {
+            return VoteUsage {
+                vote_type: ManagedBuffer::new(),
+                used_power: BigUint::zero(),
+            };
+        }
+
+        vote_usage_mapper.get()
+    }
+
+    #[view(getRemainingVotingPower)]
+    fn get_remaining_voting_power(
+        &self,
+        proposal_id: ProposalId,
+        voter: ManagedAddress,
+        voting_power: BigUint,
+    ) -> BigUint {
+        let vote_usage = self.get_vote_usage(proposal_id, &voter);
+        self.compute_remaining_power(&vote_usage, &voting_power)
+    }
+
+    #[storage_mapper("voteUsage")]
+    fn vote_usage(
+        &self,
+        proposal_id: ProposalId,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<VoteUsage<Self::Api>>;
+}
@@ -0,0 +1,365 @@
+mod vote_setup;
+
+use merkle_tool::HashFunction;
+use vote_setup::*;
+
+#[test]
+fn vote_full_power_test() {
+    let mut state = VoteTestState::new(HashFunction::Sha256);
+    state.set_block_epoch(START_EPOCH);
+
+    state.delegate_vote(FIRST_VOTER_ADDRESS, "yes", None);
+    state.check_vote_usage(FIRST_VOTER_ADDRESS, "yes", 10);
+    state.check_remaining_voting_power(FIRST_VOTER_ADDRESS, 0);
+    state.check_governance_vote(FIRST_VOTER_ADDRESS, "yes", 10);
+    state.check_governance_tally("yes", 10);
+    state.check_delegated_voting_power(10);
+}
+
+#[test]
+fn vote_partial_power_test() {
+    let mut state = VoteTestState::new(HashFunction::Sha256);
+    state.set_block_epoch(START_EPOCH);
+
+    state.delegate_vote(SECOND_VOTER_ADDRESS, "no", Some(5));
+    state.check_vote_usage(SECOND_VOTER_ADDRESS, "no", 5);
+    state.check_remaining_voting_power(SECOND_VOTER_ADDRESS, 15);
+    state.check_governance_vote(SECOND_VOTER_ADDRESS, "no", 5);
+
+    // the second vote reaches the governance contract as another delegated vote
+    // for the same delegate, which adds to the power it already recorded
+    state.delegate_vote(SECOND_VOTER_ADDRESS, "no", None);
+    state.check_vote_usage(SECOND_VOTER_ADDRESS, "no", 20);
+    state.check_remaining_voting_power(SECOND_VOTER_ADDRESS, 0);
+    state.check_governance_vote(SECOND_VOTER_ADDRESS, "no", 20);
+    state.check_governance_tally("no", 20);
+    state.check_delegated_voting_power(20);
+
+    // the forwarded vote type cannot change
+    state.delegate_vote(THIRD_VOTER_ADDRESS, "yes", Some(10));
+    state.delegate_vote_check_error(
+        THIRD_VOTER_ADDRESS,
+        "no",
+        Some(10),
+        "Vote type cannot be changed for this proposal",
+    );
+    state.check_governance_vote(THIRD_VOTER_ADDRESS, "yes", 10);
+}
+
+#[test]
+fn vote_over_power_test() {
+    let mut state = VoteTestState::new(HashFunction::Sha256);
+    state.set_block_epoch(START_EPOCH);
+
+    state.delegate_vote_check_error(
+        FIRST_VOTER_ADDRESS,
+        "yes",
+        Some(11),
+        "Insufficient remaining voting power",
+    );
+
+    state.delegate_vote(FIRST_VOTER_ADDRESS, "yes", Some(4));
+    state.delegate_vote_check_error(
+        FIRST_VOTER_ADDRESS,
+        "yes",
+        Some(7),
+        "Insufficient remaining voting power",
+    );
+    state.check_vote_usage(FIRST_VOTER_ADDRESS, "yes", 4);
+    state.check_governance_tally("yes", 4);
+}
+
+#[test]
+fn vote_repeated_test() {
+    let mut state = VoteTestState::new(HashFunction::Sha256);
+    state.set_block_epoch(START_EPOCH);
+
+    state.delegate_vote(THIRD_VOTER_ADDRESS, "abstain", None);
+    state.delegate_vote_check_error(
+        THIRD_VOTER_ADDRESS,
+        "abstain",
+        None,
+        "Insufficient remaining voting power",
+    );
+    state.check_vote_usage(THIRD_VOTER_ADDRESS, "abstain", 30);
+    state.check_governance_tally("abstain", 30);
+    state.check_delegated_voting_power(30);
+}
+
+#[test]
+fn vote_cast_event_and_tallies_test() {
+    let mut state = VoteTestState::new(HashFunction::Sha256);
+    state.set_block_epoch(START_EPOCH);
+
+    let logs = state.delegate_vote(FIRST_VOTER_ADDRESS, "yes", None);
+    check_vote_cast_log(&logs, FIRST_VOTER_ADDRESS, "yes", 10, 10);
+    let logs = state.delegate_vote(SECOND_VOTER_ADDRESS, "no", Some(5));
+    check_vote_cast_log(&logs, SECOND_VOTER_ADDRESS, "no", 5, 5);
+    let logs = state.delegate_vote(THIRD_VOTER_ADDRESS, "yes", Some(30));
+    check_vote_cast_log(&logs, THIRD_VOTER_ADDRESS, "yes", 30, 30);
+
+    // the last topic is the total power the voter used on the proposal
+    let logs = state.delegate_vote(SECOND_VOTER_ADDRESS, "no", Some(15));
+    check_vote_cast_log(&logs, SECOND_VOTER_ADDRESS, "no", 15, 20);
+
+    state.check_vote_totals([40, 20, 0, 0]);
+    state.check_vote_total("yes", 40);
+    state.check_vote_total("veto", 0);
+    state.check_voters_count(3);
+    state.check_vote_usage(FIRST_VOTER_ADDRESS, "yes", 10);
+    state.check_vote_usage(SECOND_VOTER_ADDRESS, "no", 20);
+    state.check_vote_usage(THIRD_VOTER_ADDRESS, "yes", 30);
+    state.check_governance_tally("yes", 40);
+    state.check_governance_tally("no", 20);
+
+    // a rejected vote emits nothing and changes no tally
+    state.delegate_vote_check_error(FIRST_VOTER_ADDRESS, "maybe", None, "Invalid vote type");
+    state.check_vote_totals([40, 20, 0, 0]);
+    state.check_voters_count(3);
+}
+
+#[test]
+fn vote_window_test() {
+    let mut state = VoteTestState::new(HashFunction::Sha256);
+
+    state.set_block_epoch(START_EPOCH - 1);
+    state.delegate_vote_check_error(
+        FIRST_VOTER_ADDRESS,
+        "yes",
+        None,
+        "Voting is not open for this proposal",
+    );
+
+    state.set_block_epoch(END_EPOCH);
+    state.delegate_vote_check_error(
+        FIRST_VOTER_ADDRESS,
+        "yes",
+        None,
+        "Voting is not open for this proposal",
+    );
+
+    state.set_block_epoch(END_EPOCH - 1);
+    state.delegate_vote(FIRST_VOTER_ADDRESS, "yes", None);
+    state.check_vote_usage(FIRST_VOTER_ADDRESS, "yes", 10);
+}
+
+#[test]
+fn root_hash_lock_test() {
+    let mut state = VoteTestState::new(HashFunction::Sha256);
+    let root_hash = state.tree.root();
+    let default_root_hash = [2u8; 32];
+
+    // before the start epoch the root can be replaced
+    state.set_root_hash(PROPOSAL_ID, &[1u8; 32]);
+    state.set_root_hash(PROPOSAL_ID, &root_hash);
+    state.check_root_hash(PROPOSAL_ID, Some(root_hash));
+
+    // only registered proposals and the default proposal 0 get a root
+    state.set_root_hash_check_error(7, &root_hash, "Proposal does not exist");
+    state.set_root_hash(0, &default_root_hash);
+    state.check_root_hash_fallback_enabled(true);
+
+    state.set_block_epoch(START_EPOCH);
+    state.set_root_hash_check_error(
+        PROPOSAL_ID,
+        &[1u8; 32],
+        "Root hash cannot change after voting started",
+    );
+
+    // a proposal registered after its start epoch falls back to the default root,
+    // which then locks its own root
+    state.set_block_epoch(START_EPOCH + 1);
+    state.register_proposal(2, START_EPOCH, END_EPOCH);
+    state.check_root_hash(2, Some(default_root_hash));
+    state.set_root_hash_check_error(
+        2,
+        &root_hash,
+        "Root hash cannot change after voting started",
+    );
+
+    // without the fallback it can still get a first root, which is then locked
+    state.set_root_hash_fallback(false);
+    state.check_root_hash_fallback_enabled(false);
+    state.check_root_hash(2, None);
+    state.set_root_hash(2, &root_hash);
+    state.check_root_hash(2, Some(root_hash));
+    state.set_root_hash_check_error(
+        2,
+        &[1u8; 32],
+        "Root hash cannot change after voting started",
+    );
+}
+
+#[test]
+fn vote_keccak_proof_test() {
+    let mut state = VoteTestState::new(HashFunction::Keccak256);
+    state.set_block_epoch(START_EPOCH);
+
+    state.delegate_vote(FIFTH_VOTER_ADDRESS, "veto", None);
+    state.check_vote_usage(FIFTH_VOTER_ADDRESS, "veto", 50);
+
+    // a sha256 proof of the same snapshot does not match the keccak256 root
+    let sha256_state = VoteTestState::new(HashFunction::Sha256);
+    let sha256_proof = sha256_state.voter_proof(FIRST_VOTER_ADDRESS);
+    state.delegate_vote_with_proof_check_error(
+        FIRST_VOTER_ADDRESS,
+        "yes",
+        None,
+        &sha256_proof,
+        "Invalid merkle proof provided",
+    );
+}
+
+#[test]
+fn prove_voting_powers_test() {
+    for hash_function in [HashFunction::Sha256, HashFunction::Keccak256] {
+        let mut state = VoteTestState::new(hash_function);
+        state.set_block_epoch(START_EPOCH);
+
+        let leaves = state.voter_leaves(&[FOURTH_VOTER_ADDRESS, FIRST_VOTER_ADDRESS]);
+        let indices = leaves.iter().map(|leaf| leaf.2).collect::<Vec<_>>();
+        let proof = state.tree.multi_proof(&indices);
+        assert_eq!(proof.len(), 3);
+
+        // a tampered leaf changes the rebuilt root
+        let mut tampered_leaves = leaves.clone();
+        tampered_leaves[0].1 += 1;
+        state.prove_voting_powers_check_error(
+            &tampered_leaves,
+            &proof,
+            "Invalid merkle proof provided",
+        );
+
+        // the proof items are used in the order the verification needs them
+        let mut reordered_proof = proof.clone();
+        reordered_proof.swap(0, 1);
+        state.prove_voting_powers_check_error(
+            &leaves,
+            &reordered_proof,
+            "Invalid merkle proof provided",
+        );
+
+        // the leaves must be sorted by their index
+        let mut unsorted_leaves = leaves.clone();
+        unsorted_leaves.reverse();
+        state.prove_voting_powers_check_error(
+            &unsorted_leaves,
+            &proof,
+            "Invalid merkle proof provided",
+        );
+
+        state.prove_voting_powers(&leaves, &proof);
+        state.check_proven_voting_power(FIRST_VOTER_ADDRESS, 10);
+        state.check_proven_voting_power(FOURTH_VOTER_ADDRESS, 40);
+
+        // the proven voters vote without a proof of their own, the others still need one
+        state.delegate_vote_with_proof(FIRST_VOTER_ADDRESS, "yes", None, &[]);
+        state.delegate_vote_with_proof(FOURTH_VOTER_ADDRESS, "no", Some(15), &[]);
+        state.delegate_vote_with_proof_check_error(
+            SECOND_VOTER_ADDRESS,
+            "yes",
+            None,
+            &[],
+            "Invalid merkle proof provided",
+        );
+        state.check_vote_usage(FIRST_VOTER_ADDRESS, "yes", 10);
+        state.check_vote_usage(FOURTH_VOTER_ADDRESS, "no", 15);
+        state.check_governance_tally("yes", 10);
+        state.check_governance_tally("no", 15);
+    }
+}
+
+#[test]
+fn signed_vote_test() {
+    let mut state = VoteTestState::new(HashFunction::Sha256);
+    state.set_block_epoch(START_EPOCH);
+    let signature_error = (10, "ed25519 verify error");
+
+    // a signature of another key
+    let signature = state.sign_vote(&[8u8; 32], PROPOSAL_ID, "yes", SIGNER_POWER, 0);
+    state.relay_signed_vote_check_error("yes", SIGNER_POWER, 0, &signature, signature_error);
+
+    // a signature of another proposal, vote type or voting power
+    let signature = state.sign_vote(&SIGNER_SECRET_KEY, PROPOSAL_ID + 1, "yes", SIGNER_POWER, 0);
+    state.relay_signed_vote_check_error("yes", SIGNER_POWER, 0, &signature, signature_error);
+    let signature = state.sign_vote(&SIGNER_SECRET_KEY, PROPOSAL_ID, "no", SIGNER_POWER, 0);
+    state.relay_signed_vote_check_error("yes", SIGNER_POWER, 0, &signature, signature_error);
+    let signature = state.sign_vote(&SIGNER_SECRET_KEY, PROPOSAL_ID, "yes", SIGNER_POWER - 1, 0);
+    state.relay_signed_vote_check_error("yes", SIGNER_POWER, 0, &signature, signature_error);
+    state.check_voter_nonce(0);
+
+    let signature = state.sign_vote(&SIGNER_SECRET_KEY, PROPOSAL_ID, "yes", SIGNER_POWER, 0);
+    state.relay_signed_vote("yes", SIGNER_POWER, 0, &signature);
+    state.check_voter_nonce(1);
+    state.check_vote_usage(signer_address(), "yes", SIGNER_POWER);
+    state.check_governance_vote(signer_address(), "yes", SIGNER_POWER);
+
+    // the same signed vote cannot be relayed twice
+    state.relay_signed_vote_check_error(
+        "yes",
+        SIGNER_POWER,
+        0,
+        &signature,
+        (4, "Invalid vote nonce"),
+    );
+    state.check_voter_nonce(1);
+}
+
+#[test]
+fn aggregated_vote_test() {
+    let mut state = VoteTestState::new(HashFunction::Sha256);
+    state.set_proposal_aggregation(PROPOSAL_ID);
+    state.set_block_epoch(START_EPOCH);
+    state.set_proposal_aggregation_check_error(PROPOSAL_ID, "Proposal voting has already started");
+
+    // the votes are only tallied, nothing is delegated to the governance contract
+    state.delegate_vote(FIRST_VOTER_ADDRESS, "yes", None);
+    state.delegate_vote(SECOND_VOTER_ADDRESS, "no", None);
+    state.delegate_vote(THIRD_VOTER_ADDRESS, "yes", Some(15));
+    state.delegate_vote(FOURTH_VOTER_ADDRESS, "veto", Some(5));
+    state.check_vote_totals([25, 20, 0, 5]);
+    state.check_governance_tally("yes", 0);
+    state.check_delegated_voting_power(0);
+
+    state.cast_aggregated_vote_check_error("Voting has not ended yet");
+    state.set_block_epoch(END_EPOCH);
+
+    // a single vote for the winning type, with the summed power of all the tallies
+    let logs = state.cast_aggregated_vote();
+    check_aggregated_vote_cast_logs(&logs, "yes", 50, PROTOCOL_STAKE);
+    state.check_aggregated_vote_cast(true);
+    state.cast_aggregated_vote_check_error("Aggregated vote was already cast");
+}
+
+#[test]
+fn aggregated_vote_double_count_test() {
+    let mut state = VoteTestState::new(HashFunction::Sha256);
+    state.set_proposal_aggregation(PROPOSAL_ID);
+    state.set_block_epoch(START_EPOCH);
+
+    // a voter who already voted cannot add their power again
+    state.delegate_vote(FIRST_VOTER_ADDRESS, "yes", None);
+    state.delegate_vote_check_error(
+        FIRST_VOTER_ADDRESS,
+        "yes",
+        None,
+        "Insufficient remaining voting power",
+    );
+    state.delegate_vote_check_error(
+        FIRST_VOTER_ADDRESS,
+        "no",
+        None,
+        "Insufficient remaining voting power",
+    );
+
+    // a changed vote moves the power already used instead of counting it twice
+    state.delegate_vote(SECOND_VOTER_ADDRESS, "no", Some(5));
+    state.delegate_vote(SECOND_VOTER_ADDRESS, "yes", Some(5));
+    state.check_vote_usage(SECOND_VOTER_ADDRESS, "yes", 10);
+    state.check_vote_totals([20, 0, 0, 0]);
+    state.check_voters_count(2);
+
+    state.set_block_epoch(END_EPOCH);
+    let logs = state.cast_aggregated_vote();
+    check_aggregated_vote_cast_logs(&logs, "yes", 20, PROTOCOL_STAKE);
+}
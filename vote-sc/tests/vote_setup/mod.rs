@@ -0,0 +1,828 @@
+use ed25519_dalek::{Signer, SigningKey};
+use governance_mock::governance_mock_proxy;
+use liquid_staking::{
+    liquid_staking_proxy,
+    setup::{config::ConfigModule, parameters::ParametersModule},
+};
+use merkle_tool::{HashFunction, MerkleTree, SnapshotEntry};
+use multiversx_sc_scenario::{imports::*, scenario_model::Log};
+use vote_sc::vote_sc_proxy;
+
+pub const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+pub const FIRST_VOTER_ADDRESS: TestAddress = TestAddress::new("first-voter");
+pub const SECOND_VOTER_ADDRESS: TestAddress = TestAddress::new("second-voter");
+pub const THIRD_VOTER_ADDRESS: TestAddress = TestAddress::new("third-voter");
+pub const FOURTH_VOTER_ADDRESS: TestAddress = TestAddress::new("fourth-voter");
+pub const FIFTH_VOTER_ADDRESS: TestAddress = TestAddress::new("fifth-voter");
+pub const RELAYER_ADDRESS: TestAddress = TestAddress::new("relayer");
+pub const VOTE_SC_ADDRESS: TestSCAddress = TestSCAddress::new("vote-sc");
+pub const LIQUID_STAKING_ADDRESS: TestSCAddress = TestSCAddress::new("liquid-staking");
+
+pub const VOTE_SC_CODE_PATH: MxscPath = MxscPath::new("output/vote-sc.mxsc.json");
+pub const LIQUID_STAKING_CODE_PATH: MxscPath =
+    MxscPath::new("../liquid-staking/output/liquid-staking.mxsc.json");
+pub const GOVERNANCE_MOCK_CODE_PATH: MxscPath =
+    MxscPath::new("../governance-mock/output/governance-mock.mxsc.json");
+
+// the voting power of every voter in the snapshot, in EGLD
+pub const SNAPSHOT: [(TestAddress, u64); 5] = [
+    (FIRST_VOTER_ADDRESS, 10),
+    (SECOND_VOTER_ADDRESS, 20),
+    (THIRD_VOTER_ADDRESS, 30),
+    (FOURTH_VOTER_ADDRESS, 40),
+    (FIFTH_VOTER_ADDRESS, 50),
+];
+
+// a voter proven through a multi-proof, with its power and its leaf index
+pub type ProvenLeaf = (TestAddress<'static>, u64, usize);
+
+// the voter of the signed votes, whose address is its ed25519 public key;
+// it is the last leaf of the snapshot
+pub const SIGNER_SECRET_KEY: [u8; 32] = [7u8; 32];
+pub const SIGNER_POWER: u64 = 60;
+
+pub const PROPOSAL_ID: u32 = 1;
+pub const START_EPOCH: u64 = 2;
+pub const END_EPOCH: u64 = 5;
+pub const PROTOCOL_STAKE: u64 = 1_000;
+pub const TX_GAS: u64 = 600_000_000;
+
+pub fn exp18(value: u64) -> BigUint<StaticApi> {
+    BigUint::from(value) * BigUint::from(10u64).pow(18)
+}
+
+pub fn snapshot_power(voter: TestAddress) -> u64 {
+    SNAPSHOT
+        .iter()
+        .find(|(address, _)| *address == voter)
+        .map(|(_, power)| *power)
+        .unwrap()
+}
+
+pub fn snapshot_entry(voter: TestAddress, power: u64) -> SnapshotEntry {
+    SnapshotEntry {
+        address: *voter.to_address().as_array(),
+        power: num_bigint::BigUint::from(power) * num_bigint::BigUint::from(10u64).pow(18),
+    }
+}
+
+pub fn signer_address() -> Address {
+    Address::from(
+        SigningKey::from_bytes(&SIGNER_SECRET_KEY)
+            .verifying_key()
+            .to_bytes(),
+    )
+}
+
+pub fn to_managed_hash(hash: &[u8; 32]) -> ManagedByteArray<StaticApi, 32> {
+    ManagedByteArray::new_from_bytes(hash)
+}
+
+pub fn to_managed_proof(
+    proof: &[[u8; 32]],
+) -> ManagedVec<StaticApi, ManagedByteArray<StaticApi, 32>> {
+    proof.iter().map(to_managed_hash).collect()
+}
+
+fn to_proven_voters(
+    leaves: &[ProvenLeaf],
+) -> MultiValueEncoded<StaticApi, MultiValue3<ManagedAddress<StaticApi>, BigUint<StaticApi>, u32>> {
+    leaves
+        .iter()
+        .map(|(voter, power, index)| {
+            (voter.to_managed_address(), exp18(*power), *index as u32).into()
+        })
+        .collect()
+}
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("vote-sc");
+    blockchain.register_contract(VOTE_SC_CODE_PATH, vote_sc::ContractBuilder);
+    blockchain.register_contract(LIQUID_STAKING_CODE_PATH, liquid_staking::ContractBuilder);
+    blockchain.register_contract(GOVERNANCE_MOCK_CODE_PATH, governance_mock::ContractBuilder);
+    blockchain
+}
+
+// the vote contract, forwarding its votes through the liquid staking contract
+// to a mock governance contract placed at the governance system address
+pub struct VoteTestState {
+    pub world: ScenarioWorld,
+    pub tree: MerkleTree,
+}
+
+impl VoteTestState {
+    // registers PROPOSAL_ID in both the vote and the governance contracts,
+    // with the snapshot root hash built with the given hash function
+    pub fn new(hash_function: HashFunction) -> Self {
+        let mut entries = SNAPSHOT
+            .iter()
+            .map(|(voter, power)| snapshot_entry(*voter, *power))
+            .collect::<Vec<_>>();
+        entries.push(SnapshotEntry {
+            address: *signer_address().as_array(),
+            power: num_bigint::BigUint::from(SIGNER_POWER)
+                * num_bigint::BigUint::from(10u64).pow(18),
+        });
+        let tree = MerkleTree::new(hash_function, entries).unwrap();
+
+        let mut world = world();
+        world.account(OWNER_ADDRESS).nonce(1);
+        for (voter, _) in SNAPSHOT {
+            world.account(voter).nonce(1);
+        }
+        world.account(RELAYER_ADDRESS).nonce(1);
+
+        world
+            .account(GovernanceSystemSCAddress)
+            .nonce(1)
+            .code(GOVERNANCE_MOCK_CODE_PATH)
+            .owner(OWNER_ADDRESS);
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .init()
+            .code(LIQUID_STAKING_CODE_PATH)
+            .new_address(LIQUID_STAKING_ADDRESS)
+            .run();
+        // the delegated votes are only bounded by the protocol stake; the Rust VM gives
+        // every sync call a fixed gas limit, which is below the default minimum gas values
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .whitebox(liquid_staking::contract_obj, |sc| {
+                sc.min_gas_for_async_call().set(0);
+                sc.min_gas_for_callback().set(0);
+                sc.min_gas_finish_exec().set(0);
+                sc.virtual_egld_reserve()
+                    .set(BigUint::from(PROTOCOL_STAKE) * BigUint::from(10u64).pow(18));
+            });
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .init()
+            .code(VOTE_SC_CODE_PATH)
+            .new_address(VOTE_SC_ADDRESS)
+            .run();
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .set_vote_contract(VOTE_SC_ADDRESS)
+            .run();
+
+        let mut state = Self { world, tree };
+        state.set_liquid_staking_address();
+        state.create_governance_proposal();
+        state.register_proposal(PROPOSAL_ID, START_EPOCH, END_EPOCH);
+        state.set_proposal_hash_function(PROPOSAL_ID, hash_function);
+        let root_hash = state.tree.root();
+        state.set_root_hash(PROPOSAL_ID, &root_hash);
+
+        state
+    }
+
+    pub fn set_block_epoch(&mut self, epoch: u64) {
+        self.world.current_block().block_epoch(epoch);
+    }
+
+    pub fn set_liquid_staking_address(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .set_liquid_staking_address(LIQUID_STAKING_ADDRESS)
+            .run();
+    }
+
+    pub fn create_governance_proposal(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(GovernanceSystemSCAddress)
+            .typed(governance_mock_proxy::GovernanceMockProxy)
+            .proposal(ManagedBuffer::from("commit-hash"), START_EPOCH, END_EPOCH)
+            .run();
+    }
+
+    pub fn register_proposal(&mut self, proposal_id: u32, start_epoch: u64, end_epoch: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .register_proposal(proposal_id, start_epoch, end_epoch, false)
+            .run();
+    }
+
+    pub fn set_proposal_hash_function(&mut self, proposal_id: u32, hash_function: HashFunction) {
+        let hash_function = match hash_function {
+            HashFunction::Sha256 => vote_sc_proxy::HashFunction::Sha256,
+            HashFunction::Keccak256 => vote_sc_proxy::HashFunction::Keccak256,
+        };
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .set_proposal_hash_function(proposal_id, hash_function)
+            .run();
+    }
+
+    pub fn set_root_hash(&mut self, proposal_id: u32, root_hash: &[u8; 32]) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .set_root_hash(to_managed_hash(root_hash), proposal_id)
+            .run();
+    }
+
+    // votes with the voting power and the proof of the voter in the snapshot
+    pub fn set_root_hash_check_error(
+        &mut self,
+        proposal_id: u32,
+        root_hash: &[u8; 32],
+        error_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .set_root_hash(to_managed_hash(root_hash), proposal_id)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn set_root_hash_fallback(&mut self, enabled: bool) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .set_root_hash_fallback(enabled)
+            .run();
+    }
+
+    // votes with the proof of the voter in the snapshot
+    pub fn delegate_vote(
+        &mut self,
+        voter: TestAddress,
+        vote_type: &str,
+        amount: Option<u64>,
+    ) -> Vec<Log> {
+        let proof = self.voter_proof(voter);
+        self.delegate_vote_with_proof(voter, vote_type, amount, &proof)
+    }
+
+    // returns the logs of the vote, without those of the delivered governance vote
+    pub fn delegate_vote_with_proof(
+        &mut self,
+        voter: TestAddress,
+        vote_type: &str,
+        amount: Option<u64>,
+        proof: &[[u8; 32]],
+    ) -> Vec<Log> {
+        let previous_used_power = self.get_used_power(voter);
+        let logs = self
+            .world
+            .tx()
+            .from(voter)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .delegate_vote(
+                PROPOSAL_ID,
+                vote_type,
+                exp18(snapshot_power(voter)),
+                to_managed_proof(proof),
+                OptionalValue::from(amount.map(exp18)),
+            )
+            .gas(TX_GAS)
+            .returns(ReturnsLogs)
+            .run();
+
+        let used_power = self.get_used_power(voter);
+        self.deliver_governance_vote(voter, vote_type, used_power - previous_used_power);
+
+        logs
+    }
+
+    // the Rust VM drops the promises registered during a sync call, so the delegated vote
+    // that the liquid staking contract sends to the governance contract is delivered here
+    // votes on an aggregated proposal are only tallied, nothing is forwarded
+    fn deliver_governance_vote<A: ProxyArg<ManagedAddress<StaticApi>>>(
+        &mut self,
+        voter: A,
+        vote_type: &str,
+        voting_power: BigUint<StaticApi>,
+    ) {
+        if self.is_aggregated_proposal() {
+            return;
+        }
+
+        self.world
+            .tx()
+            .from(LIQUID_STAKING_ADDRESS)
+            .to(GovernanceSystemSCAddress)
+            .typed(governance_mock_proxy::GovernanceMockProxy)
+            .delegate_vote(PROPOSAL_ID, vote_type, voter, voting_power)
+            .run();
+    }
+
+    pub fn set_proposal_aggregation(&mut self, proposal_id: u32) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .set_proposal_aggregation(proposal_id)
+            .run();
+    }
+
+    pub fn set_proposal_aggregation_check_error(&mut self, proposal_id: u32, error_message: &str) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .set_proposal_aggregation(proposal_id)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn is_aggregated_proposal(&mut self) -> bool {
+        self.world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .aggregated_proposal(PROPOSAL_ID)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn cast_aggregated_vote(&mut self) -> Vec<Log> {
+        self.world
+            .tx()
+            .from(RELAYER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .cast_aggregated_vote(PROPOSAL_ID)
+            .gas(TX_GAS)
+            .returns(ReturnsLogs)
+            .run()
+    }
+
+    pub fn cast_aggregated_vote_check_error(&mut self, error_message: &str) {
+        self.world
+            .tx()
+            .from(RELAYER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .cast_aggregated_vote(PROPOSAL_ID)
+            .gas(TX_GAS)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn delegate_vote_check_error(
+        &mut self,
+        voter: TestAddress,
+        vote_type: &str,
+        amount: Option<u64>,
+        error_message: &str,
+    ) {
+        let proof = self.voter_proof(voter);
+        self.delegate_vote_with_proof_check_error(voter, vote_type, amount, &proof, error_message);
+    }
+
+    pub fn delegate_vote_with_proof_check_error(
+        &mut self,
+        voter: TestAddress,
+        vote_type: &str,
+        amount: Option<u64>,
+        proof: &[[u8; 32]],
+        error_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(voter)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .delegate_vote(
+                PROPOSAL_ID,
+                vote_type,
+                exp18(snapshot_power(voter)),
+                to_managed_proof(proof),
+                OptionalValue::from(amount.map(exp18)),
+            )
+            .gas(TX_GAS)
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn voter_index(&self, voter: TestAddress) -> usize {
+        self.tree.position(voter.to_address().as_array()).unwrap()
+    }
+
+    pub fn voter_proof(&self, voter: TestAddress) -> Vec<[u8; 32]> {
+        self.tree.proof(self.voter_index(voter))
+    }
+
+    // the leaves of the given voters, sorted by their index as the multi-proofs need them
+    pub fn voter_leaves(&self, voters: &[TestAddress<'static>]) -> Vec<ProvenLeaf> {
+        let mut leaves = voters
+            .iter()
+            .map(|voter| (*voter, snapshot_power(*voter), self.voter_index(*voter)))
+            .collect::<Vec<_>>();
+        leaves.sort_by_key(|(_, _, index)| *index);
+
+        leaves
+    }
+
+    pub fn prove_voting_powers(&mut self, leaves: &[ProvenLeaf], proof: &[[u8; 32]]) {
+        let leaf_count = self.tree.entries().len() as u32;
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .prove_voting_powers(
+                PROPOSAL_ID,
+                leaf_count,
+                to_managed_proof(proof),
+                to_proven_voters(leaves),
+            )
+            .run();
+    }
+
+    pub fn prove_voting_powers_check_error(
+        &mut self,
+        leaves: &[ProvenLeaf],
+        proof: &[[u8; 32]],
+        error_message: &str,
+    ) {
+        let leaf_count = self.tree.entries().len() as u32;
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .prove_voting_powers(
+                PROPOSAL_ID,
+                leaf_count,
+                to_managed_proof(proof),
+                to_proven_voters(leaves),
+            )
+            .returns(ExpectError(4, error_message))
+            .run();
+    }
+
+    pub fn check_proven_voting_power(&mut self, voter: TestAddress, expected: u64) {
+        let proven = self
+            .world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .proven_voting_power(PROPOSAL_ID, voter)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(proven.root_hash.to_byte_array(), self.tree.root());
+        assert_eq!(proven.voting_power, exp18(expected));
+    }
+
+    // signs the vote message of the contract like a wallet signs a message
+    pub fn sign_vote(
+        &mut self,
+        secret_key: &[u8; 32],
+        proposal_id: u32,
+        vote_type: &str,
+        voting_power: u64,
+        nonce: u64,
+    ) -> Vec<u8> {
+        let message = self
+            .world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .get_vote_message(proposal_id, vote_type, exp18(voting_power), nonce)
+            .returns(ReturnsResult)
+            .run()
+            .to_vec();
+
+        let mut signable_message = b"\x17Elrond Signed Message:\n".to_vec();
+        signable_message.extend(message.len().to_string().into_bytes());
+        signable_message.extend(message);
+        let message_hash = HashFunction::Keccak256.hash(&signable_message);
+
+        SigningKey::from_bytes(secret_key)
+            .sign(&message_hash)
+            .to_bytes()
+            .to_vec()
+    }
+
+    pub fn relay_signed_vote(
+        &mut self,
+        vote_type: &str,
+        voting_power: u64,
+        nonce: u64,
+        signature: &[u8],
+    ) {
+        let signer = signer_address();
+        let proof = self.tree.proof(self.tree.entries().len() - 1);
+        let previous_used_power = self.get_used_power(&signer);
+        self.world
+            .tx()
+            .from(RELAYER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .delegate_signed_vote(
+                &signer,
+                PROPOSAL_ID,
+                vote_type,
+                exp18(voting_power),
+                nonce,
+                to_managed_proof(&proof),
+                ManagedBuffer::new_from_bytes(signature),
+            )
+            .gas(TX_GAS)
+            .run();
+
+        let used_power = self.get_used_power(&signer);
+        self.deliver_governance_vote(&signer, vote_type, used_power - previous_used_power);
+    }
+
+    pub fn relay_signed_vote_check_error(
+        &mut self,
+        vote_type: &str,
+        voting_power: u64,
+        nonce: u64,
+        signature: &[u8],
+        error: (u64, &str),
+    ) {
+        let proof = self.tree.proof(self.tree.entries().len() - 1);
+        self.world
+            .tx()
+            .from(RELAYER_ADDRESS)
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .delegate_signed_vote(
+                signer_address(),
+                PROPOSAL_ID,
+                vote_type,
+                exp18(voting_power),
+                nonce,
+                to_managed_proof(&proof),
+                ManagedBuffer::new_from_bytes(signature),
+            )
+            .gas(TX_GAS)
+            .returns(ExpectError(error.0, error.1))
+            .run();
+    }
+
+    pub fn check_voter_nonce(&mut self, expected: u64) {
+        self.world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .voter_nonce(signer_address())
+            .returns(ExpectValue(expected))
+            .run();
+    }
+
+    pub fn get_used_power<A: ProxyArg<ManagedAddress<StaticApi>>>(
+        &mut self,
+        voter: A,
+    ) -> BigUint<StaticApi> {
+        let vote_usage = self
+            .world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .get_vote_usage(PROPOSAL_ID, voter)
+            .returns(ReturnsResult)
+            .run();
+
+        vote_usage.used_power
+    }
+
+    pub fn check_vote_usage<A: ProxyArg<ManagedAddress<StaticApi>>>(
+        &mut self,
+        voter: A,
+        vote_type: &str,
+        used_power: u64,
+    ) {
+        let vote_usage = self
+            .world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .get_vote_usage(PROPOSAL_ID, voter)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(vote_usage.vote_type, ManagedBuffer::from(vote_type));
+        assert_eq!(vote_usage.used_power, exp18(used_power));
+    }
+
+    pub fn check_vote_total(&mut self, vote_type: &str, expected: u64) {
+        self.world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .proposal_vote_total(PROPOSAL_ID, vote_type)
+            .returns(ExpectValue(exp18(expected)))
+            .run();
+    }
+
+    // the totals of all vote types, in the order of VOTE_TYPES
+    pub fn check_vote_totals(&mut self, expected: [u64; 4]) {
+        let tallies = self
+            .world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .get_proposal_vote_totals(PROPOSAL_ID)
+            .returns(ReturnsResult)
+            .run();
+        let vote_types = ["yes", "no", "abstain", "veto"];
+        assert_eq!(tallies.len(), vote_types.len());
+        for (index, tally) in tallies.iter().enumerate() {
+            assert_eq!(tally.vote_type, ManagedBuffer::from(vote_types[index]));
+            assert_eq!(tally.voting_power, exp18(expected[index]));
+        }
+    }
+
+    pub fn check_voters_count(&mut self, expected: usize) {
+        self.world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .proposal_voters_count(PROPOSAL_ID)
+            .returns(ExpectValue(expected))
+            .run();
+    }
+
+    pub fn check_remaining_voting_power(&mut self, voter: TestAddress, expected: u64) {
+        self.world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .get_remaining_voting_power(PROPOSAL_ID, voter, exp18(snapshot_power(voter)))
+            .returns(ExpectValue(exp18(expected)))
+            .run();
+    }
+
+    pub fn check_root_hash(&mut self, proposal_id: u32, expected: Option<[u8; 32]>) {
+        let root_hash = self
+            .world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .get_root_hash(proposal_id)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(
+            root_hash.into_option().map(|hash| hash.to_byte_array()),
+            expected
+        );
+    }
+
+    pub fn check_root_hash_fallback_enabled(&mut self, expected: bool) {
+        self.world
+            .query()
+            .to(VOTE_SC_ADDRESS)
+            .typed(vote_sc_proxy::VoteSCProxy)
+            .is_root_hash_fallback_enabled()
+            .returns(ExpectValue(expected))
+            .run();
+    }
+
+    pub fn check_governance_vote<A: ProxyArg<ManagedAddress<StaticApi>>>(
+        &mut self,
+        voter: A,
+        vote_type: &str,
+        power: u64,
+    ) {
+        let vote = self
+            .world
+            .query()
+            .to(GovernanceSystemSCAddress)
+            .typed(governance_mock_proxy::GovernanceMockProxy)
+            .votes(PROPOSAL_ID, voter)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(vote.vote_type, ManagedBuffer::from(vote_type));
+        assert_eq!(vote.voting_power, exp18(power));
+    }
+
+    pub fn check_governance_tally(&mut self, vote_type: &str, expected: u64) {
+        self.world
+            .query()
+            .to(GovernanceSystemSCAddress)
+            .typed(governance_mock_proxy::GovernanceMockProxy)
+            .proposal_tally(PROPOSAL_ID, vote_type)
+            .returns(ExpectValue(exp18(expected)))
+            .run();
+    }
+
+    pub fn check_aggregated_vote_cast(&mut self, expected: bool) {
+        self.world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .aggregated_vote_cast(PROPOSAL_ID)
+            .returns(ExpectValue(expected))
+            .run();
+    }
+
+    pub fn check_delegated_voting_power(&mut self, expected: u64) {
+        self.world
+            .query()
+            .to(LIQUID_STAKING_ADDRESS)
+            .typed(liquid_staking_proxy::LiquidStakingProxy)
+            .delegated_voting_power(PROPOSAL_ID)
+            .returns(ExpectValue(exp18(expected)))
+            .run();
+    }
+}
+
+// the VoteCast event has all its fields indexed, so they are all found in the topics
+pub fn check_vote_cast_log(
+    logs: &[Log],
+    voter: TestAddress,
+    vote_type: &str,
+    voting_power: u64,
+    used_power: u64,
+) {
+    let vote_cast_logs = logs
+        .iter()
+        .filter(|log| log.topics.first().map(Vec::as_slice) == Some(b"VoteCast".as_slice()))
+        .collect::<Vec<_>>();
+    assert_eq!(vote_cast_logs.len(), 1);
+
+    let log = vote_cast_logs[0];
+    assert_eq!(log.address, VOTE_SC_ADDRESS.to_address());
+    assert_eq!(
+        log.topics[1..],
+        [
+            voter.to_address().to_vec(),
+            top_encode_to_vec_u8_or_panic(&PROPOSAL_ID),
+            vote_type.as_bytes().to_vec(),
+            exp18(voting_power).to_bytes_be().as_slice().to_vec(),
+            exp18(used_power).to_bytes_be().as_slice().to_vec(),
+        ]
+    );
+}
+
+// the vote contract emits the combined vote and the liquid staking contract casts it
+// once, with the part of its stake not delegated yet
+pub fn check_aggregated_vote_cast_logs(
+    logs: &[Log],
+    vote_type: &str,
+    voting_power: u64,
+    protocol_power: u64,
+) {
+    let find_logs = |identifier: &[u8]| {
+        logs.iter()
+            .filter(|log| log.topics.first().map(Vec::as_slice) == Some(identifier))
+            .collect::<Vec<_>>()
+    };
+
+    let vote_sc_logs = find_logs(b"AggregatedVoteCast");
+    assert_eq!(vote_sc_logs.len(), 1);
+    assert_eq!(vote_sc_logs[0].address, VOTE_SC_ADDRESS.to_address());
+    assert_eq!(
+        vote_sc_logs[0].topics[1..],
+        [
+            top_encode_to_vec_u8_or_panic(&PROPOSAL_ID),
+            vote_type.as_bytes().to_vec(),
+        ]
+    );
+    assert_eq!(
+        vote_sc_logs[0].data,
+        [exp18(voting_power).to_bytes_be().as_slice().to_vec()]
+    );
+
+    let ls_logs = find_logs(b"aggregated_vote_cast");
+    assert_eq!(ls_logs.len(), 1);
+    assert_eq!(ls_logs[0].address, LIQUID_STAKING_ADDRESS.to_address());
+    assert_eq!(
+        ls_logs[0].topics[1..],
+        [
+            top_encode_to_vec_u8_or_panic(&PROPOSAL_ID),
+            vote_type.as_bytes().to_vec(),
+        ]
+    );
+    assert_eq!(
+        ls_logs[0].data,
+        [exp18(protocol_power).to_bytes_be().as_slice().to_vec()]
+    );
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            6
+// Endpoints:                           28
 // Async Callback (empty):               1
-// Total number of exported functions:   9
+// Total number of exported functions:  31
 
 #![no_std]
 
@@ -21,11 +21,33 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         set_root_hash => set_root_hash
+        set_root_hash_fallback => set_root_hash_fallback
         set_liquid_staking_address => set_liquid_staking_address
         delegate_vote => delegate_vote
+        delegate_signed_vote => delegate_signed_vote
+        prove_voting_powers => prove_voting_powers
+        cast_aggregated_vote => cast_aggregated_vote
         getLiquidStakingAddress => liquid_staking_sc
+        set_proposal_aggregation => set_proposal_aggregation
+        isAggregatedProposal => aggregated_proposal
+        isAggregatedVoteCast => aggregated_vote_cast
+        set_proposal_hash_function => set_proposal_hash_function
+        getProposalHashFunction => proposal_hash_function
+        getProvenVotingPower => proven_voting_power
+        register_proposal => register_proposal
+        getActiveProposals => get_active_proposals
+        getProposal => proposal
+        getProposals => proposals
+        getVoteMessage => get_vote_message
+        getVoterNonce => voter_nonce
         getProposalRootHash => get_root_hash
         confirmVotingPower => confirm_voting_power
+        getProposalVoteTotals => get_proposal_vote_totals
+        getProposalVoteTotal => proposal_vote_total
+        getProposalVotersCount => proposal_voters_count
+        isRootHashFallbackEnabled => is_root_hash_fallback_enabled
+        getVoteUsage => get_vote_usage
+        getRemainingVotingPower => get_remaining_voting_power
     )
 }
 